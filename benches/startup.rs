@@ -0,0 +1,101 @@
+#[allow(dead_code)]
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::{FakeGithub, Workspace};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use octocrab::OctocrabBuilder;
+use prmaker_core::{conventional, issues, remote};
+use std::{
+    io::Read,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+const STARTUP_GIT_CALLS: [&[&str]; 3] = [
+    &["config", "--get", "remote.origin.url"],
+    &["rev-parse", "--show-toplevel"],
+    &["branch", "--show-current"],
+];
+
+fn regexes(c: &mut Criterion) {
+    c.bench_function("regex/parse_remote", |b| {
+        b.iter(|| remote::parse_remote(black_box("git@github.com:acme/api.git")))
+    });
+    c.bench_function("regex/from_branch_name", |b| {
+        b.iter(|| issues::from_branch_name(black_box("CT-12-add-login")))
+    });
+    c.bench_function("regex/commit_type", |b| {
+        b.iter(|| conventional::commit_type(black_box("feat(web): add login")))
+    });
+}
+
+fn git_calls(c: &mut Criterion) {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&workspace.repo)
+            .output()
+            .unwrap()
+    };
+
+    c.bench_function("git/startup_calls", |b| {
+        b.iter(|| STARTUP_GIT_CALLS.map(git))
+    });
+}
+
+fn client(c: &mut Criterion) {
+    c.bench_function("client/build", |b| {
+        b.iter(|| {
+            OctocrabBuilder::new()
+                .personal_token("test-token".to_owned())
+                .base_url("http://127.0.0.1:1/")
+                .unwrap()
+                .build()
+                .unwrap()
+        })
+    });
+}
+
+fn first_prompt(c: &mut Criterion) {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+
+    c.bench_function("first_prompt", |b| {
+        b.iter_custom(|iters| {
+            (0..iters)
+                .map(|_| time_until(workspace.command(&[]), "PR title:"))
+                .sum()
+        })
+    });
+}
+
+fn time_until(mut command: Command, output: &str) -> Duration {
+    let started = Instant::now();
+    let mut child = command.spawn().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+    let mut seen = vec![];
+    let mut buffer = [0; 1024];
+
+    while !String::from_utf8_lossy(&seen).contains(output) {
+        match stdout.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => seen.extend_from_slice(&buffer[..read]),
+        }
+    }
+
+    let elapsed = started.elapsed();
+    let _ = child.kill();
+    let _ = child.wait();
+
+    elapsed
+}
+
+criterion_group! {
+    name = startup;
+    config = Criterion::default().sample_size(20);
+    targets = regexes, git_calls, client, first_prompt
+}
+criterion_main!(startup);
@@ -0,0 +1,78 @@
+use alloc::{borrow::ToOwned, format, string::String};
+
+use crate::{gitmoji, LazyRegex};
+
+static CONVENTIONAL_REGEX: LazyRegex = LazyRegex::new(r"^(\w+)(\([^)]*\))?!?: \S");
+static TYPE_PREFIX_REGEX: LazyRegex = LazyRegex::new(r"^(\w+)(\([^)]*\))?!?:");
+static PREFIX_REGEX: LazyRegex = LazyRegex::new(r"^\w+(?:\([^)]*\))?!?: ");
+static UNSCOPED_REGEX: LazyRegex = LazyRegex::new(r"^(\w+)(!?): (\S.*)$");
+
+pub fn commit_type(title: &str) -> Option<String> {
+    CONVENTIONAL_REGEX
+        .captures(gitmoji::strip(title))
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_lowercase())
+}
+
+pub fn prefix_type(title: &str) -> Option<String> {
+    TYPE_PREFIX_REGEX
+        .captures(gitmoji::strip(title))
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_lowercase())
+}
+
+pub fn is_conventional(title: &str) -> bool {
+    commit_type(title).is_some()
+}
+
+pub fn split_prefix(title: &str) -> (&str, &str) {
+    match PREFIX_REGEX.find(title) {
+        Some(prefix) => title.split_at(prefix.end()),
+        None => ("", title),
+    }
+}
+
+pub fn add_scope(title: &str, scope: &str) -> String {
+    match UNSCOPED_REGEX.captures(title) {
+        Some(c) => format!("{}({scope}){}: {}", &c[1], &c[2], &c[3]),
+        None => title.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_type_test() {
+        assert_eq!(Some("feat".to_owned()), commit_type("feat: add thing"));
+        assert_eq!(Some("fix".to_owned()), commit_type("fix(api)!: break it"));
+        assert_eq!(Some("feat".to_owned()), commit_type("✨ feat: add thing"));
+        assert_eq!(None, commit_type("Add thing"));
+        assert_eq!(None, commit_type("feat:missing space"));
+    }
+
+    #[test]
+    fn prefix_type_test() {
+        assert_eq!(Some("feat".to_owned()), prefix_type("feat:missing space"));
+        assert_eq!(Some("fix".to_owned()), prefix_type("fix(api)!:"));
+        assert_eq!(None, prefix_type("Add thing"));
+    }
+
+    #[test]
+    fn split_prefix_test() {
+        assert_eq!(
+            ("feat(api)!: ", "add thing"),
+            split_prefix("feat(api)!: add thing")
+        );
+        assert_eq!(("", "Add thing"), split_prefix("Add thing"));
+    }
+
+    #[test]
+    fn add_scope_test() {
+        assert_eq!("feat(api): add thing", add_scope("feat: add thing", "api"));
+        assert_eq!("fix(api)!: break it", add_scope("fix!: break it", "api"));
+        assert_eq!("fix(web): keep it", add_scope("fix(web): keep it", "api"));
+        assert_eq!("Add thing", add_scope("Add thing", "api"));
+    }
+}
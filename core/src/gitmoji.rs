@@ -0,0 +1,33 @@
+pub const GITMOJIS: [(&str, &str, &str); 11] = [
+    ("feat", "✨", "Introduce new features"),
+    ("fix", "🐛", "Fix a bug"),
+    ("docs", "📝", "Add or update documentation"),
+    ("style", "🎨", "Improve structure or format of the code"),
+    ("refactor", "♻️", "Refactor code"),
+    ("perf", "⚡️", "Improve performance"),
+    ("test", "✅", "Add, update, or pass tests"),
+    ("build", "📦️", "Add or update compiled files or packages"),
+    ("ci", "👷", "Add or update CI build system"),
+    ("chore", "🔧", "Add or update configuration files"),
+    ("revert", "⏪️", "Revert changes"),
+];
+
+pub fn strip(title: &str) -> &str {
+    GITMOJIS
+        .iter()
+        .find_map(|(_, emoji, _)| title.strip_prefix(emoji))
+        .map(|rest| rest.trim_start())
+        .unwrap_or(title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_test() {
+        assert_eq!("feat: add login", strip("✨ feat: add login"));
+        assert_eq!("fix: crash", strip("🐛fix: crash"));
+        assert_eq!("Add login", strip("Add login"));
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+
+use crate::LazyRegex;
+
+static BRANCH_ISSUE_REGEX: LazyRegex = LazyRegex::new(r"^([a-zA-Z][a-zA-Z\d]*-\d+)(?:[-_].*)?$");
+static ISSUE_KEY_REGEX: LazyRegex = LazyRegex::new(r"\b[A-Z][A-Z\d]+-\d+\b");
+const PLACEHOLDER_WORDS: [&str; 7] = ["temp", "tmp", "wip", "test", "todo", "xxx", "draft"];
+
+pub fn parse_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|i| i.trim())
+        .filter(|i| !i.is_empty())
+        .map(|i| i.to_owned())
+        .collect()
+}
+
+pub fn looks_wrong(issue: &str, branch: &str) -> bool {
+    let Some((project, number)) = issue.rsplit_once('-') else {
+        return true;
+    };
+
+    let is_placeholder = |word: &str| PLACEHOLDER_WORDS.contains(&word.to_lowercase().as_str());
+    let branch_type = branch.split('/').next().unwrap_or_default();
+
+    project.is_empty()
+        || number.is_empty()
+        || !number.chars().all(|c| c.is_ascii_digit())
+        || is_placeholder(project)
+        || (branch.contains('/') && is_placeholder(branch_type))
+}
+
+pub fn from_branch_name(branch: &str) -> Vec<String> {
+    let mut segments: Vec<&str> = branch.split('/').collect();
+    let mut issues = vec![];
+
+    if segments.len() > 1 && !BRANCH_ISSUE_REGEX.is_match(segments[0]) {
+        segments.remove(0);
+    }
+
+    for segment in segments {
+        let Some(captures) = BRANCH_ISSUE_REGEX.captures(segment) else {
+            break;
+        };
+
+        issues.push(captures[1].to_owned());
+
+        if captures[1].len() < segment.len() {
+            break;
+        }
+    }
+
+    issues
+}
+
+pub fn from_commits(messages: &str) -> Vec<String> {
+    let mut issues: Vec<String> = vec![];
+
+    for issue in ISSUE_KEY_REGEX
+        .find_iter(messages)
+        .map(|m| m.as_str().to_owned())
+    {
+        if !issues.contains(&issue) {
+            issues.push(issue);
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_branch_name_test() {
+        let branches = [
+            "fix/CT-1111/fix-something",
+            "fix/CT-1111/CT-2222/CT-3333/another-fix",
+        ];
+
+        let yt_issues = branches.map(from_branch_name);
+
+        assert_eq!(
+            yt_issues,
+            [vec!["CT-1111"], vec!["CT-1111", "CT-2222", "CT-3333"]]
+        );
+        assert!(from_branch_name("fix/something").is_empty());
+        assert_eq!(from_branch_name("CT-1/login"), ["CT-1"]);
+        assert_eq!(from_branch_name("feat/CT-1-login"), ["CT-1"]);
+        assert_eq!(from_branch_name("fix/CT-1/add/more"), ["CT-1"]);
+        assert!(from_branch_name("feat/add-2fa").is_empty());
+    }
+
+    #[test]
+    fn from_commits_test() {
+        let messages =
+            "fix: CT-1111 crash on login\n\nAlso closes CT-2222 and CT-1111\nutf-8 sha-256";
+
+        assert_eq!(from_commits(messages), ["CT-1111", "CT-2222"]);
+        assert_eq!(parse_list(" CT-1, ,CT-2 "), ["CT-1", "CT-2"]);
+    }
+
+    #[test]
+    fn looks_wrong_test() {
+        assert!(!looks_wrong("CT-1111", "fix/CT-1111/fix-something"));
+        assert!(looks_wrong("TEMP-1", "fix/TEMP-1/fix-something"));
+        assert!(looks_wrong("CT-1111", "wip/CT-1111/fix-something"));
+        assert!(looks_wrong("fix-something", "fix/fix-something/more"));
+        assert!(looks_wrong("CT1111", "fix/CT1111/fix-something"));
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::ops::Deref;
+use once_cell::race::OnceBox;
+use regex::Regex;
+
+pub mod conventional;
+pub mod gitmoji;
+pub mod issues;
+pub mod remote;
+pub mod template;
+
+struct LazyRegex {
+    pattern: &'static str,
+    regex: OnceBox<Regex>,
+}
+
+impl LazyRegex {
+    const fn new(pattern: &'static str) -> Self {
+        LazyRegex {
+            pattern,
+            regex: OnceBox::new(),
+        }
+    }
+}
+
+impl Deref for LazyRegex {
+    type Target = Regex;
+
+    fn deref(&self) -> &Regex {
+        self.regex
+            .get_or_init(|| Box::new(Regex::new(self.pattern).unwrap()))
+    }
+}
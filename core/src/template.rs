@@ -0,0 +1,117 @@
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
+pub const DEFAULT_TEMPLATE: &str = include_str!("../../pull_request_template.md");
+pub const GENERATED_START: &str = "<!-- prmaker:start -->";
+pub const GENERATED_END: &str = "<!-- prmaker:end -->";
+
+pub fn render(template: &str, body: &str, issue: &str) -> String {
+    template
+        .replace("{body}", body)
+        .replace("{issue}", issue)
+        .to_owned()
+}
+
+pub fn link_issues(issues: &[String], issue_url: &str) -> String {
+    let issue_url = issue_url.trim_end_matches('/');
+
+    issues
+        .iter()
+        .map(|i| format!("[{i}]({issue_url}/{i})"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+pub fn add_issue_prefix(title: &str, issue: &str, format: &str) -> String {
+    if issue.is_empty() {
+        return title.to_owned();
+    }
+
+    let prefix = format.replace("{issue}", issue);
+
+    if title.starts_with(&prefix) {
+        title.to_owned()
+    } else {
+        format!("{prefix}{title}")
+    }
+}
+
+pub fn replace_generated(existing: &str, generated: &str) -> Option<String> {
+    let start = existing.find(GENERATED_START)?;
+    let end = existing[start..].find(GENERATED_END)? + start + GENERATED_END.len();
+
+    Some(format!(
+        "{}{}{}",
+        &existing[..start],
+        generated.trim_end(),
+        &existing[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let expected = "### What does this PR do?
+
+Title
+
+<!--
+Please include a summary of the change and/or which issue is fixed. Please also include relevant motivation and context. List any dependencies that are required for this change, also provide (if appropriate) any evidence - screenshots, gifs, logs, etc.
+
+Oh, remember to follow conventional commits (https://conventionalcommits.org) on pull request title ;)
+-->
+
+---
+
+**Related issue:** CT-1111
+";
+
+        assert_eq!(expected, render(DEFAULT_TEMPLATE, "Title", "CT-1111"));
+    }
+
+    #[test]
+    fn add_issue_prefix_test() {
+        assert_eq!(
+            "[ABC-123] Fix login crash",
+            add_issue_prefix("Fix login crash", "ABC-123", "[{issue}] ")
+        );
+        assert_eq!(
+            "[ABC-123] Fix login crash",
+            add_issue_prefix("[ABC-123] Fix login crash", "ABC-123", "[{issue}] ")
+        );
+        assert_eq!(
+            "Fix login crash",
+            add_issue_prefix("Fix login crash", "", "[{issue}] ")
+        );
+    }
+
+    #[test]
+    fn link_issues_test() {
+        assert_eq!(
+            "[ABC-123](https://yt.example.com/issue/ABC-123), [ABC-124](https://yt.example.com/issue/ABC-124)",
+            link_issues(
+                &["ABC-123".to_owned(), "ABC-124".to_owned()],
+                "https://yt.example.com/issue/"
+            )
+        );
+        assert_eq!("", link_issues(&[], "https://yt.example.com/issue"));
+    }
+
+    #[test]
+    fn replace_generated_test() {
+        let existing = format!(
+            "{GENERATED_START}\nold body\n{GENERATED_END}\n\n## Screenshots\n\nmanual notes\n"
+        );
+        let generated = format!("{GENERATED_START}\nnew body\n{GENERATED_END}\n");
+
+        assert_eq!(
+            Some(format!(
+                "{GENERATED_START}\nnew body\n{GENERATED_END}\n\n## Screenshots\n\nmanual notes\n"
+            )),
+            replace_generated(&existing, &generated)
+        );
+        assert_eq!(None, replace_generated("manual body", &generated));
+    }
+}
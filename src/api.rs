@@ -0,0 +1,259 @@
+use octocrab::Error::GitHub;
+use serde::Deserialize;
+use std::{env, path::Path};
+
+use crate::{
+    config::Config,
+    forge::{self, Host},
+    git, late_vars,
+    network::within_timeout,
+    pr::{self, PR},
+    profiles::{self, Profile},
+    projects, reviewers, try_build_octocrab, GITHUB_TOKEN_VAR, GITHUB_USER_VAR,
+};
+
+/// A PR to open from the repository in the current directory.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrRequest {
+    pub title: String,
+    /// Defaults to the default body, rendered in the repo's template either way.
+    pub body: Option<String>,
+    /// Defaults to the configured base branch or the repository default branch.
+    pub base: Option<String>,
+    /// Defaults to the current branch.
+    pub head: Option<String>,
+    /// Users, `org/team` teams or `@group` reviewer groups.
+    pub reviewers: Vec<String>,
+    pub issues: Vec<String>,
+    pub draft: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrResult {
+    pub number: u64,
+    pub url: String,
+    pub reviewers: Vec<String>,
+    /// Follow-up steps that failed after the PR was created.
+    pub warnings: Vec<String>,
+}
+
+/// Creates the PR with the prmaker config and the credentials of the
+/// detected forge, without prompting or exiting. Problems that don't stop
+/// the PR from being created are returned as warnings instead of printed.
+pub async fn create_pull_request(request: PrRequest) -> Result<PrResult, String> {
+    if request.title.trim().is_empty() {
+        return Err("title is required".to_owned());
+    }
+
+    let config = Config::try_load()?;
+    let remote_url = git::try_git(&["config", "--get", "remote.origin.url"])
+        .map_err(|_| "no origin remote".to_owned())?;
+
+    let profile = profiles::select(&config.profiles, &remote_url);
+
+    match forge::resolve(&remote_url, &config.forges) {
+        Ok(Host::GitHub) => create_on_github(request, config, profile, &remote_url).await,
+        Ok(host) => create_on_forge(host, request, config, &remote_url).await,
+        Err(host) if host.is_empty() => Err(format!("can't parse the remote url {remote_url}")),
+        Err(host) => Err(format!("unsupported forge {host}")),
+    }
+}
+
+async fn create_on_forge(
+    host: Host,
+    request: PrRequest,
+    config: &Config,
+    remote_url: &str,
+) -> Result<PrResult, String> {
+    let body = request.body.as_deref().unwrap_or(pr::DEFAULT_BODY);
+    let (selection, unknown) = reviewers::try_expand(&request.reviewers, &config.reviewer_groups);
+    let new_pr = forge::NewPullRequest {
+        title: request.title.clone(),
+        body: pr::build_full_body(config, &[], body, &request.issues, &[]),
+        head: match &request.head {
+            Some(head) => head.clone(),
+            None => git::try_git(&["branch", "--show-current"])?,
+        },
+        base: request
+            .base
+            .as_deref()
+            .or(config.base_branch())
+            .unwrap_or_default()
+            .to_owned(),
+        reviewers: [selection.usernames, selection.teams].concat(),
+    };
+    let mut warnings = unknown_groups(unknown);
+
+    if request.draft && !host.capabilities().drafts {
+        warnings.push(format!(
+            "{host} doesn't support drafts, opened it ready for review"
+        ));
+    }
+
+    let reviewers = new_pr.reviewers.clone();
+    let (created, skipped) = forge::open_pull_request(host, remote_url, new_pr).await?;
+
+    warnings.extend(skipped);
+
+    Ok(PrResult {
+        number: created.number,
+        url: created.link,
+        reviewers: reviewers
+            .into_iter()
+            .filter(|r| host.capabilities().teams || !r.contains('/'))
+            .collect(),
+        warnings,
+    })
+}
+
+async fn create_on_github(
+    request: PrRequest,
+    config: &Config,
+    profile: Option<&Profile>,
+    remote_url: &str,
+) -> Result<PrResult, String> {
+    let user = profile
+        .and_then(|p| p.user.clone())
+        .or_else(|| env::var(GITHUB_USER_VAR).ok())
+        .ok_or_else(|| format!("{GITHUB_USER_VAR} is not set"))?;
+    let token = profile
+        .and_then(|p| p.token.clone())
+        .or_else(|| env::var(GITHUB_TOKEN_VAR).ok())
+        .ok_or_else(|| format!("{GITHUB_TOKEN_VAR} is not set"))?;
+    let octocrab = try_build_octocrab(token)?;
+
+    let (base, repo) = pr::parse_remote(remote_url)
+        .ok_or_else(|| format!("can't parse the remote url {remote_url}"))?;
+
+    let base_branch = match request.base.as_deref().or(config.base_branch()) {
+        Some(branch) => branch.to_owned(),
+        None => within_timeout(octocrab.repos(&base, &repo).get())
+            .await?
+            .ok()
+            .and_then(|r| r.default_branch)
+            .ok_or("couldn't get the repository default branch")?,
+    };
+    let head = match &request.head {
+        Some(head) => head.clone(),
+        None => git::try_git(&["branch", "--show-current"])?,
+    };
+    let diff_head = match &request.head {
+        Some(head) => format!("origin/{head}"),
+        None => git::head().to_owned(),
+    };
+    let body = request.body.clone().unwrap_or(pr::DEFAULT_BODY.to_owned());
+
+    let mut pr = PR::new(base, repo, head, base_branch, String::new(), String::new());
+    let toplevel = git::try_git(&["rev-parse", "--show-toplevel"])?;
+    let toplevel = Path::new(&toplevel);
+    let changed_files = git::changed_files(&format!("origin/{}...{diff_head}", pr.base_branch));
+
+    pr.projects = projects::get_touched_projects(&config.projects, &changed_files, toplevel);
+    pr.yt_issues = request.issues.clone();
+
+    let (template, template_warning) = pr::read_template(&pr.projects, toplevel);
+
+    pr.restore_with_template(&request.title, &body, config, &template, toplevel);
+
+    let create_resp = within_timeout(
+        octocrab
+            .pulls(&pr.base, &pr.repo)
+            .create(&pr.title, &pr.branch, &pr.base_branch)
+            .body(&pr.full_body)
+            .draft(request.draft)
+            .send(),
+    )
+    .await?;
+
+    let created = match create_resp {
+        Ok(created) => created,
+        Err(GitHub { source, .. }) => return Err(source.message),
+        Err(err) => return Err(err.to_string()),
+    };
+
+    let mut result = PrResult {
+        number: created.number,
+        url: pr::get_pr_link(&created),
+        reviewers: vec![],
+        warnings: template_warning.into_iter().collect(),
+    };
+
+    let late = late_vars::Created {
+        number: created.number,
+        link: &result.url,
+        head_sha: &created.head.sha,
+    };
+
+    if let Some(body) = late_vars::substitute(&pr.full_body, &config.late_vars, &late) {
+        let update_resp = within_timeout(
+            octocrab
+                .pulls(&pr.base, &pr.repo)
+                .update(created.number)
+                .body(&body)
+                .send(),
+        )
+        .await;
+
+        if !matches!(update_resp, Ok(Ok(_))) {
+            result
+                .warnings
+                .push("couldn't fill in the late variables".to_owned());
+        }
+    }
+
+    let assign_resp = within_timeout(
+        octocrab
+            .issues(&pr.base, &pr.repo)
+            .add_assignees(created.number, &[&user]),
+    )
+    .await;
+
+    if !matches!(assign_resp, Ok(Ok(_))) {
+        result.warnings.push(format!("couldn't assign {user}"));
+    }
+
+    let labels = pr.project_labels();
+
+    if !labels.is_empty() {
+        let labels_resp = within_timeout(
+            octocrab
+                .issues(&pr.base, &pr.repo)
+                .add_labels(created.number, &labels),
+        )
+        .await;
+
+        if !matches!(labels_resp, Ok(Ok(_))) {
+            result.warnings.push("couldn't add the labels".to_owned());
+        }
+    }
+
+    let (selection, unknown) = reviewers::try_expand(&request.reviewers, &config.reviewer_groups);
+
+    result.warnings.extend(unknown_groups(unknown));
+
+    if !selection.is_empty() {
+        let reviews_resp = within_timeout(octocrab.pulls(&pr.base, &pr.repo).request_reviews(
+            created.number,
+            selection.usernames.clone(),
+            selection.teams.clone(),
+        ))
+        .await;
+
+        match reviews_resp {
+            Ok(Ok(_)) => result.reviewers = [selection.usernames, selection.teams].concat(),
+            _ => result
+                .warnings
+                .push("couldn't request the reviewers".to_owned()),
+        }
+    }
+
+    Ok(result)
+}
+
+fn unknown_groups(names: Vec<String>) -> Vec<String> {
+    names
+        .into_iter()
+        .map(|name| format!("unknown reviewer group {name}, ignored it"))
+        .collect()
+}
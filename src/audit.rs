@@ -0,0 +1,67 @@
+use chrono::Utc;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+use crate::run_log::state_path;
+
+const AUDIT_FILE: &str = "audit.log";
+
+static LOG: OnceLock<Mutex<File>> = OnceLock::new();
+
+pub fn enable() -> Option<PathBuf> {
+    let path = state_path(AUDIT_FILE)?;
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .ok()?;
+    let _ = LOG.set(Mutex::new(file));
+
+    Some(path)
+}
+
+pub fn record(kind: &str, call: &str, outcome: &str, elapsed: Duration) {
+    let Some(log) = LOG.get() else {
+        return;
+    };
+
+    let line = format_line(kind, call, outcome, elapsed);
+
+    if let Ok(mut file) = log.lock() {
+        let _ = writeln!(file, "{} {line}", Utc::now().format("%Y-%m-%dT%H:%M:%SZ"));
+    }
+}
+
+fn format_line(kind: &str, call: &str, outcome: &str, elapsed: Duration) -> String {
+    let call = call.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    format!("{kind} {call} -> {outcome} ({}ms)", elapsed.as_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_line_test() {
+        assert_eq!(
+            "git rev-parse --abbrev-ref HEAD -> ok (120ms)",
+            format_line(
+                "git",
+                "rev-parse  --abbrev-ref\nHEAD",
+                "ok",
+                Duration::from_millis(120)
+            )
+        );
+    }
+}
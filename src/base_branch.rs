@@ -0,0 +1,163 @@
+use colored::Colorize;
+use futures_util::StreamExt;
+use octocrab::{models::repos::Branch, Octocrab};
+use std::{pin::pin, process::exit};
+
+use crate::{assume_defaults, flush_line, network::with_timeout, paginate, read_input};
+
+pub async fn resolve(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    configured: Option<&str>,
+) -> String {
+    let Some(branch) = configured else {
+        return match get_default_branch(octocrab, base, repo).await {
+            Some(branch) => branch,
+            None => {
+                println!("\n{}", "Couldn't get the repository default branch".red());
+                select_branch(octocrab, base, repo).await
+            }
+        };
+    };
+
+    if let Some(number) = parse_pr_reference(branch) {
+        return get_pr_head(octocrab, base, repo, number).await;
+    }
+
+    if branch_exists(octocrab, base, repo, branch).await {
+        return branch.to_owned();
+    }
+
+    println!(
+        "\n{}",
+        format!("Configured base branch `{branch}` doesn't exist in {base}/{repo}").red()
+    );
+
+    select_branch(octocrab, base, repo).await
+}
+
+async fn get_pr_head(octocrab: &Octocrab, base: &str, repo: &str, number: u64) -> String {
+    let base_pr = match with_timeout!(octocrab.pulls(base, repo).get(number)) {
+        Ok(base_pr) => base_pr,
+        Err(_) => {
+            println!("{}", format!("Couldn't fetch PR #{number}").red());
+            exit(1);
+        }
+    };
+
+    let same_repo = base_pr
+        .head
+        .repo
+        .as_ref()
+        .and_then(|r| r.full_name.as_deref())
+        .is_some_and(|name| name.eq_ignore_ascii_case(&format!("{base}/{repo}")));
+
+    if !same_repo {
+        println!(
+            "{}",
+            format!("PR #{number} comes from a fork, its branch can't be used as base").red()
+        );
+        exit(1);
+    }
+
+    println!(
+        "\nUsing {} from PR #{number} as base branch",
+        base_pr.head.ref_field.cyan()
+    );
+
+    base_pr.head.ref_field
+}
+
+fn parse_pr_reference(branch: &str) -> Option<u64> {
+    branch.strip_prefix('#')?.parse().ok()
+}
+
+pub async fn get_default_branch(octocrab: &Octocrab, base: &str, repo: &str) -> Option<String> {
+    with_timeout!(octocrab.repos(base, repo).get())
+        .ok()?
+        .default_branch
+}
+
+async fn branch_exists(octocrab: &Octocrab, base: &str, repo: &str, branch: &str) -> bool {
+    let route = format!("repos/{base}/{repo}/branches");
+    let mut branches = pin!(paginate::paginate::<Branch>(octocrab, route));
+
+    while let Some(found) = branches.next().await {
+        match found {
+            Ok(found) if found.name == branch => return true,
+            Ok(_) => {}
+            Err(_) => exit_listing_failed(base, repo),
+        }
+    }
+
+    false
+}
+
+async fn select_branch(octocrab: &Octocrab, base: &str, repo: &str) -> String {
+    if assume_defaults() {
+        println!(
+            "{}",
+            "Pass the base branch with --base to run without prompts".red()
+        );
+        exit(1);
+    }
+
+    println!("\n{}", "** Branches **".blue());
+
+    let route = format!("repos/{base}/{repo}/branches");
+    let mut stream = pin!(paginate::paginate::<Branch>(octocrab, route));
+    let mut branches = vec![];
+
+    while let Some(branch) = stream.next().await {
+        match branch {
+            Ok(branch) => {
+                println!("{} - {}", branches.len().to_string().purple(), branch.name);
+                branches.push(branch.name);
+            }
+            Err(_) => exit_listing_failed(base, repo),
+        }
+    }
+
+    loop {
+        print!("\n{}", "Select the base branch: ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.is_empty() {
+            println!(
+                "\n{}",
+                "Pass the base branch with --base to run without prompts".red()
+            );
+            exit(1);
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < branches.len() => return branches[index].clone(),
+            Ok(_) => println!("{}", "Branch not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+}
+
+fn exit_listing_failed(base: &str, repo: &str) -> ! {
+    println!(
+        "{}",
+        format!("Couldn't list branches of {base}/{repo}").red()
+    );
+    exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pr_reference_test() {
+        assert_eq!(Some(123), parse_pr_reference("#123"));
+        assert_eq!(None, parse_pr_reference("123"));
+        assert_eq!(None, parse_pr_reference("#feature"));
+        assert_eq!(None, parse_pr_reference("next"));
+    }
+}
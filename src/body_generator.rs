@@ -0,0 +1,44 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use crate::git;
+
+pub fn generate(command: &str, base_branch: &str) -> Result<String, String> {
+    let range = format!("origin/{base_branch}...{}", git::head());
+    let commits = git::try_git(&["log", "--pretty=format:%B", &range])?;
+    let diff = git::try_git(&["diff", &range])?;
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to run `{command}`: {err}"))?;
+
+    let input = build_input(&commits, &diff);
+    let mut stdin = child.stdin.take().unwrap();
+
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child.wait_with_output().map_err(|err| err.to_string())?;
+    let _ = writer.join();
+
+    if !output.status.success() {
+        return Err(format!("`{command}` exited with {}", output.status));
+    }
+
+    let body = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+
+    if body.is_empty() {
+        Err(format!("`{command}` produced no output"))
+    } else {
+        Ok(body)
+    }
+}
+
+fn build_input(commits: &str, diff: &str) -> String {
+    format!("# Commits\n\n{commits}\n\n# Diff\n\n{diff}\n")
+}
@@ -0,0 +1,127 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use serde::Deserialize;
+use std::{fmt::Display, process::exit, sync::OnceLock};
+
+use crate::network::with_timeout;
+
+static SERVER: OnceLock<Server> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Feature {
+    MergeQueue,
+    DraftConversion,
+}
+
+impl Feature {
+    const ALL: [Feature; 2] = [Feature::MergeQueue, Feature::DraftConversion];
+
+    fn min_version(self) -> (u32, u32) {
+        match self {
+            Feature::MergeQueue => (3, 12),
+            Feature::DraftConversion => (3, 2),
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Feature::MergeQueue => "merge queues",
+            Feature::DraftConversion => "converting PRs to draft",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Server {
+    GitHub,
+    Enterprise(String),
+}
+
+impl Server {
+    pub fn supports(&self, feature: Feature) -> bool {
+        match self {
+            Server::GitHub => true,
+            Server::Enterprise(version) => {
+                parse_version(version).is_none_or(|v| v >= feature.min_version())
+            }
+        }
+    }
+
+    pub fn unsupported(&self) -> Vec<Feature> {
+        Feature::ALL
+            .into_iter()
+            .filter(|f| !self.supports(*f))
+            .collect()
+    }
+}
+
+impl Display for Server {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Server::GitHub => write!(f, "github.com"),
+            Server::Enterprise(version) => write!(f, "GitHub Enterprise Server {version}"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Meta {
+    installed_version: Option<String>,
+}
+
+pub async fn server(octocrab: &Octocrab) -> &'static Server {
+    if let Some(server) = SERVER.get() {
+        return server;
+    }
+
+    let meta: octocrab::Result<Meta> = with_timeout!(octocrab.get("meta", None::<&()>));
+    let server = match meta.ok().and_then(|m| m.installed_version) {
+        Some(version) => Server::Enterprise(version),
+        None => Server::GitHub,
+    };
+
+    SERVER.get_or_init(|| server)
+}
+
+pub async fn ensure(octocrab: &Octocrab, feature: Feature) {
+    let server = server(octocrab).await;
+
+    if !server.supports(feature) {
+        let (major, minor) = feature.min_version();
+
+        println!(
+            "{}",
+            format!("{server} doesn't support {feature}, it needs {major}.{minor} or newer").red()
+        );
+        exit(1);
+    }
+}
+
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+
+    Some((major, minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supports_test() {
+        let old = Server::Enterprise("3.9.4".to_owned());
+
+        assert!(Server::GitHub.supports(Feature::MergeQueue));
+        assert!(!old.supports(Feature::MergeQueue));
+        assert!(old.supports(Feature::DraftConversion));
+        assert!(Server::Enterprise("3.12.0".to_owned()).supports(Feature::MergeQueue));
+        assert!(Server::Enterprise("unknown".to_owned()).supports(Feature::MergeQueue));
+        assert_eq!(old.unsupported(), [Feature::MergeQueue]);
+    }
+}
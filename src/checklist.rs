@@ -0,0 +1,109 @@
+use std::{collections::BTreeMap, path::Path};
+
+use crate::projects::Project;
+
+pub type Checklists = BTreeMap<String, Vec<String>>;
+
+const DEFAULT_CHECKLISTS: [(&str, &[&str]); 3] = [
+    (
+        "Cargo.toml",
+        &["`cargo test` passes", "`cargo clippy` has no warnings"],
+    ),
+    ("package.json", &["`npm test` passes", "Lint passes"]),
+    (
+        "mix.exs",
+        &["`mix test` passes", "`mix format --check-formatted` passes"],
+    ),
+];
+const COMMON_ITEMS: [&str; 1] = ["Added or updated tests"];
+
+pub fn get_checklist(
+    configured: &Checklists,
+    projects: &[Project],
+    toplevel: &Path,
+) -> Vec<String> {
+    let checklists = if configured.is_empty() {
+        default_checklists()
+    } else {
+        configured.clone()
+    };
+
+    let mut dirs: Vec<&str> = projects.iter().map(|p| p.path.as_str()).collect();
+    dirs.push("");
+
+    let manifests: Vec<&String> = checklists
+        .keys()
+        .filter(|m| dirs.iter().any(|d| toplevel.join(d).join(m).is_file()))
+        .collect();
+
+    build_checklist(&checklists, &manifests)
+}
+
+pub fn build_section(items: &[String]) -> Option<String> {
+    if items.is_empty() {
+        return None;
+    }
+
+    let entries: Vec<String> = items.iter().map(|i| format!("- [ ] {i}")).collect();
+
+    Some(format!("### Test plan\n\n{}\n", entries.join("\n")))
+}
+
+fn build_checklist(checklists: &Checklists, manifests: &[&String]) -> Vec<String> {
+    let mut items: Vec<String> = vec![];
+
+    for manifest in manifests {
+        for item in &checklists[*manifest] {
+            if !items.contains(item) {
+                items.push(item.clone());
+            }
+        }
+    }
+
+    if !items.is_empty() {
+        items.extend(COMMON_ITEMS.iter().map(|i| i.to_string()));
+    }
+
+    items
+}
+
+fn default_checklists() -> Checklists {
+    DEFAULT_CHECKLISTS
+        .iter()
+        .map(|(manifest, items)| {
+            (
+                manifest.to_string(),
+                items.iter().map(|i| i.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_checklist_test() {
+        let checklists = default_checklists();
+        let cargo = "Cargo.toml".to_owned();
+        let mix = "mix.exs".to_owned();
+
+        let items = build_checklist(&checklists, &[&cargo, &mix]);
+
+        assert_eq!(
+            build_section(&items).unwrap(),
+            "\
+### Test plan
+
+- [ ] `cargo test` passes
+- [ ] `cargo clippy` has no warnings
+- [ ] `mix test` passes
+- [ ] `mix format --check-formatted` passes
+- [ ] Added or updated tests
+"
+        );
+        assert!(build_checklist(&checklists, &[]).is_empty());
+        assert_eq!(None, build_section(&[]));
+    }
+}
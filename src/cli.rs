@@ -0,0 +1,326 @@
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+
+use crate::reviewers::ReviewerSource;
+
+/// Create pull requests for the current branch
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Run as if prmaker was started in <PATH>
+    #[arg(short = 'C', global = true, value_name = "PATH")]
+    pub directory: Option<PathBuf>,
+
+    /// Log every git invocation and API request to the audit log
+    #[arg(long, global = true)]
+    pub audit: bool,
+
+    #[cfg(feature = "replay")]
+    /// Record the GitHub API responses to a fixtures file
+    #[arg(long, global = true, value_name = "FILE", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    #[cfg(feature = "replay")]
+    /// Answer GitHub API calls from a fixtures file instead of GitHub
+    #[arg(long, global = true, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Use the credentials and defaults of a configured profile
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    #[command(flatten)]
+    pub create: CreateArgs,
+}
+
+#[derive(Args)]
+pub struct CreateArgs {
+    /// Base branch of the PR, use `#<number>` to target another PR's branch
+    #[arg(long)]
+    pub base: Option<String>,
+
+    /// Remote branch to open the PR from, defaults to the current branch
+    #[arg(long)]
+    pub head: Option<String>,
+
+    /// Repository to open the PR in when running outside a git repository
+    #[arg(long, value_name = "OWNER/REPO")]
+    pub repo: Option<String>,
+
+    /// Reviewer to request, use `@<group>` for a configured reviewer group or `<org>/<team>` for a team
+    #[arg(short, long = "reviewer", value_name = "REVIEWER")]
+    pub reviewers: Vec<String>,
+
+    /// Issue to reference instead of asking for one
+    #[arg(long = "issue", value_name = "ISSUE")]
+    pub issues: Vec<String>,
+
+    /// Related PR to list in the body, as `<org>/<repo>#<number>`
+    #[arg(long, value_name = "PR")]
+    pub related: Vec<String>,
+
+    /// Where the reviewer picker lists candidates from, overriding the config
+    #[arg(long, value_enum, value_name = "SOURCE")]
+    pub reviewer_source: Option<ReviewerSource>,
+
+    /// Store the PR and create it with `prmaker flush --due` after this time, e.g. "Mon 09:00"
+    #[arg(long, value_name = "WHEN")]
+    pub schedule: Option<String>,
+
+    /// Don't assign the PR to you
+    #[arg(long)]
+    pub no_assign: bool,
+
+    /// Don't request reviewers
+    #[arg(long)]
+    pub no_reviewers: bool,
+
+    /// Don't add the project labels
+    #[arg(long)]
+    pub no_labels: bool,
+
+    /// Use the default body instead of asking for one or generating it
+    #[arg(long)]
+    pub skip_body: bool,
+
+    /// Accept the default answer of every prompt
+    #[arg(short, long)]
+    pub yes: bool,
+
+    /// Read the PR as JSON from stdin and print the result as JSON, without prompting
+    #[arg(long)]
+    pub stdin_json: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Open a release PR whose body lists the PRs merged between two refs
+    Release {
+        /// Ref of the previous release, e.g. a tag
+        #[arg(long)]
+        from: String,
+        /// Ref being released, also used as the release PR head
+        #[arg(long, default_value = "next")]
+        to: String,
+        /// Branch the release PR is opened against
+        #[arg(long, default_value = "main")]
+        into: String,
+    },
+    /// Cherry-pick a PR onto another base branch and open a PR for it
+    Backport {
+        /// Number of the PR to backport
+        number: u64,
+        /// Branch the backport is opened against
+        #[arg(long)]
+        to: String,
+    },
+    /// Rebase or merge the base branch into the current branch and push it
+    Sync {
+        /// Branch to sync with, defaults to the configured or default branch
+        #[arg(long)]
+        base: Option<String>,
+    },
+    /// Regenerate the body of the current branch's PR, keeping manual sections
+    AmendBody,
+    /// Mark the current branch's PR as ready for review
+    Ready {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+        /// Request reviews again from requested and previous reviewers
+        #[arg(long)]
+        rerequest: bool,
+    },
+    /// Convert the current branch's PR back to draft
+    Draft {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+    },
+    /// Close the current branch's PR
+    Close {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+        /// Comment to post before closing
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// Reopen the closed PR of the current branch
+    Reopen {
+        /// Number of the PR, defaults to the current branch's closed PR
+        number: Option<u64>,
+        /// Comment to post when reopening
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// Delete the merged current branch and local branches whose PRs were merged
+    Cleanup,
+    /// Post a comment on the current branch's PR
+    Comment {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+        /// Comment to post, opens the editor when missing
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// List the PRs awaiting your review
+    Inbox,
+    /// List the PRs created with prmaker, newest first
+    History {
+        /// Text to search in the repo, branch, title or reviewers
+        query: Option<String>,
+        /// Print the entries as JSON
+        #[arg(long)]
+        json: bool,
+        /// Maximum number of entries to show
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Add the current branch's PR to the base branch merge queue
+    Queue {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+    },
+    /// Summarize the PRs in the history
+    Stats {
+        /// First day to include, as YYYY-MM-DD
+        #[arg(long)]
+        since: Option<String>,
+        /// Last day to include, as YYYY-MM-DD
+        #[arg(long)]
+        until: Option<String>,
+    },
+    /// Revert the review requests and labels of the last created PR and close it
+    Undo,
+    /// Create the scheduled PRs
+    Flush {
+        /// Only create the PRs whose scheduled time has passed
+        #[arg(long)]
+        due: bool,
+    },
+    /// Add or remove labels on the current branch's PR
+    Labels {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+    },
+    /// Open the PR of the current branch in the browser
+    Open {
+        /// Print the PR link instead of opening it
+        #[arg(long)]
+        print: bool,
+    },
+    /// Request reviews again from reviewers whose review is outdated
+    Rereview {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+        /// Comment the commits pushed since each outdated review
+        #[arg(long)]
+        summary: bool,
+    },
+    /// Submit a review on a PR, optionally using a saved reply
+    Review {
+        /// Number of the PR to review
+        number: u64,
+    },
+    /// Change the review requests of the current branch's PR
+    Reviewers {
+        #[command(subcommand)]
+        action: ReviewersAction,
+    },
+    #[cfg(feature = "vault")]
+    /// Encrypt a secret, e.g. a profile token, to store it in the config file
+    Encrypt,
+    /// Check that git, the remote, the token and the GitHub API are set up correctly
+    Doctor,
+    #[cfg(feature = "self-update")]
+    /// Replace prmaker with the binary of its latest release
+    SelfUpdate,
+    /// Run the PR creation flow in several checkouts and summarize the results
+    Batch {
+        /// Comma separated paths of the checkouts
+        #[arg(long, value_delimiter = ',', required_unless_present = "manifest")]
+        repos: Vec<PathBuf>,
+        /// File listing one checkout path per line
+        #[arg(long, conflicts_with = "repos")]
+        manifest: Option<PathBuf>,
+        /// Accept the default answer of every prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Check the PR templates for unknown variables and malformed sections and preview them
+    LintTemplate,
+    /// Re-request or swap review requests that went unanswered, using the configured reviewer pool
+    Rebalance {
+        /// Days without a review after which a request is stale
+        #[arg(long, default_value_t = 3)]
+        days: i64,
+        /// Print the planned changes without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Create the PRs listed in a TOML, JSON or YAML manifest, skipping those already open
+    Bot {
+        /// Manifest with a `prs` list of repo, head, base, title, body, labels and reviewers
+        #[arg(long)]
+        manifest: PathBuf,
+    },
+    /// Install a git hook that reacts to the first push of a branch
+    InstallHooks {
+        /// Start the PR creation flow instead of printing a reminder
+        #[arg(long)]
+        prompt: bool,
+        /// Replace an existing hook that wasn't installed by prmaker
+        #[arg(long)]
+        force: bool,
+    },
+    /// Watch for newly pushed branches without a PR and offer to create one
+    Watch {
+        /// Seconds between checks
+        #[arg(long, default_value_t = 30)]
+        interval: u64,
+        /// Check once and exit, e.g. from a git hook
+        #[arg(long)]
+        once: bool,
+    },
+    #[cfg(feature = "web")]
+    /// Serve a local form to fill in and create the PR from a browser
+    Web {
+        /// Port to listen on, defaults to a free one
+        #[arg(long, default_value_t = 0)]
+        port: u16,
+        /// Print the form URL without opening a browser
+        #[arg(long)]
+        no_open: bool,
+    },
+    /// Create PRs in the checkouts of a configured companion set and link them to each other
+    Companion {
+        /// Name of the companion set in the config file
+        set: String,
+        /// Issue to reference in every PR
+        #[arg(long)]
+        issue: Option<String>,
+        /// Reviewer to request in every PR
+        #[arg(short, long = "reviewer", value_name = "REVIEWER")]
+        reviewers: Vec<String>,
+        /// Accept the default answer of every prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    #[command(external_subcommand)]
+    External(Vec<String>),
+}
+
+#[derive(Subcommand)]
+pub enum ReviewersAction {
+    /// Request reviews from more collaborators
+    Add {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+    },
+    /// Remove requested reviewers
+    Remove {
+        /// Number of the PR, defaults to the current branch's PR
+        number: Option<u64>,
+    },
+}
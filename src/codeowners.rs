@@ -0,0 +1,129 @@
+use regex::Regex;
+
+use crate::git;
+
+const LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+struct Rule {
+    pattern: Regex,
+    owners: Vec<String>,
+}
+
+pub fn get_owning_teams(base_branch: &str) -> Vec<String> {
+    let Some(content) = LOCATIONS
+        .iter()
+        .find_map(|l| git::try_git(&["show", &format!("origin/{base_branch}:{l}")]).ok())
+    else {
+        return vec![];
+    };
+
+    let files = git::changed_files(&format!("origin/{base_branch}...{}", git::head()));
+
+    owning_teams(&parse_rules(&content), &files)
+}
+
+fn owning_teams(rules: &[Rule], files: &[String]) -> Vec<String> {
+    let mut teams: Vec<String> = vec![];
+
+    for file in files {
+        let Some(rule) = rules.iter().rev().find(|r| r.pattern.is_match(file)) else {
+            continue;
+        };
+
+        for owner in &rule.owners {
+            if owner.contains('/') && !teams.contains(owner) {
+                teams.push(owner.clone());
+            }
+        }
+    }
+
+    teams
+}
+
+fn parse_rules(content: &str) -> Vec<Rule> {
+    content
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or_default().trim())
+        .filter(|l| !l.is_empty())
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let pattern = to_regex(parts.next()?)?;
+
+            Some(Rule {
+                pattern,
+                owners: parts.map(|o| o.to_owned()).collect(),
+            })
+        })
+        .collect()
+}
+
+fn to_regex(pattern: &str) -> Option<Regex> {
+    let anchored = pattern.trim_end_matches('/').contains('/');
+    let directory = pattern.ends_with('/');
+    let pattern = pattern.trim_matches('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    regex.push_str(if directory { "/.*$" } else { "(?:/.*)?$" });
+
+    Regex::new(&regex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_regex_test() {
+        let matches = |pattern: &str, file: &str| to_regex(pattern).unwrap().is_match(file);
+
+        assert!(matches("*", "src/main.rs"));
+        assert!(matches("*.rs", "src/main.rs"));
+        assert!(matches("/src/", "src/main.rs"));
+        assert!(!matches("/src/", "lib/src/main.rs"));
+        assert!(matches("docs/", "lib/docs/index.md"));
+        assert!(matches("apps/**/mix.exs", "apps/web/mix.exs"));
+        assert!(!matches("apps/*.exs", "apps/web/mix.exs"));
+    }
+
+    #[test]
+    fn owning_teams_test() {
+        let rules = parse_rules(
+            "
+            # Default owners
+            *           @alice
+            /web/       @org/frontend @bob
+            /web/api/   @org/backend
+            ",
+        );
+        let files: Vec<String> = ["README.md", "web/app.js", "web/api/routes.js"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+
+        assert_eq!(
+            owning_teams(&rules, &files),
+            ["@org/frontend", "@org/backend"]
+        );
+        assert!(owning_teams(&rules, &files[..1]).is_empty());
+    }
+}
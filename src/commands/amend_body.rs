@@ -0,0 +1,87 @@
+use colored::Colorize;
+use std::{path::Path, process::exit};
+
+use crate::{
+    body_generator, build_octocrab, commit_picker, config::Config, contributors, get_token, git,
+    issues, network::with_timeout, pr, proceed_question, projects,
+};
+
+pub async fn run() {
+    let config = Config::load();
+    let branch = git::current_branch();
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let open_pr = pr::get_branch_pr(&octocrab, &base, &repo, None).await;
+
+    let base_branch = open_pr.base.ref_field.clone();
+
+    println!("\nFetching {base_branch}...");
+
+    git::run_or_exit(&["fetch", "origin", &base_branch]);
+
+    let changed_files = git::changed_files(&format!("origin/{base_branch}...HEAD"));
+    let projects = projects::get_touched_projects(
+        &config.projects,
+        &changed_files,
+        Path::new(&git::toplevel()),
+    );
+
+    let body = match &config.body_generator {
+        Some(command) => body_generator::generate(command, &base_branch).unwrap_or_else(|err| {
+            println!("{}", "Couldn't generate the PR body".red());
+            println!("{err}");
+            exit(1);
+        }),
+        None => build_commit_list(&base_branch),
+    };
+    let yt_issues = issues::get_yt_issues(&branch, &base_branch);
+    let contributors = contributors::get_contributors(&base_branch);
+    let generated = pr::build_full_body(config, &projects, &body, &yt_issues, &contributors);
+
+    let existing = open_pr.body.clone().unwrap_or_default();
+    let updated = match pr::replace_generated(&existing, &generated) {
+        Some(updated) => updated,
+        None => {
+            println!(
+                "\n{}",
+                "The PR body has no prmaker markers, it will be fully replaced".red()
+            );
+            generated
+        }
+    };
+
+    if updated == existing {
+        println!("\nPR #{} body is already up to date", open_pr.number);
+        return;
+    }
+
+    println!("\n{}", "** Updated body **".blue());
+    println!("{updated}");
+
+    proceed_question();
+
+    let update_resp = with_timeout!(octocrab
+        .pulls(&base, &repo)
+        .update(open_pr.number)
+        .body(&updated)
+        .send());
+
+    match update_resp {
+        Ok(_) => println!("\n{}", "PR body updated successfully".green()),
+        Err(_) => {
+            println!("\n{}", "Error when updating the PR body".red());
+            exit(1);
+        }
+    }
+}
+
+fn build_commit_list(base_branch: &str) -> String {
+    commit_picker::get_selected_subjects(base_branch)
+        .iter()
+        .map(|c| format!("- {c}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
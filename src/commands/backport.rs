@@ -0,0 +1,161 @@
+use colored::Colorize;
+use octocrab::{models::repos::RepoCommit, Octocrab};
+use std::process::exit;
+
+use crate::{
+    build_octocrab, confirm_or, get_token, git, network::with_timeout, paginate, pr,
+    proceed_question,
+};
+
+pub async fn run(number: u64, target: &str) {
+    let token = get_token();
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(token);
+
+    let original_pr = match with_timeout!(octocrab.pulls(&base, &repo).get(number)) {
+        Ok(original_pr) => original_pr,
+        Err(_) => {
+            println!("{}", format!("Couldn't fetch PR #{number}").red());
+            exit(1);
+        }
+    };
+
+    if original_pr.merged_at.is_none() {
+        println!(
+            "{}",
+            format!("PR #{number} is not merged yet, nothing to backport").red()
+        );
+        exit(1);
+    }
+
+    let shas = get_commit_shas(&octocrab, &base, &repo, number).await;
+    let original_title = original_pr.title.unwrap_or_default();
+    let branch = backport_branch(number, target);
+    let title = backport_title(&original_title, target);
+
+    println!("\n{}", "** Review backport **".blue());
+    println!("Title: {}", title.cyan());
+    println!("Commits: {}", shas.len().to_string().cyan());
+    println!("New branch: {}", branch.cyan());
+    println!("Base: {}", target.cyan());
+    println!("Remote: {}", format!("{base}/{repo}").cyan());
+
+    proceed_question();
+
+    let original_branch = git::current_branch();
+
+    println!("\nFetching {target} and PR #{number}...");
+
+    let pull_ref = format!("pull/{number}/head");
+    git::run_or_exit(&["fetch", "origin", target, &pull_ref]);
+
+    let start_point = format!("origin/{target}");
+    git::run_or_exit(&["switch", "-c", &branch, &start_point]);
+
+    println!("\nCherry-picking {} commits...", shas.len());
+
+    let mut cherry_pick_args = vec!["cherry-pick", "-x"];
+    cherry_pick_args.extend(shas.iter().map(|s| s.as_str()));
+
+    if git::try_git(&cherry_pick_args).is_err() {
+        handle_conflicts(&branch, &original_branch);
+        exit(1);
+    }
+
+    println!("\nPushing {branch}...");
+
+    git::run_or_exit(&["push", "-u", "origin", &branch]);
+
+    let mut backport_pr = pr::PR::new(
+        base,
+        repo,
+        branch,
+        target.to_owned(),
+        title,
+        backport_body(number, target),
+    );
+
+    println!("\nCreating backport PR...");
+
+    let created = backport_pr.create(&octocrab).await;
+
+    let _ = git::try_git(&["switch", &original_branch]);
+
+    if created.is_err() {
+        exit(1)
+    }
+}
+
+async fn get_commit_shas(octocrab: &Octocrab, base: &str, repo: &str, number: u64) -> Vec<String> {
+    let route = format!("repos/{base}/{repo}/pulls/{number}/commits");
+    let commits: Vec<RepoCommit> = match paginate::collect(octocrab, route).await {
+        Ok(commits) => commits,
+        Err(_) => {
+            println!("{}", format!("Couldn't list commits of PR #{number}").red());
+            exit(1);
+        }
+    };
+
+    commits
+        .into_iter()
+        .filter(|c| c.parents.len() <= 1)
+        .map(|c| c.sha)
+        .collect()
+}
+
+fn handle_conflicts(branch: &str, original_branch: &str) {
+    println!("\n{}", "Cherry-pick stopped because of conflicts in:".red());
+
+    for file in git::conflicted_files() {
+        println!("  {}", file.yellow());
+    }
+
+    if confirm_or("Abort the backport and go back to your branch?", false) {
+        let _ = git::try_git(&["cherry-pick", "--abort"]);
+        let _ = git::try_git(&["switch", original_branch]);
+        let _ = git::try_git(&["branch", "-D", branch]);
+
+        println!("\nBackport aborted");
+    } else {
+        println!(
+            "\nResolve the conflicts, run {}, then push {} and open the PR",
+            "git cherry-pick --continue".cyan(),
+            branch.cyan()
+        );
+    }
+}
+
+fn backport_branch(number: u64, target: &str) -> String {
+    format!("backport/{number}-to-{target}")
+}
+
+fn backport_title(title: &str, target: &str) -> String {
+    format!("[Backport {target}] {title}")
+}
+
+fn backport_body(number: u64, target: &str) -> String {
+    format!("Backport of #{number} to `{target}`.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backport_naming_test() {
+        assert_eq!(
+            "backport/42-to-release-1.x",
+            backport_branch(42, "release-1.x")
+        );
+        assert_eq!(
+            "[Backport release-1.x] fix: crash",
+            backport_title("fix: crash", "release-1.x")
+        );
+        assert_eq!(
+            "Backport of #42 to `release-1.x`.",
+            backport_body(42, "release-1.x")
+        );
+    }
+}
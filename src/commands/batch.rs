@@ -0,0 +1,191 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{exit, Command},
+};
+
+use crate::{build_octocrab, get_token, git, network::with_timeout, pr};
+
+pub struct BatchResult {
+    pub repo: String,
+    pub branch: String,
+    pub outcome: String,
+}
+
+pub async fn run(repos: Vec<PathBuf>, manifest: Option<PathBuf>, yes: bool) {
+    let cwd = env::current_dir().expect("failed to get the current directory");
+    let paths: Vec<PathBuf> = match manifest {
+        Some(manifest) => read_manifest(&manifest),
+        None => repos,
+    }
+    .into_iter()
+    .map(|path| cwd.join(path))
+    .collect();
+
+    let octocrab = build_octocrab(get_token());
+    let exe = env::current_exe().expect("failed to get the prmaker executable path");
+    let mut results = vec![];
+
+    for path in paths {
+        println!("\n{}", format!("** {} **", path.display()).blue());
+
+        results.push(run_for_repo(&octocrab, &exe, &path, yes).await);
+    }
+
+    println!("\n{}", "** Summary **".blue());
+    println!("{}", render_summary(&results));
+}
+
+async fn run_for_repo(octocrab: &Octocrab, exe: &Path, path: &Path, yes: bool) -> BatchResult {
+    let mut result = BatchResult {
+        repo: path.display().to_string(),
+        branch: String::new(),
+        outcome: String::new(),
+    };
+
+    if env::set_current_dir(path).is_err() {
+        result.outcome = "checkout not found".to_owned();
+        return result;
+    }
+
+    let remote_url = git::remote_url();
+
+    if remote_url.is_empty() {
+        result.outcome = "no origin remote".to_owned();
+        return result;
+    }
+
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+
+    result.repo = format!("{base}/{repo}");
+    result.branch = git::current_branch();
+
+    if let Ok(Some(open_pr)) = pr::find_open_pr(octocrab, &base, &repo, &result.branch).await {
+        result.outcome = format!("already open: #{}", open_pr.number);
+        return result;
+    }
+
+    if let Ok(Some(default_branch)) =
+        with_timeout!(octocrab.repos(&base, &repo).get()).map(|r| r.default_branch)
+    {
+        if default_branch == result.branch {
+            result.outcome = "on the default branch, skipped".to_owned();
+            return result;
+        }
+    }
+
+    let mut command = Command::new(exe);
+
+    if yes {
+        command.arg("--yes");
+    }
+
+    if command.status().is_err() {
+        result.outcome = "failed to run prmaker".to_owned();
+        return result;
+    }
+
+    result.outcome = match pr::find_open_pr(octocrab, &base, &repo, &result.branch).await {
+        Ok(Some(created)) => pr::get_pr_link(&created),
+        _ => "not created".to_owned(),
+    };
+
+    result
+}
+
+fn read_manifest(manifest: &Path) -> Vec<PathBuf> {
+    let content = fs::read_to_string(manifest).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Couldn't read manifest {}", manifest.display()).red()
+        );
+        println!("{err}");
+        exit(1);
+    });
+
+    let dir = manifest.parent().unwrap_or(Path::new("."));
+
+    parse_manifest(&content)
+        .into_iter()
+        .map(|path| dir.join(path))
+        .collect()
+}
+
+fn parse_manifest(content: &str) -> Vec<PathBuf> {
+    content
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(PathBuf::from)
+        .collect()
+}
+
+pub fn render_summary(results: &[BatchResult]) -> String {
+    let repo_width = results
+        .iter()
+        .map(|r| r.repo.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let branch_width = results
+        .iter()
+        .map(|r| r.branch.len())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+
+    let mut lines = vec![format!(
+        "{:repo_width$}  {:branch_width$}  Result",
+        "Repo", "Branch"
+    )];
+
+    for result in results {
+        lines.push(format!(
+            "{:repo_width$}  {:branch_width$}  {}",
+            result.repo, result.branch, result.outcome
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest_test() {
+        let manifest = "# services\n../api\n\n  ../web  \n";
+
+        assert_eq!(
+            parse_manifest(manifest),
+            [PathBuf::from("../api"), PathBuf::from("../web")]
+        );
+    }
+
+    #[test]
+    fn render_summary_test() {
+        let results = [
+            BatchResult {
+                repo: "org/api".to_owned(),
+                branch: "chore/bump-deps".to_owned(),
+                outcome: "https://github.com/org/api/pull/7".to_owned(),
+            },
+            BatchResult {
+                repo: "org/web-client".to_owned(),
+                branch: "main".to_owned(),
+                outcome: "on the default branch, skipped".to_owned(),
+            },
+        ];
+
+        let expected = "\
+Repo            Branch           Result
+org/api         chore/bump-deps  https://github.com/org/api/pull/7
+org/web-client  main             on the default branch, skipped";
+
+        assert_eq!(expected, render_summary(&results));
+    }
+}
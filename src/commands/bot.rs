@@ -0,0 +1,240 @@
+use colored::Colorize;
+use octocrab::{params::State, Error::GitHub, Octocrab};
+use serde::Deserialize;
+use std::{fs, future::Future, path::Path, process::exit};
+
+use super::batch::{render_summary, BatchResult};
+use crate::{build_octocrab, config::Config, get_token, network, pr, reviewers};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    prs: Vec<Spec>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Spec {
+    repo: String,
+    head: String,
+    base: Option<String>,
+    title: String,
+    #[serde(default)]
+    body: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    reviewers: Vec<String>,
+    #[serde(default)]
+    draft: bool,
+}
+
+pub async fn run(manifest: &Path) {
+    let content = fs::read_to_string(manifest).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Couldn't read manifest {}", manifest.display()).red()
+        );
+        println!("{err}");
+        exit(1);
+    });
+
+    let specs = parse_manifest(manifest, &content).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Invalid manifest {}", manifest.display()).red()
+        );
+        println!("{err}");
+        exit(1);
+    });
+
+    let octocrab = build_octocrab(get_token());
+    let config = Config::load();
+    let mut results = vec![];
+    let mut failed = false;
+
+    for spec in specs {
+        let mut result = BatchResult {
+            repo: spec.repo.clone(),
+            branch: spec.head.clone(),
+            outcome: String::new(),
+        };
+
+        match create(&octocrab, config, &spec).await {
+            Ok(outcome) => result.outcome = outcome,
+            Err(err) => {
+                failed = true;
+                result.outcome = format!("failed: {err}");
+            }
+        }
+
+        results.push(result);
+    }
+
+    println!("\n{}", "** Summary **".blue());
+    println!("{}", render_summary(&results));
+
+    if failed {
+        exit(1);
+    }
+}
+
+fn parse_manifest(path: &Path, content: &str) -> Result<Vec<Spec>, String> {
+    let manifest: Manifest = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(content).map_err(|err| err.to_string())?,
+        Some("toml") => toml::from_str(content).map_err(|err| err.to_string())?,
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => serde_yaml::from_str(content).map_err(|err| err.to_string())?,
+        #[cfg(not(feature = "yaml"))]
+        Some("yaml" | "yml") => {
+            return Err("YAML manifests need the yaml feature, use a .toml or .json one".to_owned())
+        }
+        _ => return Err("the manifest must be a .toml, .json or .yaml file".to_owned()),
+    };
+
+    for spec in &manifest.prs {
+        if spec.repo.split_once('/').is_none() {
+            return Err(format!("repo {} must be <owner>/<repo>", spec.repo));
+        }
+    }
+
+    Ok(manifest.prs)
+}
+
+async fn create(octocrab: &Octocrab, config: &Config, spec: &Spec) -> Result<String, String> {
+    let (owner, repo) = spec.repo.split_once('/').unwrap();
+
+    let open_prs = call(
+        octocrab
+            .pulls(owner, repo)
+            .list()
+            .head(format!("{owner}:{}", spec.head))
+            .state(State::Open)
+            .send(),
+    )
+    .await
+    .map_err(|err| format!("couldn't list the open PRs: {err}"))?;
+
+    if let Some(open_pr) = open_prs.into_iter().next() {
+        return Ok(format!("already open: #{}", open_pr.number));
+    }
+
+    let base = match &spec.base {
+        Some(base) => base.clone(),
+        None => call(octocrab.repos(owner, repo).get())
+            .await
+            .ok()
+            .and_then(|r| r.default_branch)
+            .ok_or("couldn't get the default branch")?,
+    };
+
+    let created = call(
+        octocrab
+            .pulls(owner, repo)
+            .create(&spec.title, &spec.head, &base)
+            .body(&spec.body)
+            .draft(spec.draft)
+            .send(),
+    )
+    .await?;
+
+    let link = pr::get_pr_link(&created);
+    let mut problems = vec![];
+
+    if !spec.labels.is_empty() {
+        let labels_resp = call(
+            octocrab
+                .issues(owner, repo)
+                .add_labels(created.number, &spec.labels),
+        )
+        .await;
+
+        if labels_resp.is_err() {
+            problems.push("labels");
+        }
+    }
+
+    let selection = reviewers::expand(&spec.reviewers, &config.reviewer_groups);
+
+    if !selection.is_empty() {
+        let reviews_resp = call(octocrab.pulls(owner, repo).request_reviews(
+            created.number,
+            selection.usernames.clone(),
+            selection.teams.clone(),
+        ))
+        .await;
+
+        if reviews_resp.is_err() {
+            problems.push("reviewers");
+        }
+    }
+
+    match problems.is_empty() {
+        true => Ok(link),
+        false => Ok(format!("{link} (couldn't add {})", problems.join(", "))),
+    }
+}
+
+async fn call<T>(request: impl Future<Output = octocrab::Result<T>>) -> Result<T, String> {
+    match network::within_timeout(request).await? {
+        Ok(value) => Ok(value),
+        Err(GitHub { source, .. }) => Err(source.message),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest_test() {
+        let toml = r#"
+            [[prs]]
+            repo = "acme/api"
+            head = "deps/bump-serde"
+            title = "chore(deps): bump serde"
+            labels = ["dependencies"]
+        "#;
+        let json = r#"{"prs": [{"repo": "acme/api", "head": "deps/bump-serde", "title": "chore(deps): bump serde", "labels": ["dependencies"]}]}"#;
+        let expected = vec![Spec {
+            repo: "acme/api".to_owned(),
+            head: "deps/bump-serde".to_owned(),
+            base: None,
+            title: "chore(deps): bump serde".to_owned(),
+            body: String::new(),
+            labels: vec!["dependencies".to_owned()],
+            reviewers: vec![],
+            draft: false,
+        }];
+
+        assert_eq!(
+            Ok(&expected),
+            parse_manifest(Path::new("prs.toml"), toml).as_ref()
+        );
+        assert_eq!(
+            Ok(&expected),
+            parse_manifest(Path::new("prs.json"), json).as_ref()
+        );
+        #[cfg(feature = "yaml")]
+        assert_eq!(
+            Ok(&expected),
+            parse_manifest(
+                Path::new("prs.yaml"),
+                "prs:\n  - repo: acme/api\n    head: deps/bump-serde\n    title: \"chore(deps): bump serde\"\n    labels: [dependencies]\n"
+            )
+            .as_ref()
+        );
+        #[cfg(not(feature = "yaml"))]
+        assert!(parse_manifest(Path::new("prs.yaml"), "prs: []")
+            .unwrap_err()
+            .contains("need the yaml feature"));
+        assert_eq!(
+            Err("repo api must be <owner>/<repo>".to_owned()),
+            parse_manifest(
+                Path::new("prs.toml"),
+                "[[prs]]\nrepo = \"api\"\nhead = \"x\"\ntitle = \"y\""
+            )
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use colored::Colorize;
+use octocrab::{models::pulls::PullRequest, params::State, Octocrab};
+
+use crate::{
+    base_branch, build_octocrab, config::Config, confirm_or, get_token, git, network::with_timeout,
+    paginate, pr, profiles,
+};
+
+pub async fn run() {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+    let current_branch = git::current_branch();
+    let default_branch = base_branch::get_default_branch(&octocrab, &base, &repo).await;
+    let open_prs: Vec<PullRequest> =
+        match paginate::collect(&octocrab, format!("repos/{base}/{repo}/pulls")).await {
+            Ok(open_prs) => open_prs,
+            Err(_) => {
+                println!("{}", "Couldn't list the open PRs".red());
+                return;
+            }
+        };
+    let protected = protected_branches(
+        default_branch,
+        [
+            Config::load().base_branch.clone(),
+            profiles::active().and_then(|p| p.base_branch.clone()),
+        ],
+        open_prs.into_iter().map(|p| p.base.ref_field),
+    );
+    let protected = |branch: &str| protected.iter().any(|p| p == branch);
+
+    if let Some(merged_pr) = find_merged_pr(&octocrab, &base, &repo, &current_branch)
+        .await
+        .filter(|p| !protected(&current_branch) && is_merged(&current_branch, p))
+    {
+        let base_branch = merged_pr.base.ref_field.clone();
+
+        println!(
+            "\nPR #{} of {} was merged into {}",
+            merged_pr.number,
+            current_branch.cyan(),
+            base_branch.cyan()
+        );
+
+        if confirm_or(
+            &format!(
+                "Delete {current_branch} locally and on the remote and switch to {base_branch}?"
+            ),
+            false,
+        ) {
+            if git::try_git(&["push", "origin", "--delete", &current_branch]).is_err() {
+                println!("{}", "Remote branch already deleted, skipping...".yellow());
+            }
+
+            git::run_or_exit(&["switch", &base_branch]);
+            git::run_or_exit(&["pull", "--ff-only", "origin", &base_branch]);
+            match git::try_git(&["branch", "-D", &current_branch]) {
+                Ok(_) => println!("\n{}", format!("Deleted {current_branch}").green()),
+                Err(_) => println!("\n{}", format!("Couldn't delete {current_branch}").red()),
+            }
+        }
+    }
+
+    let current_branch = git::current_branch();
+    let local_branches = git::try_git(&["for-each-ref", "--format=%(refname:short)", "refs/heads"])
+        .unwrap_or_default();
+
+    let mut merged = vec![];
+
+    for branch in local_branches
+        .lines()
+        .filter(|b| *b != current_branch && !protected(b))
+    {
+        if find_merged_pr(&octocrab, &base, &repo, branch)
+            .await
+            .is_some_and(|p| is_merged(branch, &p))
+        {
+            merged.push(branch.to_owned());
+        }
+    }
+
+    if merged.is_empty() {
+        println!("\nNo other local branches with merged PRs");
+        return;
+    }
+
+    println!("\n{}", "** Local branches with merged PRs **".blue());
+
+    for branch in &merged {
+        println!("{branch}");
+    }
+
+    if confirm_or("Delete them?", false) {
+        for branch in &merged {
+            match git::try_git(&["branch", "-D", branch]) {
+                Ok(_) => println!("Deleted {}", branch.green()),
+                Err(_) => println!("{}", format!("Couldn't delete {branch}").red()),
+            }
+        }
+    }
+}
+
+async fn find_merged_pr(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    branch: &str,
+) -> Option<PullRequest> {
+    let pulls = with_timeout!(octocrab
+        .pulls(base, repo)
+        .list()
+        .head(format!("{base}:{branch}"))
+        .state(State::Closed)
+        .send())
+    .ok()?;
+
+    pulls.into_iter().find(|p| p.merged_at.is_some())
+}
+
+fn is_merged(branch: &str, merged_pr: &PullRequest) -> bool {
+    let base_branch = &merged_pr.base.ref_field;
+
+    if branch == base_branch {
+        return false;
+    }
+
+    let tip = git::try_git(&["rev-parse", branch]).unwrap_or_default();
+    let base_ref = format!("origin/{base_branch}");
+
+    tip == merged_pr.head.sha
+        || git::try_git(&["merge-base", "--is-ancestor", branch, &base_ref]).is_ok()
+}
+
+fn protected_branches(
+    default_branch: Option<String>,
+    configured: [Option<String>; 2],
+    open_pr_bases: impl Iterator<Item = String>,
+) -> Vec<String> {
+    default_branch
+        .into_iter()
+        .chain(configured.into_iter().flatten())
+        .chain(open_pr_bases)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protected_branches_test() {
+        let protected = protected_branches(
+            Some("main".to_owned()),
+            [Some("develop".to_owned()), Some("release".to_owned())],
+            ["next".to_owned(), "main".to_owned()].into_iter(),
+        );
+
+        assert_eq!(protected, ["main", "develop", "release", "next", "main"]);
+        assert!(protected_branches(None, [None, None], std::iter::empty()).is_empty());
+    }
+}
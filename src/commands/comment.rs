@@ -0,0 +1,39 @@
+use colored::Colorize;
+use std::process::exit;
+
+use crate::{build_octocrab, editor, get_token, git, network::with_timeout, pr};
+
+pub async fn run(number: Option<u64>, message: Option<String>) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+
+    let message = match message {
+        Some(message) => message,
+        None => editor::edit("").unwrap_or_else(|err| {
+            println!("{}", "Couldn't edit the comment".red());
+            println!("{err}");
+            exit(1);
+        }),
+    };
+
+    if message.trim().is_empty() {
+        println!("\nEmpty comment, nothing to post");
+        return;
+    }
+
+    let comment_resp = with_timeout!(octocrab
+        .issues(&base, &repo)
+        .create_comment(branch_pr.number, message.trim()));
+
+    match comment_resp {
+        Ok(comment) => println!("\n{} {}", "Comment posted:".green(), comment.html_url),
+        Err(_) => {
+            println!("\n{}", "Error when posting the comment".red());
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use std::{
+    env,
+    path::Path,
+    process::{exit, Command},
+};
+
+use crate::{build_octocrab, config::Config, get_token, git, network::with_timeout, pr};
+
+const SECTION_TITLE: &str = "### Companion PRs";
+
+struct Companion {
+    base: String,
+    repo: String,
+    number: u64,
+    body: String,
+}
+
+impl Companion {
+    fn reference(&self) -> String {
+        format!("{}/{}#{}", self.base, self.repo, self.number)
+    }
+}
+
+pub async fn run(set: &str, issue: Option<String>, reviewers: Vec<String>, yes: bool) {
+    let config = Config::load();
+    let Some(paths) = config.companions.get(set) else {
+        println!("{}", format!("Unknown companion set {set}").red());
+        exit(1);
+    };
+
+    let cwd = env::current_dir().expect("failed to get the current directory");
+    let exe = env::current_exe().expect("failed to get the prmaker executable path");
+    let octocrab = build_octocrab(get_token());
+    let mut args: Vec<String> = reviewers
+        .iter()
+        .flat_map(|r| ["-r".to_owned(), r.clone()])
+        .collect();
+
+    if let Some(issue) = &issue {
+        args.extend(["--issue".to_owned(), issue.clone()]);
+    }
+
+    if yes {
+        args.push("--yes".to_owned());
+    }
+
+    let mut companions = vec![];
+
+    for path in paths {
+        let path = cwd.join(path);
+        println!("\n{}", format!("** {} **", path.display()).blue());
+
+        match create_in(&octocrab, &exe, &path, &args).await {
+            Some(companion) => companions.push(companion),
+            None => println!("{}", "No PR was created, skipping it...".red()),
+        }
+    }
+
+    if companions.len() < 2 {
+        println!("\n{}", "Not enough companion PRs to link".yellow());
+        return;
+    }
+
+    println!("\nLinking the companion PRs...");
+
+    let references: Vec<String> = companions.iter().map(|c| c.reference()).collect();
+
+    for companion in &companions {
+        let reference = companion.reference();
+        let others: Vec<String> = references
+            .iter()
+            .filter(|r| **r != reference)
+            .cloned()
+            .collect();
+        let body = with_section(&companion.body, &others);
+
+        let update_resp = with_timeout!(octocrab
+            .pulls(&companion.base, &companion.repo)
+            .update(companion.number)
+            .body(&body)
+            .send());
+
+        match update_resp {
+            Ok(_) => println!("{} {reference}", "Linked".green()),
+            Err(_) => println!("{}", format!("Couldn't link {reference}").red()),
+        }
+    }
+}
+
+async fn create_in(
+    octocrab: &Octocrab,
+    exe: &Path,
+    path: &Path,
+    args: &[String],
+) -> Option<Companion> {
+    env::set_current_dir(path).ok()?;
+
+    let remote_url = git::remote_url();
+    let (base, repo) = pr::parse_remote(&remote_url)?;
+    let branch = git::current_branch();
+
+    if let Ok(Some(open_pr)) = pr::find_open_pr(octocrab, &base, &repo, &branch).await {
+        println!("PR #{} is already open, linking it", open_pr.number);
+    } else {
+        Command::new(exe).args(args).status().ok()?;
+    }
+
+    let created = pr::find_open_pr(octocrab, &base, &repo, &branch)
+        .await
+        .ok()??;
+
+    Some(Companion {
+        base,
+        repo,
+        number: created.number,
+        body: created.body.unwrap_or_default(),
+    })
+}
+
+fn with_section(body: &str, references: &[String]) -> String {
+    let entries: Vec<String> = references.iter().map(|r| format!("- {r}")).collect();
+    let section = format!("{SECTION_TITLE}\n\n{}\n", entries.join("\n"));
+
+    let kept = match body.find(SECTION_TITLE) {
+        Some(start) => {
+            let rest = &body[start + SECTION_TITLE.len()..];
+            let end = rest
+                .find("\n#")
+                .map(|e| start + SECTION_TITLE.len() + e + 1);
+
+            match end {
+                Some(end) => format!("{}{}", &body[..start], &body[end..]),
+                None => body[..start].to_owned(),
+            }
+        }
+        None => body.to_owned(),
+    };
+
+    format!("{}\n\n{section}", kept.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_section_test() {
+        let references = ["acme/web#12".to_owned(), "acme/client#3".to_owned()];
+
+        assert_eq!(
+            "Adds login\n\n### Companion PRs\n\n- acme/web#12\n- acme/client#3\n",
+            with_section("Adds login\n", &references)
+        );
+        assert_eq!(
+            "Adds login\n\n## Notes\n\nkeep\n\n### Companion PRs\n\n- acme/web#12\n- acme/client#3\n",
+            with_section(
+                "Adds login\n\n### Companion PRs\n\n- acme/old#1\n\n## Notes\n\nkeep\n",
+                &references
+            )
+        );
+    }
+}
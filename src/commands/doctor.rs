@@ -0,0 +1,187 @@
+use chrono::{DateTime, Local};
+use colored::Colorize;
+use octocrab::Octocrab;
+use std::{env, fs, process::exit};
+
+use crate::{
+    build_octocrab, capabilities,
+    config::{self, Config},
+    forge, git,
+    network::with_timeout,
+    pr, schema, token, GITHUB_TOKEN_VAR, GITHUB_USER_VAR,
+};
+
+type Check = Result<String, String>;
+
+pub async fn run() {
+    println!("\n{}", "** Doctor **".blue());
+
+    let mut failed = false;
+    let mut report = |name: &str, check: Check| {
+        match &check {
+            Ok(detail) => println!("{} {name}: {detail}", "PASS".green()),
+            Err(error) => println!("{} {name}: {}", "FAIL".red(), error.yellow()),
+        }
+
+        failed |= check.is_err();
+        check.ok()
+    };
+
+    let remote = report("git", check_git())
+        .and_then(|_| report("repository", check_repository()))
+        .and_then(|_| report("remote", check_remote()));
+
+    report("config", check_config());
+
+    if remote.is_some() {
+        report("forge", check_forge(&Config::load().forges));
+    }
+
+    report(
+        "GitHub user",
+        env::var(GITHUB_USER_VAR).map_err(|_| unset(GITHUB_USER_VAR)),
+    );
+
+    let token = env::var(GITHUB_TOKEN_VAR).ok();
+
+    report(
+        "GitHub token",
+        token
+            .as_ref()
+            .map(|_| "set".to_owned())
+            .ok_or_else(|| unset(GITHUB_TOKEN_VAR)),
+    );
+
+    let has_token = token.is_some();
+    let octocrab = token.map(build_octocrab).unwrap_or_default();
+
+    if report("API", check_api(&octocrab).await).is_some() {
+        report("server", Ok(describe_server(&octocrab).await));
+
+        if has_token {
+            report("token scopes", check_scopes(&octocrab).await);
+        }
+    }
+
+    if let Some((base, repo)) = remote.and_then(|_| pr::parse_remote(&git::remote_url())) {
+        report(
+            "base branch",
+            check_base_branch(&octocrab, &base, &repo).await,
+        );
+    }
+
+    if failed {
+        exit(1);
+    }
+}
+
+fn unset(var: &str) -> String {
+    format!("{var} environment variable is not set")
+}
+
+fn check_git() -> Check {
+    git::try_git(&["--version"])
+}
+
+fn check_repository() -> Check {
+    match git::toplevel() {
+        toplevel if toplevel.is_empty() => Err("not inside a git repository".to_owned()),
+        toplevel => Ok(toplevel),
+    }
+}
+
+fn check_remote() -> Check {
+    let remote_url = git::remote_url();
+
+    match pr::parse_remote(&remote_url) {
+        Some((base, repo)) => Ok(format!("{base}/{repo}")),
+        None if remote_url.is_empty() => Err("no origin remote".to_owned()),
+        None => Err(format!("can't parse {remote_url}")),
+    }
+}
+
+fn check_config() -> Check {
+    let Some(path) = config::find_config_file() else {
+        return Ok("not found, using defaults".to_owned());
+    };
+
+    let content = fs::read_to_string(&path).map_err(|err| err.to_string())?;
+    let diagnostics: Vec<String> = schema::check(&content)
+        .into_iter()
+        .map(|d| format!("line {}: {}", d.line, d.message))
+        .collect();
+
+    match diagnostics.is_empty() {
+        true => Ok(path.display().to_string()),
+        false => Err(format!("{}, {}", path.display(), diagnostics.join("; "))),
+    }
+}
+
+fn check_forge(mapping: &forge::Mapping) -> Check {
+    let host = forge::resolve(&git::remote_url(), mapping)
+        .map_err(|host| format!("unsupported forge {host}, map it under [forges] in the config"))?;
+    let missing = host.capabilities().missing();
+
+    if missing.is_empty() {
+        Ok(host.to_string())
+    } else {
+        Ok(format!("{host}, without {}", missing.join(", ")))
+    }
+}
+
+async fn check_api(octocrab: &Octocrab) -> Check {
+    let rate_limit = with_timeout!(octocrab.ratelimit().get())
+        .map_err(|_| "GitHub is unreachable".to_owned())?;
+    let core = rate_limit.resources.core;
+    let reset = DateTime::from_timestamp(core.reset as i64, 0)
+        .map(|r| r.with_timezone(&Local).format("%H:%M").to_string())
+        .unwrap_or_default();
+
+    if core.remaining == 0 {
+        return Err(format!("rate limit exhausted, resets at {reset}"));
+    }
+
+    Ok(format!(
+        "{}/{} requests left, resets at {reset}",
+        core.remaining, core.limit
+    ))
+}
+
+async fn describe_server(octocrab: &Octocrab) -> String {
+    let server = capabilities::server(octocrab).await;
+    let unsupported: Vec<String> = server.unsupported().iter().map(|f| f.to_string()).collect();
+
+    if unsupported.is_empty() {
+        server.to_string()
+    } else {
+        format!("{server}, without {}", unsupported.join(", "))
+    }
+}
+
+async fn check_scopes(octocrab: &Octocrab) -> Check {
+    let Some(scopes) = token::get_scopes(octocrab).await? else {
+        return Ok("fine-grained or app token, scopes can't be listed".to_owned());
+    };
+
+    let missing = token::missing_scopes(&scopes, &token::REQUIRED_SCOPES);
+
+    if missing.is_empty() {
+        Ok(scopes)
+    } else {
+        Err(format!("missing {}", missing.join(", ")))
+    }
+}
+
+async fn check_base_branch(octocrab: &Octocrab, base: &str, repo: &str) -> Check {
+    let branch = match Config::load().base_branch() {
+        Some(branch) => branch.to_owned(),
+        None => with_timeout!(octocrab.repos(base, repo).get())
+            .ok()
+            .and_then(|r| r.default_branch)
+            .ok_or("couldn't get the default branch")?,
+    };
+
+    git::try_git(&["ls-remote", "--exit-code", "--heads", "origin", &branch])
+        .map(|_| branch.clone())
+        .map_err(|_| format!("{branch} doesn't exist on origin"))
+}
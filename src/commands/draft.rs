@@ -0,0 +1,118 @@
+use colored::Colorize;
+use octocrab::{models::pulls::PullRequest, Octocrab};
+use serde::{de::IgnoredAny, Deserialize};
+use std::process::exit;
+
+use crate::{
+    build_octocrab,
+    capabilities::{self, Feature},
+    get_token, git,
+    network::with_timeout,
+    pr,
+    reviewers::{self, Selection},
+};
+
+#[derive(Deserialize)]
+struct GraphqlResponse {
+    errors: Option<Vec<IgnoredAny>>,
+}
+
+pub async fn ready(number: Option<u64>, rerequest: bool) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+
+    if branch_pr.draft != Some(true) {
+        println!("\nPR #{} is already ready for review", branch_pr.number);
+    } else {
+        set_draft(&octocrab, &branch_pr, false).await;
+    }
+
+    if rerequest {
+        let selection = Selection {
+            usernames: get_previous_reviewers(&octocrab, &base, &repo, &branch_pr).await,
+            teams: vec![],
+        };
+
+        reviewers::request_reviews(&octocrab, &base, &repo, branch_pr.number, selection).await;
+    }
+}
+
+pub async fn draft(number: Option<u64>) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+
+    if branch_pr.draft == Some(true) {
+        println!("\nPR #{} is already a draft", branch_pr.number);
+        return;
+    }
+
+    set_draft(&octocrab, &branch_pr, true).await;
+}
+
+async fn set_draft(octocrab: &Octocrab, branch_pr: &PullRequest, draft: bool) {
+    capabilities::ensure(octocrab, Feature::DraftConversion).await;
+
+    let mutation = if draft {
+        "convertPullRequestToDraft"
+    } else {
+        "markPullRequestReadyForReview"
+    };
+    let node_id = branch_pr.node_id.clone().unwrap_or_default();
+    let query = format!(
+        "mutation {{ {mutation}(input: {{ pullRequestId: \"{node_id}\" }}) {{ clientMutationId }} }}"
+    );
+
+    let resp: octocrab::Result<GraphqlResponse> = with_timeout!(octocrab.graphql(&query));
+
+    match resp {
+        Ok(GraphqlResponse { errors: None }) if draft => {
+            println!("\n{}", "PR converted to draft".green())
+        }
+        Ok(GraphqlResponse { errors: None }) => {
+            println!("\n{}", "PR marked as ready for review".green())
+        }
+        _ => {
+            println!("\n{}", "Error when changing the PR draft state".red());
+            exit(1);
+        }
+    }
+}
+
+async fn get_previous_reviewers(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    branch_pr: &PullRequest,
+) -> Vec<String> {
+    let author = branch_pr.user.as_ref().map(|u| u.login.clone());
+    let mut logins: Vec<String> = branch_pr
+        .requested_reviewers
+        .iter()
+        .flatten()
+        .map(|u| u.login.clone())
+        .collect();
+
+    let reviews = match with_timeout!(octocrab.pulls(base, repo).list_reviews(branch_pr.number)) {
+        Ok(reviews) => reviews.items,
+        Err(_) => {
+            println!("\n{}", "Error fetching reviews, ignoring...".red());
+            vec![]
+        }
+    };
+
+    for login in reviews.into_iter().filter_map(|r| r.user.map(|u| u.login)) {
+        if !logins.contains(&login) && Some(&login) != author.as_ref() {
+            logins.push(login);
+        }
+    }
+
+    logins
+}
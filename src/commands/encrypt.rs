@@ -0,0 +1,19 @@
+use colored::Colorize;
+use std::process::exit;
+
+use crate::vault;
+
+pub fn run() {
+    let secret = vault::read_hidden("Value to encrypt: ");
+
+    if secret.is_empty() {
+        println!("{}", "Nothing to encrypt".red());
+        exit(1);
+    }
+
+    println!("\n{}", vault::encrypt(&secret, vault::passphrase()));
+    println!(
+        "\nPaste it as the config value, it's decrypted with the passphrase from {}, the keychain or a prompt",
+        "PRMAKER_PASSPHRASE".cyan()
+    );
+}
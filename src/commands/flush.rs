@@ -0,0 +1,84 @@
+use chrono::{Local, Utc};
+use colored::Colorize;
+
+use crate::{
+    build_octocrab,
+    config::Config,
+    get_token, get_user, pr,
+    reviewers::{self, Selection},
+    schedule,
+};
+
+pub async fn run(due_only: bool) {
+    let scheduled = schedule::load();
+    let now = Utc::now();
+
+    let (due, pending): (Vec<_>, Vec<_>) = scheduled
+        .into_iter()
+        .partition(|s| !due_only || s.due <= now);
+
+    if due.is_empty() {
+        println!("\nNo scheduled PRs to submit");
+
+        for scheduled in &pending {
+            println!(
+                "{} {} {}",
+                scheduled
+                    .due
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+                    .purple(),
+                format!("{}/{}", scheduled.base, scheduled.repo).cyan(),
+                scheduled.title
+            );
+        }
+
+        return;
+    }
+
+    let user = get_user();
+    let octocrab = build_octocrab(get_token());
+    let mut failed = vec![];
+
+    for scheduled in due {
+        println!("\nCreating PR {}...", scheduled.title.cyan());
+
+        let mut pr = pr::PR::new(
+            scheduled.base.clone(),
+            scheduled.repo.clone(),
+            scheduled.branch.clone(),
+            scheduled.base_branch.clone(),
+            scheduled.title.clone(),
+            scheduled.body.clone(),
+        );
+
+        if pr.create(&octocrab).await.is_err() {
+            failed.push(scheduled);
+            continue;
+        }
+
+        pr.patch_late_vars(&octocrab, &Config::load().late_vars)
+            .await;
+        pr.assign_self(&octocrab, &user).await;
+
+        let selection = Selection {
+            usernames: scheduled.reviewers,
+            teams: scheduled.teams,
+        };
+
+        reviewers::request_reviews(&octocrab, &pr.base, &pr.repo, pr.number.unwrap(), selection)
+            .await;
+
+        println!("\nPR: {}", pr.link.unwrap());
+    }
+
+    if !failed.is_empty() {
+        println!(
+            "\n{}",
+            format!("{} scheduled PRs failed and were kept", failed.len()).red()
+        );
+    }
+
+    schedule::save(&[failed, pending].concat());
+}
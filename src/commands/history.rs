@@ -0,0 +1,35 @@
+use colored::Colorize;
+
+use crate::history;
+
+pub fn run(query: Option<String>, json: bool, limit: usize) {
+    let entries = history::load();
+    let mut found = history::search(&entries, query.as_deref());
+
+    found.reverse();
+    found.truncate(limit);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&found).unwrap());
+        return;
+    }
+
+    if found.is_empty() {
+        println!("\nNo PRs found in the history");
+        return;
+    }
+
+    for entry in found {
+        println!(
+            "{} {} {} {}",
+            entry
+                .created_at
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+                .purple(),
+            format!("{}#{}", entry.repo, entry.number).cyan(),
+            entry.title,
+            entry.link
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use colored::Colorize;
+use octocrab::models::issues::Issue;
+use std::process::exit;
+
+use crate::{
+    build_octocrab,
+    commands::{open, review},
+    config::Config,
+    flush_line, get_token, git,
+    network::with_timeout,
+    pr, read_input,
+};
+
+pub async fn run() {
+    let config = Config::load();
+    let octocrab = build_octocrab(get_token());
+    let query = build_query(&config.inbox);
+
+    let search_resp = with_timeout!(octocrab
+        .search()
+        .issues_and_pull_requests(&query)
+        .per_page(100)
+        .send());
+
+    let pulls = match search_resp {
+        Ok(pulls) => pulls.items,
+        Err(_) => {
+            println!("{}", "Couldn't search the PRs awaiting your review".red());
+            exit(1);
+        }
+    };
+
+    if pulls.is_empty() {
+        println!("\nNo PRs awaiting your review");
+        return;
+    }
+
+    let current_repo = get_current_repo();
+
+    loop {
+        println!("\n{}", "** Awaiting your review **".blue());
+
+        for (index, pull) in pulls.iter().enumerate() {
+            println!(
+                "{} - {} {} {}",
+                index.to_string().purple(),
+                format!("{}#{}", get_repo_name(pull), pull.number).cyan(),
+                pull.title,
+                format!("@{}", pull.user.login).purple()
+            );
+        }
+
+        print!("\n{}", "Select a PR (empty to quit): ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.trim().is_empty() {
+            break;
+        }
+
+        let pull = match opt.trim().parse::<usize>() {
+            Ok(index) if index < pulls.len() => &pulls[index],
+            Ok(_) => {
+                println!("{}", "PR not found".red());
+                continue;
+            }
+            Err(_) => {
+                println!("{}", "Invalid option, it must be a valid number".red());
+                continue;
+            }
+        };
+
+        let in_current_repo = current_repo.as_deref() == Some(get_repo_name(pull).as_str());
+
+        print!(
+            "\n{}",
+            format!(
+                "{} to open, {} to checkout, {} to review: ",
+                "o".purple(),
+                "c".purple(),
+                "r".purple()
+            )
+            .yellow()
+        );
+        flush_line();
+
+        match read_input().trim() {
+            "o" => {
+                if !open::open_in_browser(pull.html_url.as_str()) {
+                    println!("{}", pull.html_url);
+                }
+            }
+            "c" | "r" if !in_current_repo => println!(
+                "{}",
+                format!("Run prmaker inside {} to do that", get_repo_name(pull)).red()
+            ),
+            "c" => checkout(pull),
+            "r" => review::run(pull.number).await,
+            _ => println!("{}", "Invalid option".red()),
+        }
+    }
+}
+
+fn checkout(pull: &Issue) {
+    let pull_ref = format!("pull/{}/head", pull.number);
+    let branch = format!("pr-{}", pull.number);
+
+    git::run_or_exit(&["fetch", "origin", &format!("{pull_ref}:{branch}")]);
+    git::run_or_exit(&["switch", &branch]);
+
+    println!("\n{}", format!("Switched to {branch}").green());
+}
+
+fn get_current_repo() -> Option<String> {
+    let remote_url = git::remote_url();
+
+    if remote_url.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "{}/{}",
+        pr::get_base(&remote_url),
+        pr::get_repo(&remote_url)
+    ))
+}
+
+fn get_repo_name(pull: &Issue) -> String {
+    pull.repository_url
+        .path()
+        .trim_start_matches("/repos/")
+        .to_owned()
+}
+
+fn build_query(scopes: &[String]) -> String {
+    let mut query = "is:pr is:open review-requested:@me".to_owned();
+
+    for scope in scopes {
+        if scope.contains('/') {
+            query.push_str(&format!(" repo:{scope}"));
+        } else {
+            query.push_str(&format!(" org:{scope}"));
+        }
+    }
+
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_query_test() {
+        assert_eq!(build_query(&[]), "is:pr is:open review-requested:@me");
+        assert_eq!(
+            build_query(&["acme".to_owned(), "other/tool".to_owned()]),
+            "is:pr is:open review-requested:@me org:acme repo:other/tool"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use colored::Colorize;
+use std::{fs, path::PathBuf, process::exit};
+
+use crate::git;
+
+const HOOK: &str = "reference-transaction";
+const MARKER: &str = "# Installed by prmaker install-hooks";
+
+pub fn run(prompt: bool, force: bool) {
+    let path = PathBuf::from(git::git_path("hooks")).join(HOOK);
+
+    if let Ok(hooks_path) = git::try_git(&["config", "--get", "core.hooksPath"]) {
+        println!(
+            "{}",
+            format!(
+                "core.hooksPath is set to {hooks_path}, hooks there are usually managed by a tool like husky"
+            )
+            .yellow()
+        );
+        println!("If it regenerates its hooks, call prmaker from its {HOOK} hook instead");
+    }
+
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if !existing.contains(MARKER) && !force {
+            println!(
+                "{}",
+                format!(
+                    "{} already exists and wasn't installed by prmaker",
+                    path.display()
+                )
+                .red()
+            );
+            println!("Use --force to replace it");
+            exit(1);
+        }
+    }
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    if let Err(err) = fs::write(&path, hook_script(prompt)) {
+        println!("{}", format!("Couldn't write {}", path.display()).red());
+        println!("{err}");
+        exit(1);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o755));
+    }
+
+    println!("\n{} {}", "Hook installed:".green(), path.display());
+
+    match prompt {
+        true => println!("The PR creation flow starts after the first push of a branch"),
+        false => println!("A reminder is printed after the first push of a branch"),
+    }
+}
+
+fn hook_script(prompt: bool) -> String {
+    let action = match prompt {
+        true => r#"  prmaker --head "$branch" </dev/tty >/dev/tty 2>&1"#,
+        false => {
+            r#"  echo "prmaker: $branch was pushed for the first time, open its PR with: prmaker --head $branch" >&2"#
+        }
+    };
+
+    format!(
+        r#"#!/bin/sh
+{MARKER}
+[ "$1" = committed ] || exit 0
+
+while read -r _ new ref; do
+  case "$new" in *[!0]*) ;; *) continue ;; esac
+  case "$ref" in refs/remotes/origin/*) ;; *) continue ;; esac
+  [ "$(git reflog show --format=%H "$ref" 2>/dev/null | wc -l)" -le 1 ] || continue
+  branch=${{ref#refs/remotes/origin/}}
+  [ "$branch" = HEAD ] && continue
+  git show-ref --verify --quiet "refs/heads/$branch" || continue
+{action}
+done
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hook_script_test() {
+        let reminder = hook_script(false);
+        let prompt = hook_script(true);
+
+        assert!(reminder.starts_with("#!/bin/sh\n# Installed by prmaker install-hooks\n"));
+        assert!(reminder.contains("branch=${ref#refs/remotes/origin/}"));
+        assert!(reminder.contains(r#"case "$new" in *[!0]*) ;; *) continue ;; esac"#));
+        assert!(reminder.contains("open its PR with: prmaker --head $branch"));
+        assert!(prompt.contains(r#"prmaker --head "$branch" </dev/tty"#));
+        assert!(!prompt.contains("echo"));
+    }
+}
@@ -0,0 +1,47 @@
+use colored::Colorize;
+
+use crate::{build_octocrab, get_token, git, labels, network::with_timeout, pr};
+
+pub async fn run(number: Option<u64>) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+    let current: Vec<String> = branch_pr
+        .labels
+        .iter()
+        .flatten()
+        .map(|l| l.name.clone())
+        .collect();
+
+    let Some(available) = labels::get_repo_labels(&octocrab, &base, &repo).await else {
+        return;
+    };
+
+    let selected = labels::get_selected_labels(&available, &current);
+    let (added, removed) = labels::get_changes(&current, &selected);
+
+    if added.is_empty() && removed.is_empty() {
+        println!("\nNo label changes");
+        return;
+    }
+
+    let labels_resp = with_timeout!(octocrab
+        .issues(&base, &repo)
+        .replace_all_labels(branch_pr.number, &selected));
+
+    match labels_resp {
+        Ok(_) => {
+            if !added.is_empty() {
+                println!("\nAdded: {}", added.join(", ").green());
+            }
+
+            if !removed.is_empty() {
+                println!("Removed: {}", removed.join(", ").red());
+            }
+        }
+        Err(_) => println!("\n{}", "Error when updating labels".red()),
+    }
+}
@@ -0,0 +1,125 @@
+use colored::Colorize;
+use octocrab::{
+    models::pulls::PullRequest,
+    params::{self, pulls},
+    Octocrab,
+};
+use std::process::exit;
+
+use crate::{build_octocrab, get_token, git, network::with_timeout, pr};
+
+pub async fn close(number: Option<u64>, message: Option<String>) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+
+    set_state(
+        &octocrab,
+        &base,
+        &repo,
+        &branch_pr,
+        pulls::State::Closed,
+        message,
+    )
+    .await;
+}
+
+pub async fn reopen(number: Option<u64>, message: Option<String>) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = match number {
+        Some(_) => pr::get_branch_pr(&octocrab, &base, &repo, number).await,
+        None => find_closed_pr(&octocrab, &base, &repo).await,
+    };
+
+    if branch_pr.merged_at.is_some() {
+        println!(
+            "{}",
+            format!("PR #{} is merged and can't be reopened", branch_pr.number).red()
+        );
+        exit(1);
+    }
+
+    set_state(
+        &octocrab,
+        &base,
+        &repo,
+        &branch_pr,
+        pulls::State::Open,
+        message,
+    )
+    .await;
+}
+
+async fn set_state(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    branch_pr: &PullRequest,
+    state: pulls::State,
+    message: Option<String>,
+) {
+    if let Some(message) = message {
+        let comment_resp = with_timeout!(octocrab
+            .issues(base, repo)
+            .create_comment(branch_pr.number, &message));
+
+        if comment_resp.is_err() {
+            println!("\n{}", "Error when posting the comment".red());
+            exit(1);
+        }
+    }
+
+    let (done, failed) = match state {
+        pulls::State::Closed => ("PR closed", "Error when closing the PR"),
+        _ => ("PR reopened", "Error when reopening the PR"),
+    };
+
+    let update_resp = with_timeout!(octocrab
+        .pulls(base, repo)
+        .update(branch_pr.number)
+        .state(state)
+        .send());
+
+    match update_resp {
+        Ok(_) => println!("\n{} #{}", done.green(), branch_pr.number),
+        Err(_) => {
+            println!("\n{}", failed.red());
+            exit(1);
+        }
+    }
+}
+
+async fn find_closed_pr(octocrab: &Octocrab, base: &str, repo: &str) -> PullRequest {
+    let branch = git::current_branch();
+
+    let pulls_resp = with_timeout!(octocrab
+        .pulls(base, repo)
+        .list()
+        .head(format!("{base}:{branch}"))
+        .state(params::State::Closed)
+        .send());
+
+    match pulls_resp {
+        Ok(pulls) => pulls
+            .into_iter()
+            .find(|p| p.merged_at.is_none())
+            .unwrap_or_else(|| {
+                println!(
+                    "{}",
+                    format!("No closed PR found for branch {branch}").red()
+                );
+                exit(1);
+            }),
+        Err(_) => {
+            println!("{}", "Couldn't list the closed PRs".red());
+            exit(1);
+        }
+    }
+}
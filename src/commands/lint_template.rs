@@ -0,0 +1,243 @@
+use colored::Colorize;
+use regex::Regex;
+use std::{fs, path::Path, process::exit, sync::LazyLock};
+
+use crate::{
+    checklist,
+    config::Config,
+    git,
+    late_vars::{self, LateVars},
+    pr::{DEFAULT_TEMPLATE, GENERATED_END, GENERATED_START},
+};
+use prmaker_core::template;
+
+const VARIABLES: [&str; 3] = ["body", "issue", "checklist"];
+const SAMPLE_BODY: &str = "Adds the login page";
+const SAMPLE_ISSUE: &str = "ABC-123";
+static VAR_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{(\w+)\}").unwrap());
+static LATE_VAR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*([\w.-]+)\s*\}\}").unwrap());
+
+#[derive(Debug, PartialEq)]
+struct Problem {
+    line: usize,
+    message: String,
+}
+
+pub fn run() {
+    let config = Config::load();
+    let toplevel = git::toplevel();
+    let mut templates = vec![(
+        "built-in template".to_owned(),
+        Ok(DEFAULT_TEMPLATE.to_owned()),
+    )];
+
+    for project in &config.projects {
+        if let Some(path) = &project.template {
+            let content = fs::read_to_string(Path::new(&toplevel).join(path));
+            templates.push((path.clone(), content.map_err(|err| err.to_string())));
+        }
+    }
+
+    let mut failed = false;
+
+    for (name, content) in templates {
+        println!("\n{}", format!("** {name} **").blue());
+
+        let content = match content {
+            Ok(content) => content,
+            Err(err) => {
+                println!("{} couldn't read it: {err}", "FAIL".red());
+                failed = true;
+                continue;
+            }
+        };
+
+        let problems = lint(&content, &config.late_vars);
+
+        if problems.is_empty() {
+            println!("{}", "PASS".green());
+        }
+
+        for problem in &problems {
+            println!(
+                "{} line {}: {}",
+                "FAIL".red(),
+                problem.line,
+                problem.message
+            );
+        }
+
+        failed |= !problems.is_empty();
+
+        println!("\n{}", "Preview:".purple());
+        println!("{}", preview(&content, config));
+    }
+
+    if failed {
+        exit(1);
+    }
+}
+
+fn lint(content: &str, custom: &LateVars) -> Vec<Problem> {
+    let mut problems = vec![];
+    let mut body_lines = vec![];
+    let mut fence_start = None;
+    let mut comment_start = None;
+
+    let mut report = |line: usize, message: String| problems.push(Problem { line, message });
+
+    for (index, line) in content.lines().enumerate() {
+        let number = index + 1;
+
+        if line.trim_start().starts_with("```") {
+            fence_start = match fence_start {
+                Some(_) => None,
+                None => Some(number),
+            };
+        }
+
+        let mut rest = line;
+
+        loop {
+            match comment_start {
+                None => match rest.find("<!--") {
+                    Some(start) => {
+                        comment_start = Some(number);
+                        rest = &rest[start + 4..];
+                    }
+                    None => break,
+                },
+                Some(_) => match rest.find("-->") {
+                    Some(end) => {
+                        comment_start = None;
+                        rest = &rest[end + 3..];
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        if line.contains(GENERATED_START) || line.contains(GENERATED_END) {
+            report(
+                number,
+                "prmaker's generated markers can't be part of a template".to_owned(),
+            );
+        }
+
+        for captures in LATE_VAR_REGEX.captures_iter(line) {
+            if !late_vars::is_late(&captures[1], custom) {
+                report(number, format!("unknown late variable {}", &captures[0]));
+            }
+        }
+
+        for found in VAR_REGEX.find_iter(line) {
+            let doubled =
+                line[..found.start()].ends_with('{') || line[found.end()..].starts_with('}');
+            let name = &found.as_str()[1..found.len() - 1];
+
+            if doubled {
+                continue;
+            }
+
+            if name == "body" {
+                body_lines.push(number);
+            } else if !VARIABLES.contains(&name) {
+                report(
+                    number,
+                    format!(
+                        "unknown variable {}, use {}",
+                        found.as_str(),
+                        VARIABLES.map(|v| format!("{{{v}}}")).join(", ")
+                    ),
+                );
+            }
+        }
+    }
+
+    if let Some(line) = fence_start {
+        report(line, "code block is never closed".to_owned());
+    }
+
+    if let Some(line) = comment_start {
+        report(
+            line,
+            "HTML comment is never closed, it would hide the rest of the body".to_owned(),
+        );
+    }
+
+    match body_lines.as_slice() {
+        [] => report(
+            0,
+            "{body} is missing, the PR body would be dropped".to_owned(),
+        ),
+        [_] => {}
+        [_, duplicate, ..] => report(*duplicate, "{body} is used more than once".to_owned()),
+    }
+
+    problems.sort_by_key(|p| p.line);
+    problems
+}
+
+fn preview(content: &str, config: &Config) -> String {
+    let issue = match &config.issue_url {
+        Some(issue_url) => template::link_issues(&[SAMPLE_ISSUE.to_owned()], issue_url),
+        None => SAMPLE_ISSUE.to_owned(),
+    };
+    let checklist = checklist::build_section(&["Tested it locally".to_owned()]).unwrap_or_default();
+
+    template::render(content, SAMPLE_BODY, &issue).replace("{checklist}", &checklist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lint_test() {
+        let custom: LateVars = [(
+            "preview".to_owned(),
+            "https://{{pr_number}}.example.com".to_owned(),
+        )]
+        .into();
+
+        assert!(lint(DEFAULT_TEMPLATE, &custom).is_empty());
+        assert!(lint(
+            "{body}\n\nSee {{preview}} for {{ pr_url }}\n```\n{}\n```\n",
+            &custom
+        )
+        .is_empty());
+
+        let problems: Vec<(usize, String)> = lint(
+            "## Summary\n{summary}\n{body}\n<!-- notes\n```\n{{preview_url}}\n{body}\n",
+            &custom,
+        )
+        .into_iter()
+        .map(|p| (p.line, p.message))
+        .collect();
+
+        assert_eq!(
+            vec![
+                (
+                    2,
+                    "unknown variable {summary}, use {body}, {issue}, {checklist}".to_owned()
+                ),
+                (
+                    4,
+                    "HTML comment is never closed, it would hide the rest of the body".to_owned()
+                ),
+                (5, "code block is never closed".to_owned()),
+                (6, "unknown late variable {{preview_url}}".to_owned()),
+                (7, "{body} is used more than once".to_owned()),
+            ],
+            problems
+        );
+        assert_eq!(
+            vec![Problem {
+                line: 0,
+                message: "{body} is missing, the PR body would be dropped".to_owned(),
+            }],
+            lint("**Related issue:** {issue}\n", &custom)
+        );
+    }
+}
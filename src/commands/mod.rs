@@ -0,0 +1,35 @@
+pub mod amend_body;
+pub mod backport;
+pub mod batch;
+pub mod bot;
+pub mod cleanup;
+pub mod comment;
+pub mod companion;
+pub mod doctor;
+pub mod draft;
+#[cfg(feature = "vault")]
+pub mod encrypt;
+pub mod flush;
+pub mod history;
+pub mod inbox;
+pub mod install_hooks;
+pub mod labels;
+pub mod lifecycle;
+pub mod lint_template;
+pub mod open;
+pub mod plugin;
+pub mod queue;
+pub mod rebalance;
+pub mod release;
+pub mod remote;
+pub mod rereview;
+pub mod review;
+pub mod reviewers;
+#[cfg(feature = "self-update")]
+pub mod self_update;
+pub mod stats;
+pub mod sync;
+pub mod undo;
+pub mod watch;
+#[cfg(feature = "web")]
+pub mod web;
@@ -0,0 +1,31 @@
+use std::process::{Command, Stdio};
+
+use crate::{build_octocrab, get_token, git, pr};
+
+pub async fn run(print: bool) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let url = pr::get_pr_link(&pr::get_branch_pr(&octocrab, &base, &repo, None).await);
+
+    if print || !open_in_browser(&url) {
+        println!("{url}");
+    }
+}
+
+pub fn open_in_browser(url: &str) -> bool {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+
+    Command::new(opener)
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
@@ -0,0 +1,104 @@
+use colored::Colorize;
+use std::{
+    env,
+    ffi::OsStr,
+    path::PathBuf,
+    process::{exit, Command},
+};
+
+use crate::{
+    config::{self, Config},
+    git, pr,
+};
+
+const PREFIX: &str = "prmaker-";
+
+pub fn run(args: &[String]) {
+    let Some((name, args)) = args.split_first() else {
+        return;
+    };
+
+    let executable = format!("{PREFIX}{name}");
+    let path_var = env::var_os("PATH").unwrap_or_default();
+
+    let Some(path) = find_on_path(&executable, &path_var) else {
+        println!("{}", format!("Unknown command {name}").red());
+        println!("No {executable} executable was found on PATH");
+        exit(1);
+    };
+
+    let status = Command::new(&path).args(args).envs(context()).status();
+
+    match status {
+        Ok(status) => exit(status.code().unwrap_or(1)),
+        Err(err) => {
+            println!("{}", format!("Couldn't run {}", path.display()).red());
+            println!("{err}");
+            exit(1);
+        }
+    }
+}
+
+fn context() -> Vec<(&'static str, String)> {
+    let mut context = vec![];
+
+    if let Some(path) = config::find_config_file() {
+        context.push(("PRMAKER_CONFIG_PATH", path.display().to_string()));
+    }
+
+    if let Some(base_branch) = Config::load().base_branch() {
+        context.push(("PRMAKER_BASE_BRANCH", base_branch.to_owned()));
+    }
+
+    let toplevel = git::toplevel();
+
+    if toplevel.is_empty() {
+        return context;
+    }
+
+    context.push(("PRMAKER_TOPLEVEL", toplevel));
+    context.push(("PRMAKER_BRANCH", git::current_branch()));
+
+    let remote_url = git::remote_url();
+
+    if let Some((base, repo)) = pr::parse_remote(&remote_url) {
+        context.push(("PRMAKER_REPO", format!("{base}/{repo}")));
+        context.push(("PRMAKER_REMOTE_URL", remote_url));
+    }
+
+    context
+}
+
+fn find_on_path(executable: &str, path_var: &OsStr) -> Option<PathBuf> {
+    let names = [executable.to_owned(), format!("{executable}.exe")];
+
+    env::split_paths(path_var)
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn find_on_path_test() {
+        let empty = env::temp_dir().join(format!("prmaker-plugins-empty-{}", std::process::id()));
+        let dir = env::temp_dir().join(format!("prmaker-plugins-{}", std::process::id()));
+        fs::create_dir_all(&empty).unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("prmaker-standup"), "#!/bin/sh\n").unwrap();
+
+        let path_var = env::join_paths([&empty, &dir]).unwrap();
+
+        assert_eq!(
+            Some(dir.join("prmaker-standup")),
+            find_on_path("prmaker-standup", &path_var)
+        );
+        assert_eq!(None, find_on_path("prmaker-missing", &path_var));
+
+        fs::remove_dir_all(&empty).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use serde::{de::IgnoredAny, Deserialize};
+use std::process::exit;
+
+use crate::{
+    build_octocrab,
+    capabilities::{self, Feature},
+    get_token, git,
+    network::with_timeout,
+    pr,
+};
+
+#[derive(Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<T>,
+    errors: Option<Vec<IgnoredAny>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepositoryData {
+    repository: Repository,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repository {
+    merge_queue: Option<IgnoredAny>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnqueueData {
+    enqueue_pull_request: EnqueuePullRequest,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnqueuePullRequest {
+    merge_queue_entry: Option<MergeQueueEntry>,
+}
+
+#[derive(Deserialize)]
+struct MergeQueueEntry {
+    position: u64,
+}
+
+pub async fn run(number: Option<u64>) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    capabilities::ensure(&octocrab, Feature::MergeQueue).await;
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+    let base_branch = &branch_pr.base.ref_field;
+
+    if !has_merge_queue(&octocrab, &base, &repo, base_branch).await {
+        println!(
+            "{}",
+            format!("{base_branch} has no merge queue, merge the PR instead").red()
+        );
+        exit(1);
+    }
+
+    let node_id = branch_pr.node_id.clone().unwrap_or_default();
+    let query = format!(
+        "mutation {{ enqueuePullRequest(input: {{ pullRequestId: \"{node_id}\" }}) {{ mergeQueueEntry {{ position }} }} }}"
+    );
+
+    let resp: octocrab::Result<GraphqlResponse<EnqueueData>> =
+        with_timeout!(octocrab.graphql(&query));
+
+    match resp {
+        Ok(GraphqlResponse {
+            data: Some(data),
+            errors: None,
+        }) => match data.enqueue_pull_request.merge_queue_entry {
+            Some(entry) => println!(
+                "\n{} at position {}",
+                format!("PR #{} added to the merge queue", branch_pr.number).green(),
+                entry.position.to_string().cyan()
+            ),
+            None => println!(
+                "\n{}",
+                format!("PR #{} added to the merge queue", branch_pr.number).green()
+            ),
+        },
+        _ => {
+            println!(
+                "\n{}",
+                "Error when adding the PR to the merge queue, is it approved and passing checks?"
+                    .red()
+            );
+            exit(1);
+        }
+    }
+}
+
+async fn has_merge_queue(octocrab: &Octocrab, base: &str, repo: &str, branch: &str) -> bool {
+    let query = format!(
+        "query {{ repository(owner: \"{base}\", name: \"{repo}\") {{ mergeQueue(branch: \"{branch}\") {{ id }} }} }}"
+    );
+
+    let resp: octocrab::Result<GraphqlResponse<RepositoryData>> =
+        with_timeout!(octocrab.graphql(&query));
+
+    matches!(
+        resp,
+        Ok(GraphqlResponse {
+            data: Some(RepositoryData {
+                repository: Repository {
+                    merge_queue: Some(_)
+                }
+            }),
+            ..
+        })
+    )
+}
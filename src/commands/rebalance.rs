@@ -0,0 +1,333 @@
+use chrono::{DateTime, Duration, Local, Utc};
+use colored::Colorize;
+use octocrab::{models::pulls::PullRequest, Octocrab};
+use serde::Deserialize;
+use std::{collections::VecDeque, fmt, process::exit};
+
+use crate::{
+    build_octocrab,
+    config::Config,
+    get_token, git,
+    network::with_timeout,
+    paginate, pr, proceed_question,
+    reviewers::{self, Selection},
+};
+
+const MUTATION_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+const RATE_LIMIT_RESERVE: usize = 100;
+
+#[derive(Deserialize)]
+struct Event {
+    event: String,
+    created_at: DateTime<Utc>,
+    requested_reviewer: Option<Login>,
+}
+
+#[derive(Deserialize)]
+struct Login {
+    login: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct OpenPR {
+    number: u64,
+    author: String,
+    requested: Vec<(String, DateTime<Utc>)>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Rerequest {
+        number: u64,
+        reviewer: String,
+    },
+    Swap {
+        number: u64,
+        from: String,
+        to: String,
+    },
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Rerequest { number, reviewer } => write!(f, "#{number}: re-request {reviewer}"),
+            Action::Swap { number, from, to } => write!(f, "#{number}: swap {from} for {to}"),
+        }
+    }
+}
+
+pub async fn run(days: i64, dry_run: bool) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let config = Config::load();
+    let octocrab = build_octocrab(get_token());
+
+    let pulls: Vec<PullRequest> =
+        paginate::collect(&octocrab, format!("repos/{base}/{repo}/pulls"))
+            .await
+            .unwrap_or_else(|_| {
+                println!("{}", "Couldn't list the open PRs".red());
+                exit(1);
+            });
+
+    let mut open_prs = vec![];
+    let mut scan = VecDeque::from(pulls);
+
+    while let Some(pull) = scan.pop_front() {
+        throttle(&octocrab).await;
+        open_prs.push(get_open_pr(&octocrab, &base, &repo, pull).await);
+    }
+
+    let pool = reviewers::expand(&config.reviewer_pool, &config.reviewer_groups).usernames;
+    let actions = plan(&open_prs, &pool, Utc::now() - Duration::days(days));
+
+    if actions.is_empty() {
+        println!("\nNo review requests older than {days} days");
+        return;
+    }
+
+    println!("\n{}", "** Stale review requests **".blue());
+
+    for action in &actions {
+        println!("{action}");
+    }
+
+    if dry_run {
+        return;
+    }
+
+    proceed_question();
+
+    let mut queue = VecDeque::from(actions);
+
+    while let Some(action) = queue.pop_front() {
+        throttle(&octocrab).await;
+
+        println!("\n{}", action.to_string().cyan());
+
+        let selection = |login: String| Selection {
+            usernames: vec![login],
+            teams: vec![],
+        };
+
+        match action {
+            Action::Rerequest { number, reviewer } => {
+                reviewers::remove_review_requests(
+                    &octocrab,
+                    &base,
+                    &repo,
+                    number,
+                    selection(reviewer.clone()),
+                )
+                .await;
+                reviewers::request_reviews(&octocrab, &base, &repo, number, selection(reviewer))
+                    .await;
+            }
+            Action::Swap { number, from, to } => {
+                let requested =
+                    reviewers::request_reviews(&octocrab, &base, &repo, number, selection(to))
+                        .await;
+
+                if requested.is_empty() {
+                    println!("{}", format!("Keeping {from} on #{number}").yellow());
+                    continue;
+                }
+
+                reviewers::remove_review_requests(&octocrab, &base, &repo, number, selection(from))
+                    .await;
+            }
+        }
+
+        if !queue.is_empty() {
+            tokio::time::sleep(MUTATION_INTERVAL).await;
+        }
+    }
+}
+
+async fn get_open_pr(octocrab: &Octocrab, base: &str, repo: &str, pull: PullRequest) -> OpenPR {
+    let route = format!("repos/{base}/{repo}/issues/{}/events", pull.number);
+    let events: Vec<Event> = paginate::collect(octocrab, route).await.unwrap_or_default();
+    let opened_at = pull.created_at.unwrap_or_else(Utc::now);
+
+    let requested = pull
+        .requested_reviewers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|reviewer| {
+            let requested_at = events
+                .iter()
+                .filter(|e| e.event == "review_requested")
+                .filter(|e| {
+                    e.requested_reviewer
+                        .as_ref()
+                        .is_some_and(|r| r.login == reviewer.login)
+                })
+                .map(|e| e.created_at)
+                .max()
+                .unwrap_or(opened_at);
+
+            (reviewer.login, requested_at)
+        })
+        .collect();
+
+    OpenPR {
+        number: pull.number,
+        author: pull.user.map(|u| u.login).unwrap_or_default(),
+        requested,
+    }
+}
+
+fn plan(prs: &[OpenPR], pool: &[String], cutoff: DateTime<Utc>) -> Vec<Action> {
+    let mut load: Vec<(String, usize)> = pool
+        .iter()
+        .map(|login| {
+            let requests = prs
+                .iter()
+                .filter(|pr| pr.requested.iter().any(|(r, _)| r == login))
+                .count();
+
+            (login.clone(), requests)
+        })
+        .collect();
+
+    let mut actions = vec![];
+
+    for pr in prs {
+        let mut assigned: Vec<String> = vec![];
+
+        for (reviewer, requested_at) in &pr.requested {
+            if *requested_at > cutoff {
+                continue;
+            }
+
+            let candidate = load
+                .iter_mut()
+                .filter(|(login, _)| *login != pr.author && *login != *reviewer)
+                .filter(|(login, _)| !pr.requested.iter().any(|(r, _)| r == login))
+                .filter(|(login, _)| !assigned.contains(login))
+                .min_by_key(|(_, requests)| *requests);
+
+            actions.push(match candidate {
+                Some((login, requests)) => {
+                    *requests += 1;
+                    assigned.push(login.clone());
+
+                    Action::Swap {
+                        number: pr.number,
+                        from: reviewer.clone(),
+                        to: login.clone(),
+                    }
+                }
+                None => Action::Rerequest {
+                    number: pr.number,
+                    reviewer: reviewer.clone(),
+                },
+            });
+        }
+    }
+
+    actions
+}
+
+async fn throttle(octocrab: &Octocrab) {
+    let Ok(rate_limit) = with_timeout!(octocrab.ratelimit().get()) else {
+        return;
+    };
+
+    let core = rate_limit.resources.core;
+
+    if core.remaining > RATE_LIMIT_RESERVE {
+        return;
+    }
+
+    let reset = DateTime::from_timestamp(core.reset as i64, 0).unwrap_or_else(Utc::now);
+    let wait = (reset - Utc::now()).to_std().unwrap_or_default();
+
+    println!(
+        "\n{}",
+        format!(
+            "Rate limit almost exhausted, waiting until {}...",
+            reset.with_timezone(&Local).format("%H:%M")
+        )
+        .yellow()
+    );
+
+    tokio::time::sleep(wait).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_test() {
+        let now = Utc::now();
+        let old = now - Duration::days(5);
+        let cutoff = now - Duration::days(3);
+        let prs = [
+            OpenPR {
+                number: 1,
+                author: "carol".to_owned(),
+                requested: vec![("alice".to_owned(), old), ("bob".to_owned(), now)],
+            },
+            OpenPR {
+                number: 2,
+                author: "dave".to_owned(),
+                requested: vec![("alice".to_owned(), old)],
+            },
+        ];
+        let pool = ["alice", "bob", "carol", "erin"].map(String::from);
+
+        assert_eq!(
+            vec![
+                Action::Swap {
+                    number: 1,
+                    from: "alice".to_owned(),
+                    to: "erin".to_owned(),
+                },
+                Action::Swap {
+                    number: 2,
+                    from: "alice".to_owned(),
+                    to: "carol".to_owned(),
+                },
+            ],
+            plan(&prs, &pool, cutoff)
+        );
+        assert_eq!(
+            vec![
+                Action::Rerequest {
+                    number: 1,
+                    reviewer: "alice".to_owned(),
+                },
+                Action::Rerequest {
+                    number: 2,
+                    reviewer: "alice".to_owned(),
+                },
+            ],
+            plan(&prs, &[], cutoff)
+        );
+
+        let two_stale = [OpenPR {
+            number: 3,
+            author: "dave".to_owned(),
+            requested: vec![("alice".to_owned(), old), ("bob".to_owned(), old)],
+        }];
+
+        assert_eq!(
+            vec![
+                Action::Swap {
+                    number: 3,
+                    from: "alice".to_owned(),
+                    to: "carol".to_owned(),
+                },
+                Action::Rerequest {
+                    number: 3,
+                    reviewer: "bob".to_owned(),
+                },
+            ],
+            plan(&two_stale, &pool[..3], cutoff)
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use colored::Colorize;
+use octocrab::{models::pulls::PullRequest, Octocrab};
+use regex::Regex;
+use std::{collections::BTreeMap, process::exit, sync::LazyLock};
+
+use crate::{
+    build_octocrab, conventional, flush_line, get_token, git, network::with_timeout, pr,
+    proceed_question, read_input,
+};
+
+static PR_NUMBER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Merge pull request #(\d+)|\(#(\d+)\)$").unwrap());
+
+const GROUPS: [(&str, &str); 10] = [
+    ("feat", "Features"),
+    ("fix", "Bug Fixes"),
+    ("perf", "Performance"),
+    ("refactor", "Refactoring"),
+    ("docs", "Documentation"),
+    ("test", "Tests"),
+    ("build", "Chores"),
+    ("ci", "Chores"),
+    ("chore", "Chores"),
+    ("style", "Chores"),
+];
+const OTHER_GROUP: &str = "Other";
+
+#[derive(Debug)]
+struct MergedPR {
+    number: u64,
+    title: String,
+    labels: Vec<String>,
+}
+
+pub async fn run(from: &str, to: &str, into: &str) {
+    let token = get_token();
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+
+    let subjects = git::commit_subjects(&format!("{from}..{to}")).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Couldn't list commits between {from} and {to}").red()
+        );
+        println!("{err}");
+        println!("Make sure both refs exist locally, `git fetch --tags` may help");
+        exit(1);
+    });
+
+    let numbers = get_pr_numbers(&subjects);
+
+    if numbers.is_empty() {
+        println!(
+            "{}",
+            format!("No merged PRs found between {from} and {to}").red()
+        );
+        exit(1);
+    }
+
+    let octocrab = build_octocrab(token);
+
+    println!("\nFetching {} merged PRs...", numbers.len());
+
+    let merged_prs = fetch_merged_prs(&octocrab, &base, &repo, &numbers).await;
+    let changelog = build_changelog(&merged_prs);
+    let title = get_release_title(to, into);
+
+    println!("\n{}", "** Review release PR **".blue());
+    println!("Title: {}", title.cyan());
+    println!("Head: {}", to.cyan());
+    println!("Base: {}", into.cyan());
+    println!("Remote: {}", format!("{base}/{repo}").cyan());
+    println!("\n{changelog}");
+
+    proceed_question();
+
+    let mut release_pr = pr::PR::new(base, repo, to.to_owned(), into.to_owned(), title, changelog);
+
+    println!("\nCreating release PR...");
+
+    if release_pr.create(&octocrab).await.is_err() {
+        exit(1)
+    }
+}
+
+async fn fetch_merged_prs(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    numbers: &[u64],
+) -> Vec<MergedPR> {
+    let mut merged_prs = vec![];
+
+    for &number in numbers {
+        match with_timeout!(octocrab.pulls(base, repo).get(number)) {
+            Ok(github_pr) => merged_prs.push(to_merged_pr(github_pr)),
+            Err(_) => println!(
+                "{}",
+                format!("Couldn't fetch PR #{number}, skipping...").red()
+            ),
+        }
+    }
+
+    merged_prs
+}
+
+fn to_merged_pr(github_pr: PullRequest) -> MergedPR {
+    MergedPR {
+        number: github_pr.number,
+        title: github_pr.title.unwrap_or_default(),
+        labels: github_pr
+            .labels
+            .unwrap_or_default()
+            .into_iter()
+            .map(|l| l.name)
+            .collect(),
+    }
+}
+
+fn get_pr_numbers(subjects: &[String]) -> Vec<u64> {
+    let mut numbers = vec![];
+
+    for subject in subjects {
+        let number = PR_NUMBER_REGEX
+            .captures(subject)
+            .and_then(|c| c.get(1).or_else(|| c.get(2)))
+            .and_then(|m| m.as_str().parse::<u64>().ok());
+
+        if let Some(number) = number {
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+    }
+
+    numbers
+}
+
+fn get_group(merged_pr: &MergedPR) -> String {
+    if let Some(commit_type) = conventional::prefix_type(&merged_pr.title) {
+        if let Some((_, group)) = GROUPS.iter().find(|(t, _)| *t == commit_type) {
+            return group.to_string();
+        }
+    }
+
+    merged_pr
+        .labels
+        .first()
+        .cloned()
+        .unwrap_or_else(|| OTHER_GROUP.to_owned())
+}
+
+fn group_rank(group: &str) -> usize {
+    match GROUPS.iter().position(|(_, g)| *g == group) {
+        Some(position) => position,
+        None if group == OTHER_GROUP => usize::MAX,
+        None => GROUPS.len(),
+    }
+}
+
+fn build_changelog(merged_prs: &[MergedPR]) -> String {
+    let mut groups: BTreeMap<(usize, String), Vec<&MergedPR>> = BTreeMap::new();
+
+    for merged_pr in merged_prs {
+        let group = get_group(merged_pr);
+
+        groups
+            .entry((group_rank(&group), group))
+            .or_default()
+            .push(merged_pr);
+    }
+
+    groups
+        .into_iter()
+        .map(|((_, group), prs)| {
+            let entries: Vec<String> = prs
+                .iter()
+                .map(|p| format!("- {} (#{})", p.title, p.number))
+                .collect();
+
+            format!("## {group}\n\n{}\n", entries.join("\n"))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn get_release_title(to: &str, into: &str) -> String {
+    let default_title = format!("Release {to} into {into}");
+
+    println!("\nRelease PR title: {}", default_title.purple());
+    print!("Leave it blank to use the title above or digit a new one: ");
+    flush_line();
+
+    let title = read_input();
+
+    if title.trim().is_empty() {
+        default_title
+    } else {
+        title.trim().to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merged_pr(number: u64, title: &str, labels: &[&str]) -> MergedPR {
+        MergedPR {
+            number,
+            title: title.to_owned(),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn get_pr_numbers_test() {
+        let subjects = [
+            "Merge pull request #12 from org/feat/CT-1111/thing",
+            "fix: squashed change (#15)",
+            "chore: no pr reference",
+            "Merge pull request #12 from org/feat/CT-1111/thing",
+        ]
+        .map(String::from);
+
+        assert_eq!(vec![12, 15], get_pr_numbers(&subjects));
+    }
+
+    #[test]
+    fn build_changelog_test() {
+        let merged_prs = [
+            merged_pr(3, "chore: bump deps", &[]),
+            merged_pr(1, "fix(api): handle timeouts", &[]),
+            merged_pr(4, "Improve onboarding", &["ux"]),
+            merged_pr(5, "Tweak things", &[]),
+            merged_pr(6, "fix:trailing newline", &[]),
+            merged_pr(2, "feat: add release mode", &["enhancement"]),
+        ];
+
+        let expected = "\
+## Features
+
+- feat: add release mode (#2)
+
+## Bug Fixes
+
+- fix(api): handle timeouts (#1)
+- fix:trailing newline (#6)
+
+## Chores
+
+- chore: bump deps (#3)
+
+## ux
+
+- Improve onboarding (#4)
+
+## Other
+
+- Tweak things (#5)
+";
+
+        assert_eq!(expected, build_changelog(&merged_prs));
+    }
+}
@@ -0,0 +1,160 @@
+use colored::Colorize;
+use octocrab::{models::repos::RepoCommit, Octocrab};
+use std::{path::Path, process::exit, sync::atomic::Ordering};
+
+use crate::{
+    assume_defaults, base_branch, build_octocrab, change_dir, cli::CreateArgs, config::Config,
+    confirm_or, flush_line, get_token, get_user, git, network::with_timeout, pr, proceed_question,
+    read_input, repo_picker, ASSUME_DEFAULTS,
+};
+
+pub async fn run(args: CreateArgs) {
+    ASSUME_DEFAULTS.store(args.yes, Ordering::Relaxed);
+
+    let user = get_user();
+    let octocrab = build_octocrab(get_token());
+
+    println!(
+        "{}",
+        "Not inside a git repository, pick one instead".yellow()
+    );
+
+    let (base, repo) = match &args.repo {
+        Some(full_name) => match full_name.split_once('/') {
+            Some((base, repo)) => (base.to_owned(), repo.to_owned()),
+            None => {
+                println!(
+                    "{}",
+                    format!("Invalid repository {full_name}, use <owner>/<repo>").red()
+                );
+                exit(1);
+            }
+        },
+        None => repo_picker::pick(&octocrab, &user).await,
+    };
+
+    if confirm_or(&format!("Clone {base}/{repo} into ./{repo}?"), false) {
+        let url = format!("git@github.com:{base}/{repo}.git");
+
+        git::run_or_exit(&["clone", &url, &repo]);
+        change_dir(Path::new(&repo));
+
+        println!("\nCloned {base}/{repo}, checkout a branch and run prmaker again");
+        return;
+    }
+
+    let config = Config::load();
+    let head = match &args.head {
+        Some(head) => head.clone(),
+        None => select_head(&octocrab, &base, &repo).await,
+    };
+    let configured_base = args.base.as_deref().or(config.base_branch());
+    let base_branch = base_branch::resolve(&octocrab, &base, &repo, configured_base).await;
+
+    if head == base_branch {
+        println!(
+            "{}",
+            format!("{head} is the base branch, pick another one").red()
+        );
+        exit(1);
+    }
+
+    let last_commit = get_last_commit(&octocrab, &base, &repo, &head).await;
+
+    println!("\nPR title: {}", last_commit.purple());
+    print!("Leave it blank to use the title above or digit a new one: ");
+    flush_line();
+
+    let title = match read_input().trim() {
+        "" => last_commit,
+        title => title.to_owned(),
+    };
+
+    print!("\nPR body: ");
+    flush_line();
+
+    let body = read_input().trim().to_owned();
+    let mut pr = pr::PR::new(base, repo, head, base_branch, title, body);
+
+    println!("\n{}", "** Review PR **".blue());
+    println!("{pr}");
+
+    proceed_question();
+
+    if pr.create(&octocrab).await.is_err() {
+        exit(1)
+    }
+
+    pr.assign_self(&octocrab, &user).await;
+
+    println!("\nPR: {}", pr.link.unwrap())
+}
+
+async fn select_head(octocrab: &Octocrab, base: &str, repo: &str) -> String {
+    if assume_defaults() {
+        no_prompts();
+    }
+
+    let branches_resp = with_timeout!(octocrab
+        .repos(base, repo)
+        .list_branches()
+        .per_page(100)
+        .send());
+
+    let branches: Vec<String> = match branches_resp {
+        Ok(branches) => branches.into_iter().map(|b| b.name).collect(),
+        Err(_) => {
+            println!(
+                "{}",
+                format!("Couldn't list branches of {base}/{repo}").red()
+            );
+            exit(1);
+        }
+    };
+
+    println!("\n{}", "** Remote branches **".blue());
+
+    for (index, branch) in branches.iter().enumerate() {
+        println!("{} - {}", index.to_string().purple(), branch);
+    }
+
+    loop {
+        print!("\n{}", "Select the branch to open the PR from: ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.is_empty() {
+            no_prompts();
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < branches.len() => return branches[index].clone(),
+            Ok(_) => println!("{}", "Branch not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+}
+
+fn no_prompts() -> ! {
+    println!(
+        "\n{}",
+        "Pass the branch with --head to run without prompts".red()
+    );
+    exit(1);
+}
+
+async fn get_last_commit(octocrab: &Octocrab, base: &str, repo: &str, branch: &str) -> String {
+    let route = format!("repos/{base}/{repo}/commits/{branch}");
+
+    match with_timeout!(octocrab.get::<RepoCommit, _, ()>(&route, None)) {
+        Ok(commit) => commit
+            .commit
+            .message
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_owned(),
+        Err(_) => branch.to_owned(),
+    }
+}
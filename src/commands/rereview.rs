@@ -0,0 +1,203 @@
+use colored::Colorize;
+use octocrab::{
+    models::pulls::{Review, ReviewState},
+    Error::GitHub,
+};
+use std::process::exit;
+
+use crate::{
+    build_octocrab, compare, get_token, git,
+    network::with_timeout,
+    pr, proceed_question,
+    reviewers::{self, Selection},
+};
+
+#[derive(Debug, PartialEq)]
+struct StaleReview {
+    login: String,
+    commit_id: String,
+}
+
+pub async fn run(number: Option<u64>, summary: bool) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+    let head_sha = branch_pr.head.sha.clone();
+
+    let reviews = match with_timeout!(octocrab.pulls(&base, &repo).list_reviews(branch_pr.number)) {
+        Ok(reviews) => reviews.items,
+        Err(_) => {
+            println!("{}", "Couldn't list the PR reviews".red());
+            exit(1);
+        }
+    };
+
+    let stale = get_stale_reviews(&reviews, &head_sha);
+
+    if stale.is_empty() {
+        println!("\nNo stale reviews, nothing to re-request");
+        return;
+    }
+
+    let logins: Vec<String> = stale.iter().map(|s| s.login.clone()).collect();
+
+    println!("\nStale reviews from {}", logins.join(", ").cyan());
+    proceed_question();
+
+    let selection = Selection {
+        usernames: logins,
+        teams: vec![],
+    };
+
+    reviewers::request_reviews(&octocrab, &base, &repo, branch_pr.number, selection).await;
+
+    if !summary {
+        return;
+    }
+
+    for review in &stale {
+        let comparison =
+            match compare::compare(&octocrab, &base, &repo, &review.commit_id, &head_sha).await {
+                Ok(comparison) => comparison,
+                Err(err) => {
+                    let reason = match err {
+                        GitHub { source, .. } => source.message,
+                        err => err.to_string(),
+                    };
+
+                    println!(
+                        "\n{} {}: {reason}",
+                        "Couldn't compare the commits since the review of".red(),
+                        review.login
+                    );
+                    continue;
+                }
+            };
+        let commits: Vec<String> = comparison
+            .commits
+            .iter()
+            .map(|c| {
+                c.commit
+                    .message
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_owned()
+            })
+            .collect();
+
+        let comment_resp = with_timeout!(octocrab
+            .issues(&base, &repo)
+            .create_comment(branch_pr.number, build_summary(review, &commits)));
+
+        if comment_resp.is_err() {
+            println!(
+                "\n{}",
+                format!("Error when posting the summary for {}", review.login).red()
+            );
+        }
+    }
+
+    println!("\n{}", "Change summaries posted".green());
+}
+
+fn get_stale_reviews(reviews: &[Review], head_sha: &str) -> Vec<StaleReview> {
+    let mut latest: Vec<StaleReview> = vec![];
+
+    for review in reviews {
+        let (Some(user), Some(commit_id)) = (&review.user, &review.commit_id) else {
+            continue;
+        };
+
+        if !matches!(
+            review.state,
+            Some(ReviewState::Approved | ReviewState::ChangesRequested)
+        ) {
+            continue;
+        }
+
+        latest.retain(|r| r.login != user.login);
+        latest.push(StaleReview {
+            login: user.login.clone(),
+            commit_id: commit_id.clone(),
+        });
+    }
+
+    latest.retain(|r| r.commit_id != head_sha);
+    latest
+}
+
+fn build_summary(review: &StaleReview, commits: &[String]) -> String {
+    let short_sha = &review.commit_id[..review.commit_id.len().min(7)];
+    let mut summary = format!(
+        "@{} changes since your last review ({short_sha}):\n",
+        review.login
+    );
+
+    if commits.is_empty() {
+        summary.push_str("\nThe branch history was rewritten\n");
+    }
+
+    for commit in commits {
+        summary.push_str(&format!("\n- {commit}"));
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(login: &str, state: &str, commit_id: &str) -> Review {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "node_id": "PRR_1",
+            "html_url": "https://github.com/o/r/pull/1#pullrequestreview-1",
+            "user": {
+                "login": login,
+                "id": 1,
+                "node_id": "U_1",
+                "avatar_url": "https://github.com/images/u.png",
+                "gravatar_id": "",
+                "url": "https://api.github.com/users/u",
+                "html_url": "https://github.com/u",
+                "followers_url": "https://api.github.com/users/u/followers",
+                "following_url": "https://api.github.com/users/u/following",
+                "gists_url": "https://api.github.com/users/u/gists",
+                "starred_url": "https://api.github.com/users/u/starred",
+                "subscriptions_url": "https://api.github.com/users/u/subscriptions",
+                "organizations_url": "https://api.github.com/users/u/orgs",
+                "repos_url": "https://api.github.com/users/u/repos",
+                "events_url": "https://api.github.com/users/u/events",
+                "received_events_url": "https://api.github.com/users/u/received_events",
+                "type": "User",
+                "site_admin": false
+            },
+            "state": state,
+            "commit_id": commit_id,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn get_stale_reviews_test() {
+        let reviews = [
+            review("alice", "APPROVED", "aaa"),
+            review("bob", "CHANGES_REQUESTED", "aaa"),
+            review("bob", "APPROVED", "head"),
+            review("carol", "COMMENTED", "aaa"),
+        ];
+
+        assert_eq!(
+            get_stale_reviews(&reviews, "head"),
+            [StaleReview {
+                login: "alice".to_owned(),
+                commit_id: "aaa".to_owned()
+            }]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use colored::Colorize;
+use serde::{de::IgnoredAny, Serialize};
+use std::{collections::BTreeMap, process::exit};
+
+use crate::{
+    build_octocrab, config::Config, confirm_or, editor, flush_line, get_token, git,
+    network::with_timeout, pr, proceed_question, read_input,
+};
+
+const EVENTS: [(&str, &str); 3] = [
+    ("APPROVE", "Approve"),
+    ("REQUEST_CHANGES", "Request changes"),
+    ("COMMENT", "Comment"),
+];
+
+#[derive(Serialize)]
+struct ReviewRequest<'a> {
+    event: &'a str,
+    body: &'a str,
+}
+
+#[derive(Debug, PartialEq)]
+enum ReplyOption {
+    Empty,
+    Saved(usize),
+    Write,
+    Invalid,
+}
+
+pub async fn run(number: u64) {
+    let config = Config::load();
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let reviewed_pr = match with_timeout!(octocrab.pulls(&base, &repo).get(number)) {
+        Ok(reviewed_pr) => reviewed_pr,
+        Err(_) => {
+            println!("{}", format!("Couldn't fetch PR #{number}").red());
+            exit(1);
+        }
+    };
+
+    println!("\n{}", "** Review PR **".blue());
+    println!(
+        "Title: {}",
+        reviewed_pr.title.clone().unwrap_or_default().cyan()
+    );
+    println!(
+        "Author: {}",
+        reviewed_pr
+            .user
+            .as_ref()
+            .map(|u| u.login.clone())
+            .unwrap_or_default()
+            .cyan()
+    );
+    println!(
+        "Branches: {}",
+        format!(
+            "{} -> {}",
+            reviewed_pr.head.ref_field, reviewed_pr.base.ref_field
+        )
+        .cyan()
+    );
+    println!("Link: {}", pr::get_pr_link(&reviewed_pr).cyan());
+
+    let (event, event_name) = select_event();
+    let body = get_review_body(&config.saved_replies, event);
+
+    println!("\nSubmitting {}...", event_name.to_lowercase());
+    proceed_question();
+
+    let route = format!("repos/{base}/{repo}/pulls/{number}/reviews");
+    let review_resp: octocrab::Result<IgnoredAny> =
+        with_timeout!(octocrab.post(&route, Some(&ReviewRequest { event, body: &body })));
+
+    match review_resp {
+        Ok(_) => println!("\n{}", "Review submitted successfully".green()),
+        Err(_) => {
+            println!("\n{}", "Error when submitting the review".red());
+            exit(1);
+        }
+    }
+}
+
+fn select_event() -> (&'static str, &'static str) {
+    println!("\n{}", "** Review event **".blue());
+
+    for (index, (_, name)) in EVENTS.iter().enumerate() {
+        println!("{} - {}", index.to_string().purple(), name);
+    }
+
+    loop {
+        print!("\n{}", "Select the review event: ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < EVENTS.len() => return EVENTS[index],
+            Ok(_) => println!("{}", "Event not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+}
+
+fn get_review_body(saved_replies: &BTreeMap<String, String>, event: &str) -> String {
+    let replies: Vec<(&String, &String)> = saved_replies.iter().collect();
+
+    loop {
+        if !replies.is_empty() {
+            println!("\n{}", "** Saved replies **".blue());
+
+            for (index, (name, reply)) in replies.iter().enumerate() {
+                println!("{} - {}: {}", index.to_string().purple(), name, reply);
+            }
+        }
+
+        print!(
+            "\n{}",
+            format!(
+                "Select a saved reply, {} to write one or leave it blank: ",
+                "e".purple()
+            )
+            .yellow()
+        );
+        flush_line();
+
+        let body = match parse_reply_option(&read_input(), replies.len()) {
+            ReplyOption::Empty => String::new(),
+            ReplyOption::Saved(index) => {
+                let reply = replies[index].1.clone();
+
+                if confirm_or("Edit the saved reply?", false) {
+                    edit_body(&reply)
+                } else {
+                    reply
+                }
+            }
+            ReplyOption::Write => edit_body(""),
+            ReplyOption::Invalid => {
+                println!("{}", "Saved reply not found".red());
+                continue;
+            }
+        };
+
+        if body.is_empty() && event != "APPROVE" {
+            println!("{}", "This review event requires a body".red());
+            continue;
+        }
+
+        return body;
+    }
+}
+
+fn edit_body(initial: &str) -> String {
+    editor::edit(initial).unwrap_or_else(|err| {
+        println!("{}", "Couldn't edit the review body".red());
+        println!("{err}");
+        initial.to_owned()
+    })
+}
+
+fn parse_reply_option(input: &str, replies: usize) -> ReplyOption {
+    match input.trim() {
+        "" => ReplyOption::Empty,
+        "e" => ReplyOption::Write,
+        opt => match opt.parse::<usize>() {
+            Ok(index) if index < replies => ReplyOption::Saved(index),
+            _ => ReplyOption::Invalid,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reply_option_test() {
+        assert_eq!(ReplyOption::Empty, parse_reply_option("\n", 2));
+        assert_eq!(ReplyOption::Write, parse_reply_option("e\n", 2));
+        assert_eq!(ReplyOption::Saved(1), parse_reply_option("1\n", 2));
+        assert_eq!(ReplyOption::Invalid, parse_reply_option("2\n", 2));
+        assert_eq!(ReplyOption::Invalid, parse_reply_option("lgtm", 2));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    build_octocrab, committers,
+    config::Config,
+    get_token, git, pr,
+    reviewers::{self, Selection},
+};
+
+pub async fn add(number: Option<u64>) {
+    let config = Config::load();
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+    let requested = requested_logins(&branch_pr);
+
+    let candidates = reviewers::get_candidates(
+        &octocrab,
+        &base,
+        &repo,
+        &branch_pr.base.ref_field,
+        config.reviewer_source,
+    )
+    .await;
+
+    let Some(candidates) = candidates else {
+        return;
+    };
+
+    let suggested: Vec<String> = committers::get_recent_committers(
+        &octocrab,
+        &base,
+        &repo,
+        &branch_pr.base.ref_field,
+        config.suggested_committers(),
+    )
+    .await
+    .into_iter()
+    .filter(|s| !requested.contains(s))
+    .collect();
+    let suggested = reviewers::without_ignored(suggested, &config.never_suggest);
+
+    let candidates: Vec<String> = candidates
+        .into_iter()
+        .filter(|c| !requested.contains(c))
+        .collect();
+    let candidates = reviewers::without_ignored(candidates, &config.never_suggest);
+    let logins: Vec<String> = suggested.iter().chain(&candidates).cloned().collect();
+    let details = reviewers::get_details(&octocrab, &base, &logins).await;
+    let selection = reviewers::get_selected_reviewers(
+        candidates,
+        &suggested,
+        Selection::default(),
+        &config.reviewer_groups,
+        &details,
+    );
+
+    reviewers::request_reviews(&octocrab, &base, &repo, branch_pr.number, selection).await;
+}
+
+pub async fn remove(number: Option<u64>) {
+    let config = Config::load();
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let octocrab = build_octocrab(get_token());
+
+    let branch_pr = pr::get_branch_pr(&octocrab, &base, &repo, number).await;
+    let requested = requested_logins(&branch_pr);
+    let teams = requested_teams(&branch_pr, &base);
+
+    if requested.is_empty() && teams.is_empty() && config.reviewer_groups.is_empty() {
+        println!("\nNo review requests to remove");
+        return;
+    }
+
+    let details = reviewers::get_details(&octocrab, &base, &requested).await;
+    let selection = reviewers::get_selected_reviewers(
+        requested.into_iter().chain(teams).collect(),
+        &[],
+        Selection::default(),
+        &config.reviewer_groups,
+        &details,
+    );
+
+    reviewers::remove_review_requests(
+        &octocrab,
+        &base,
+        &repo,
+        branch_pr.number,
+        split_teams(selection),
+    )
+    .await;
+}
+
+fn requested_logins(branch_pr: &octocrab::models::pulls::PullRequest) -> Vec<String> {
+    branch_pr
+        .requested_reviewers
+        .iter()
+        .flatten()
+        .map(|u| u.login.clone())
+        .collect()
+}
+
+fn requested_teams(branch_pr: &octocrab::models::pulls::PullRequest, org: &str) -> Vec<String> {
+    branch_pr
+        .requested_teams
+        .iter()
+        .flatten()
+        .map(|t| format!("{org}/{}", t.slug))
+        .collect()
+}
+
+fn split_teams(selection: Selection) -> Selection {
+    let (teams, usernames): (Vec<String>, Vec<String>) = selection
+        .usernames
+        .into_iter()
+        .partition(|r| r.contains('/'));
+
+    Selection {
+        usernames,
+        teams: selection
+            .teams
+            .into_iter()
+            .chain(
+                teams
+                    .iter()
+                    .filter_map(|t| t.split_once('/'))
+                    .map(|(_, slug)| slug.to_owned()),
+            )
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_teams_test() {
+        let selection = split_teams(Selection {
+            usernames: vec!["alice".to_owned(), "acme/web".to_owned()],
+            teams: vec!["backend".to_owned()],
+        });
+
+        assert_eq!(selection.usernames, ["alice"]);
+        assert_eq!(selection.teams, ["backend", "web"]);
+    }
+}
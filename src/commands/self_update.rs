@@ -0,0 +1,154 @@
+use colored::Colorize;
+use octocrab::{models::repos::Asset, Octocrab};
+use sha2::{Digest, Sha256};
+use std::{env, fs, process::exit};
+
+use crate::{build_octocrab, network::with_timeout, proceed_question, GITHUB_TOKEN_VAR};
+
+const OWNER: &str = "joaopsramos";
+const REPO: &str = "prmaker";
+
+pub async fn run() {
+    let octocrab = env::var(GITHUB_TOKEN_VAR)
+        .map(build_octocrab)
+        .unwrap_or_default();
+
+    let release = match with_timeout!(octocrab.repos(OWNER, REPO).releases().get_latest()) {
+        Ok(release) => release,
+        Err(_) => {
+            println!("{}", "Couldn't fetch the latest release".red());
+            exit(1);
+        }
+    };
+
+    let current = env!("CARGO_PKG_VERSION");
+    let latest = release.tag_name.trim_start_matches('v');
+
+    if !is_newer(latest, current) {
+        println!("prmaker {current} is already the latest version");
+        return;
+    }
+
+    let name = asset_name();
+    let find_asset = |name: &str| release.assets.iter().find(|a| a.name == name);
+
+    let (Some(binary), Some(checksum)) = (find_asset(&name), find_asset(&format!("{name}.sha256")))
+    else {
+        println!(
+            "{}",
+            format!(
+                "Release {} has no {name} binary with a checksum",
+                release.tag_name
+            )
+            .red()
+        );
+        exit(1);
+    };
+
+    println!("\nUpdating prmaker {current} to {}", latest.cyan());
+    proceed_question();
+
+    println!("\nDownloading {name}...");
+
+    let bytes = download(&octocrab, binary).await;
+    let checksum = String::from_utf8_lossy(&download(&octocrab, checksum).await).into_owned();
+
+    if parse_checksum(&checksum) != Some(sha256(&bytes)) {
+        println!("{}", "Checksum mismatch, the binary was not replaced".red());
+        exit(1);
+    }
+
+    if let Err(err) = replace_executable(&bytes) {
+        println!("{}", "Couldn't replace the prmaker executable".red());
+        println!("{err}");
+        exit(1);
+    }
+
+    println!("\n{}", format!("Updated to prmaker {latest}").green());
+}
+
+async fn download(octocrab: &Octocrab, asset: &Asset) -> Vec<u8> {
+    let resp = match with_timeout!(octocrab._get(asset.browser_download_url.as_str(), None::<&()>))
+    {
+        Ok(resp) if resp.status().is_success() => resp,
+        _ => {
+            println!("{}", format!("Couldn't download {}", asset.name).red());
+            exit(1);
+        }
+    };
+
+    match resp.bytes().await {
+        Ok(bytes) => bytes.to_vec(),
+        Err(_) => {
+            println!("{}", format!("Couldn't download {}", asset.name).red());
+            exit(1);
+        }
+    }
+}
+
+fn replace_executable(bytes: &[u8]) -> std::io::Result<()> {
+    let current = env::current_exe()?;
+    let staged = current.with_extension("new");
+
+    fs::write(&staged, bytes)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))?;
+    }
+
+    fs::rename(&staged, &current)
+}
+
+fn asset_name() -> String {
+    format!("prmaker-{}-{}", env::consts::ARCH, env::consts::OS)
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn parse_checksum(checksum: &str) -> Option<String> {
+    checksum
+        .split_whitespace()
+        .next()
+        .map(|hash| hash.to_lowercase())
+}
+
+fn is_newer(latest: &str, current: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+
+    parse(latest) > parse(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_newer_test() {
+        assert!(is_newer("0.2.0", "0.1.0"));
+        assert!(is_newer("0.10.0", "0.9.1"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1.0", "0.2.0"));
+    }
+
+    #[test]
+    fn checksum_test() {
+        let checksum =
+            "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824  prmaker\n";
+
+        assert_eq!(parse_checksum(checksum), Some(sha256(b"hello")));
+        assert_eq!(parse_checksum(""), None);
+    }
+}
@@ -0,0 +1,181 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use colored::Colorize;
+use octocrab::Octocrab;
+use serde::Deserialize;
+use std::process::exit;
+
+use crate::{build_octocrab, get_token, history, network::with_timeout};
+
+const SIZE_BUCKETS: [(u64, &str); 4] = [(10, "XS"), (50, "S"), (250, "M"), (1000, "L")];
+const LARGEST_BUCKET: &str = "XL";
+
+#[derive(Debug, Deserialize)]
+struct PullStats {
+    created_at: DateTime<Utc>,
+    merged_at: Option<DateTime<Utc>>,
+    additions: u64,
+    deletions: u64,
+}
+
+pub async fn run(since: Option<String>, until: Option<String>) {
+    let since = since.map(|d| parse_date(&d));
+    let until = until.map(|d| parse_date(&d));
+
+    let entries: Vec<history::Entry> = history::load()
+        .into_iter()
+        .filter(|e| {
+            let date = e.created_at.date_naive();
+
+            since.is_none_or(|s| date >= s) && until.is_none_or(|u| date <= u)
+        })
+        .collect();
+
+    if entries.is_empty() {
+        println!("\nNo PRs found in the history for this range");
+        return;
+    }
+
+    let octocrab = build_octocrab(get_token());
+    let mut merge_times = vec![];
+    let mut sizes = vec![];
+
+    println!("\nFetching {} PRs...", entries.len());
+
+    for entry in &entries {
+        let Some(stats) = get_pull_stats(&octocrab, entry).await else {
+            continue;
+        };
+
+        if let Some(merged_at) = stats.merged_at {
+            merge_times.push(merged_at - stats.created_at);
+        }
+
+        sizes.push(size_bucket(stats.additions + stats.deletions).to_owned());
+    }
+
+    println!("\n{}", "** PRs per repo **".blue());
+    print_counts(count(entries.iter().map(|e| e.repo.clone())));
+
+    println!("\n{}", "** Most requested reviewers **".blue());
+    print_counts(count(entries.iter().flat_map(|e| e.reviewers.clone())));
+
+    println!("\n{}", "** PR sizes **".blue());
+    print_counts(count(sizes));
+
+    println!("\n{}", "** Time to merge **".blue());
+
+    match average(&merge_times) {
+        Some(duration) => println!(
+            "{} across {} merged PRs",
+            format_duration(duration).cyan(),
+            merge_times.len()
+        ),
+        None => println!("No merged PRs"),
+    }
+}
+
+async fn get_pull_stats(octocrab: &Octocrab, entry: &history::Entry) -> Option<PullStats> {
+    let route = format!("repos/{}/pulls/{}", entry.repo, entry.number);
+
+    match with_timeout!(octocrab.get(&route, None::<&()>)) {
+        Ok(stats) => Some(stats),
+        Err(_) => {
+            println!(
+                "{}",
+                format!(
+                    "Couldn't fetch {}#{}, ignoring...",
+                    entry.repo, entry.number
+                )
+                .red()
+            );
+            None
+        }
+    }
+}
+
+fn parse_date(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap_or_else(|_| {
+        println!(
+            "{}",
+            format!("Invalid date {date}, use the YYYY-MM-DD format").red()
+        );
+        exit(1);
+    })
+}
+
+fn print_counts(counts: Vec<(String, usize)>) {
+    if counts.is_empty() {
+        println!("None");
+    }
+
+    for (name, count) in counts {
+        println!("{} {}", count.to_string().purple(), name);
+    }
+}
+
+fn count(values: impl IntoIterator<Item = String>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = vec![];
+
+    for value in values {
+        match counts.iter_mut().find(|(name, _)| *name == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    counts
+}
+
+fn size_bucket(lines: u64) -> &'static str {
+    SIZE_BUCKETS
+        .iter()
+        .find(|(max, _)| lines < *max)
+        .map(|(_, name)| *name)
+        .unwrap_or(LARGEST_BUCKET)
+}
+
+fn average(durations: &[Duration]) -> Option<Duration> {
+    let total: Duration = durations.iter().copied().sum();
+
+    (!durations.is_empty()).then(|| total / durations.len() as i32)
+}
+
+fn format_duration(duration: Duration) -> String {
+    let hours = duration.num_hours();
+
+    if hours < 48 {
+        format!("{hours}h {}m", duration.num_minutes() % 60)
+    } else {
+        format!("{}d {}h", hours / 24, hours % 24)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_test() {
+        let repos = ["acme/web", "acme/api", "acme/web"].map(|r| r.to_owned());
+
+        assert_eq!(
+            count(repos),
+            [("acme/web".to_owned(), 2), ("acme/api".to_owned(), 1)]
+        );
+    }
+
+    #[test]
+    fn time_and_size_test() {
+        assert_eq!(size_bucket(9), "XS");
+        assert_eq!(size_bucket(250), "L");
+        assert_eq!(size_bucket(5000), "XL");
+
+        let merge_times = [Duration::hours(2), Duration::hours(4)];
+
+        assert_eq!(average(&merge_times), Some(Duration::hours(3)));
+        assert_eq!(average(&[]), None);
+        assert_eq!(format_duration(Duration::minutes(90)), "1h 30m");
+        assert_eq!(format_duration(Duration::hours(50)), "2d 2h");
+    }
+}
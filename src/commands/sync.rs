@@ -0,0 +1,104 @@
+use colored::Colorize;
+use std::process::exit;
+
+use crate::{
+    base_branch, build_octocrab,
+    config::{Config, SyncStrategy},
+    confirm_or, get_token, git, pr,
+};
+
+pub async fn run(base_branch: Option<String>) {
+    let config = Config::load();
+    let branch = git::current_branch();
+
+    let base_branch = match base_branch {
+        Some(base_branch) => base_branch,
+        None => {
+            let remote_url = git::remote_url();
+            let base = pr::get_base(&remote_url);
+            let repo = pr::get_repo(&remote_url);
+            let octocrab = build_octocrab(get_token());
+
+            base_branch::resolve(&octocrab, &base, &repo, config.base_branch()).await
+        }
+    };
+
+    if branch.is_empty() || branch == base_branch {
+        println!(
+            "{}",
+            format!("Checkout the branch you want to sync with {base_branch} first").red()
+        );
+        exit(1);
+    }
+
+    println!("\nFetching {base_branch}...");
+
+    git::run_or_exit(&["fetch", "origin", &base_branch]);
+
+    let upstream = format!("origin/{base_branch}");
+
+    match config.sync_strategy {
+        SyncStrategy::Rebase => {
+            println!("\nRebasing {branch} onto {upstream}...");
+
+            if git::try_git(&["rebase", &upstream]).is_err() {
+                handle_conflicts("rebase");
+                exit(1);
+            }
+
+            println!("\nPushing {branch}...");
+
+            git::run_or_exit(&["push", "--force-with-lease", "origin", &branch]);
+        }
+        SyncStrategy::Merge => {
+            println!("\nMerging {upstream} into {branch}...");
+
+            if git::try_git(&["merge", "--no-edit", &upstream]).is_err() {
+                handle_conflicts("merge");
+                exit(1);
+            }
+
+            println!("\nPushing {branch}...");
+
+            git::run_or_exit(&["push", "origin", &branch]);
+        }
+    }
+
+    println!(
+        "\n{}",
+        format!("{branch} is up to date with {base_branch}").green()
+    );
+}
+
+fn handle_conflicts(operation: &str) {
+    let conflicts = git::conflicted_files();
+
+    if conflicts.is_empty() {
+        println!(
+            "\n{}",
+            format!("Couldn't {operation}, make sure the working tree is clean").red()
+        );
+        return;
+    }
+
+    println!(
+        "\n{}",
+        format!("The {operation} stopped because of conflicts in:").red()
+    );
+
+    for file in conflicts {
+        println!("  {}", file.yellow());
+    }
+
+    if confirm_or(&format!("Abort the {operation}?"), false) {
+        let _ = git::try_git(&[operation, "--abort"]);
+
+        println!("\n{}", format!("Aborted the {operation}").yellow());
+    } else {
+        println!(
+            "\nResolve the conflicts, run {}, then {} again",
+            format!("git {operation} --continue").cyan(),
+            "prmaker sync".cyan()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use colored::Colorize;
+use octocrab::params::pulls;
+use std::process::exit;
+
+use crate::{
+    build_octocrab, confirm_or, get_token,
+    network::with_timeout,
+    reviewers::{self, Selection},
+    run_log::RunLog,
+};
+
+pub async fn run() {
+    let Some(run_log) = RunLog::load() else {
+        println!("\nNothing to undo");
+        return;
+    };
+
+    let octocrab = build_octocrab(get_token());
+    let RunLog {
+        base, repo, number, ..
+    } = &run_log;
+
+    println!("\n{}", "** Last run **".blue());
+    println!("PR: {}", run_log.link.cyan());
+
+    if !run_log.reviewers.is_empty() || !run_log.teams.is_empty() {
+        let requested: Vec<String> = run_log
+            .reviewers
+            .iter()
+            .chain(run_log.teams.iter())
+            .cloned()
+            .collect();
+
+        println!("Review requests: {}", requested.join(", ").cyan());
+    }
+
+    if !run_log.labels.is_empty() {
+        println!("Labels: {}", run_log.labels.join(", ").cyan());
+    }
+
+    if confirm_or("Remove the review requests and labels?", false) {
+        let selection = Selection {
+            usernames: run_log.reviewers.clone(),
+            teams: run_log.teams.clone(),
+        };
+
+        reviewers::remove_review_requests(&octocrab, base, repo, *number, selection).await;
+
+        for label in &run_log.labels {
+            if with_timeout!(octocrab.issues(base, repo).remove_label(*number, label)).is_err() {
+                println!("{}", format!("Error when removing label {label}").red());
+            }
+        }
+    }
+
+    if confirm_or(&format!("Close PR #{number}?"), false) {
+        let update_resp = with_timeout!(octocrab
+            .pulls(base, repo)
+            .update(*number)
+            .state(pulls::State::Closed)
+            .send());
+
+        match update_resp {
+            Ok(_) => println!("\n{} #{number}", "PR closed".green()),
+            Err(_) => {
+                println!("\n{}", "Error when closing the PR".red());
+                exit(1);
+            }
+        }
+    }
+
+    RunLog::clear();
+}
@@ -0,0 +1,149 @@
+use colored::Colorize;
+use std::{collections::BTreeMap, env, fs, process::Command, time::Duration};
+
+use crate::{base_branch, build_octocrab, config::Config, confirm, get_token, git, pr, run_log};
+
+const WATCH_FILE: &str = "watched_branches.json";
+
+type Known = BTreeMap<String, Vec<String>>;
+
+pub async fn run(interval: u64, once: bool) {
+    let remote_url = git::remote_url();
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let key = format!("{base}/{repo}");
+    let octocrab = build_octocrab(get_token());
+    let exe = env::current_exe().expect("failed to get the prmaker executable path");
+
+    let mut all_known = load();
+    let first_run = !all_known.contains_key(&key);
+    let mut known = all_known.remove(&key).unwrap_or_default();
+
+    if first_run {
+        known = git::branches("refs/remotes/origin");
+    }
+
+    let ignored: Vec<String> = [
+        Config::load().base_branch().map(str::to_owned),
+        base_branch::get_default_branch(&octocrab, &base, &repo).await,
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if !once {
+        println!(
+            "\nWatching {} for newly pushed branches, press Ctrl+C to stop...",
+            key.cyan()
+        );
+    }
+
+    loop {
+        let remote = git::branches("refs/remotes/origin");
+        let local = git::branches("refs/heads");
+
+        for branch in new_branches(&known, &remote, &local, &ignored) {
+            known.push(branch.clone());
+
+            match pr::find_open_pr(&octocrab, &base, &repo, &branch).await {
+                Ok(None) => {}
+                Ok(Some(_)) => continue,
+                Err(_) => {
+                    println!(
+                        "{}",
+                        format!("Couldn't check whether {branch} has a PR, skipping it").red()
+                    );
+                    continue;
+                }
+            }
+
+            println!(
+                "\n{} {}",
+                "New branch without a PR:".yellow(),
+                branch.cyan()
+            );
+
+            if confirm("Create a PR for it now?") {
+                let status = Command::new(&exe).args(["--head", &branch]).status();
+
+                if status.is_err() {
+                    println!("{}", "Couldn't start the PR creation flow".red());
+                }
+            }
+        }
+
+        known.retain(|b| remote.contains(b));
+        save(&key, &known);
+
+        if once {
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_secs(interval)).await;
+    }
+}
+
+fn new_branches(
+    known: &[String],
+    remote: &[String],
+    local: &[String],
+    ignored: &[String],
+) -> Vec<String> {
+    remote
+        .iter()
+        .filter(|b| local.contains(b) && !known.contains(b) && !ignored.contains(b))
+        .cloned()
+        .collect()
+}
+
+fn load() -> Known {
+    run_log::state_path(WATCH_FILE)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(key: &str, known: &[String]) {
+    let Some(path) = run_log::state_path(WATCH_FILE) else {
+        return;
+    };
+
+    let mut all_known = load();
+    all_known.insert(key.to_owned(), known.to_vec());
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    if let Ok(content) = serde_json::to_string_pretty(&all_known) {
+        let _ = fs::write(path, content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_branches_test() {
+        let to_vec =
+            |branches: &[&str]| -> Vec<String> { branches.iter().map(|b| b.to_string()).collect() };
+
+        assert_eq!(
+            vec!["feature/login"],
+            new_branches(
+                &to_vec(&["main", "fix/typo"]),
+                &to_vec(&["main", "fix/typo", "feature/login", "teammate/wip"]),
+                &to_vec(&["main", "feature/login"]),
+                &[],
+            )
+        );
+        assert!(new_branches(
+            &[],
+            &to_vec(&["develop"]),
+            &to_vec(&["develop"]),
+            &to_vec(&["develop"])
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,505 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use std::{process::exit, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    time::timeout,
+};
+
+use crate::{
+    build_octocrab,
+    cli::CreateArgs,
+    commands::open::open_in_browser,
+    config::Config,
+    duplicates, get_token, get_user,
+    pr::{self, PR},
+    reviewers, validation,
+};
+
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Form {
+    token: String,
+    title: String,
+    body: String,
+    issues: Vec<String>,
+    reviewers: Vec<String>,
+    confirmed: bool,
+}
+
+struct Request {
+    method: String,
+    target: String,
+    host: Option<String>,
+    origin: Option<String>,
+    body: String,
+}
+
+pub async fn run(args: CreateArgs, port: u16, no_open: bool) {
+    let user = get_user();
+    let config = Config::load();
+    let octocrab = build_octocrab(get_token());
+
+    let mut pr = PR::build(config, &args, &octocrab).await;
+    let body = pr::default_body(config, &pr.base_branch);
+    pr.restore(&pr.title.clone(), &body, config);
+
+    let source = args.reviewer_source.unwrap_or(config.reviewer_source);
+    let candidates =
+        reviewers::get_candidates(&octocrab, &pr.base, &pr.repo, &pr.base_branch, source)
+            .await
+            .unwrap_or_default();
+    let candidates = reviewers::without_ignored(candidates, &config.never_suggest);
+    let selected = [pr.suggested_reviewers(), args.reviewers.clone()].concat();
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .unwrap_or_else(|err| {
+            println!("{}", "Couldn't start the web server".red());
+            println!("{err}");
+            exit(1);
+        });
+    let address = listener.local_addr().unwrap();
+    let hosts = [address.to_string(), format!("localhost:{}", address.port())];
+    let token = random_token();
+    let url = format!("http://{address}/?token={token}");
+
+    println!("\nPR form: {}", url.cyan());
+    println!("Waiting for the form to be submitted, press Ctrl+C to cancel...");
+
+    if !no_open {
+        open_in_browser(&url);
+    }
+
+    let (sender, mut requests) = mpsc::channel::<(TcpStream, Request)>(8);
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let Ok((mut socket, _)) = accepted else {
+                    break;
+                };
+                let sender = sender.clone();
+
+                tokio::spawn(async move {
+                    if let Ok(Some(request)) = timeout(READ_TIMEOUT, read_request(&mut socket)).await {
+                        let _ = sender.send((socket, request)).await;
+                    }
+                });
+            }
+            Some((mut socket, request)) = requests.recv() => {
+                if !authorized(&request, &hosts, &token) {
+                    respond(&mut socket, "403 Forbidden", &page_with("<p>Forbidden</p>")).await;
+                    continue;
+                }
+
+                if request.method != "POST" {
+                    let page = render_form(&pr, &candidates, &selected, &token, "");
+                    respond(&mut socket, "200 OK", &page).await;
+                    continue;
+                }
+
+                let form = parse_form(&request.body);
+
+                pr.yt_issues = form.issues.clone();
+                pr.restore(&form.title, &form.body, config);
+
+                if let Some(notice) = check(&octocrab, &pr, config, form.confirmed).await {
+                    let page = render_form(&pr, &candidates, &form.reviewers, &token, &notice);
+                    respond(&mut socket, "200 OK", &page).await;
+                    continue;
+                }
+
+                let result = match create(&octocrab, &mut pr, config, &user, &form.reviewers).await {
+                    Some(link) => format!("<p>PR created: <a href=\"{0}\">{0}</a></p>", escape(&link)),
+                    None => "<p>Something went wrong creating the PR, check the terminal.</p>".to_owned(),
+                };
+
+                respond(&mut socket, "200 OK", &page_with(&result)).await;
+
+                if pr.link.is_some() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn random_token() -> String {
+    let mut bytes = [0; 32];
+
+    getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+        println!("{}", "Couldn't generate the form token".red());
+        println!("{err}");
+        exit(1);
+    });
+
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn authorized(request: &Request, hosts: &[String], token: &str) -> bool {
+    let Some(host) = request.host.as_ref().filter(|h| hosts.contains(h)) else {
+        return false;
+    };
+
+    if request
+        .origin
+        .as_ref()
+        .is_some_and(|origin| *origin != format!("http://{host}"))
+    {
+        return false;
+    }
+
+    match request.method.as_str() {
+        "GET" => query_token(&request.target) == Some(token),
+        "POST" => parse_form(&request.body).token == token,
+        _ => false,
+    }
+}
+
+fn query_token(target: &str) -> Option<&str> {
+    let (_, query) = target.split_once('?')?;
+
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+}
+
+async fn check(octocrab: &Octocrab, pr: &PR, config: &Config, confirmed: bool) -> Option<String> {
+    let errors = validation::validate(&pr.title, &pr.full_body, &config.validation);
+
+    if !errors.is_empty() {
+        return Some(notice("The PR doesn't pass validation:", &errors, false));
+    }
+
+    if confirmed {
+        return None;
+    }
+
+    let placeholders = pr.placeholders(config);
+
+    if !placeholders.is_empty() {
+        return Some(notice(
+            "The body still has placeholders:",
+            &placeholders,
+            true,
+        ));
+    }
+
+    let similar = duplicates::find_similar(octocrab, pr).await;
+
+    if !similar.is_empty() {
+        return Some(notice("A similar PR already exists:", &similar, true));
+    }
+
+    None
+}
+
+fn notice(title: &str, items: &[String], confirmable: bool) -> String {
+    let items: String = items
+        .iter()
+        .map(|item| format!("<li>{}</li>", escape(item)))
+        .collect();
+    let confirm = match confirmable {
+        true => "<p><label><input type=\"checkbox\" name=\"confirm\" value=\"yes\"> Create it anyway</label></p>",
+        false => "",
+    };
+
+    format!(
+        "<p><strong>{}</strong></p>\n<ul>{items}</ul>\n{confirm}",
+        escape(title)
+    )
+}
+
+async fn create(
+    octocrab: &Octocrab,
+    pr: &mut PR,
+    config: &Config,
+    user: &str,
+    names: &[String],
+) -> Option<String> {
+    println!("\nCreating PR {}...", pr.title.cyan());
+
+    pr.create(octocrab).await.ok()?;
+    pr.patch_late_vars(octocrab, &config.late_vars).await;
+    pr.assign_self(octocrab, user).await;
+    pr.add_labels(octocrab, &pr.project_labels()).await;
+
+    let selection = reviewers::expand(names, &config.reviewer_groups);
+
+    if !selection.is_empty() {
+        reviewers::request_reviews(octocrab, &pr.base, &pr.repo, pr.number?, selection).await;
+    }
+
+    let link = pr.link.clone()?;
+    println!("\nPR: {link}");
+
+    Some(link)
+}
+
+async fn read_request(socket: &mut TcpStream) -> Option<Request> {
+    let mut buffer = vec![];
+    let mut chunk = [0; 4096];
+
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.ok()?;
+
+        if read == 0 {
+            return None;
+        }
+
+        buffer.extend_from_slice(&chunk[..read]);
+
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+    let mut request_line = head.split_whitespace();
+    let method = request_line.next()?.to_owned();
+    let target = request_line.next()?.to_owned();
+    let header = |header: &str| {
+        head.lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case(header))
+            .map(|(_, value)| value.trim().to_owned())
+    };
+    let content_length: usize = header("content-length")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = buffer[header_end..].to_vec();
+
+    while body.len() < content_length {
+        let read = socket.read(&mut chunk).await.ok()?;
+
+        if read == 0 {
+            break;
+        }
+
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(Request {
+        method,
+        target,
+        host: header("host"),
+        origin: header("origin"),
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+async fn respond(socket: &mut TcpStream, status: &str, html: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\ncontent-type: text/html; charset=utf-8\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{html}",
+        html.len()
+    );
+
+    let _ = socket.write_all(response.as_bytes()).await;
+}
+
+fn render_form(
+    pr: &PR,
+    candidates: &[String],
+    selected: &[String],
+    token: &str,
+    notice: &str,
+) -> String {
+    let mut reviewers: Vec<&String> = selected.iter().collect();
+
+    for candidate in candidates {
+        if !reviewers.contains(&candidate) {
+            reviewers.push(candidate);
+        }
+    }
+
+    let checkboxes: String = reviewers
+        .iter()
+        .map(|login| {
+            let checked = if selected.contains(login) { " checked" } else { "" };
+            format!(
+                "<label><input type=\"checkbox\" name=\"reviewer\" value=\"{0}\"{checked}> {0}</label><br>\n",
+                escape(login)
+            )
+        })
+        .collect();
+
+    page_with(&format!(
+        "<p>{} &rarr; {} in {}/{}</p>
+{notice}
+<form method=\"post\" action=\"/?token={token}\">
+<input type=\"hidden\" name=\"token\" value=\"{token}\">
+<p><label>Title<br><input name=\"title\" size=\"80\" value=\"{}\"></label></p>
+<p><label>Issues, comma separated<br><input name=\"issues\" size=\"80\" value=\"{}\"></label></p>
+<p><label>Body<br><textarea name=\"body\" rows=\"20\" cols=\"80\">{}</textarea></label></p>
+<fieldset><legend>Reviewers</legend>
+{checkboxes}<label>Others, comma separated <input name=\"reviewers\" size=\"40\"></label>
+</fieldset>
+<p><button type=\"submit\">Create PR</button></p>
+</form>",
+        escape(&pr.branch),
+        escape(&pr.base_branch),
+        escape(&pr.base),
+        escape(&pr.repo),
+        escape(&pr.title),
+        escape(&pr.yt_issues.join(", ")),
+        escape(&pr.body),
+    ))
+}
+
+fn page_with(content: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>prmaker</title></head>\n<body>\n<h1>prmaker</h1>\n{content}\n</body>\n</html>\n"
+    )
+}
+
+fn parse_form(body: &str) -> Form {
+    let mut form = Form {
+        token: String::new(),
+        title: String::new(),
+        body: String::new(),
+        issues: vec![],
+        reviewers: vec![],
+        confirmed: false,
+    };
+
+    let split = |value: &str| -> Vec<String> {
+        value
+            .split(',')
+            .map(|v| v.trim().to_owned())
+            .filter(|v| !v.is_empty())
+            .collect()
+    };
+
+    for pair in body.split('&') {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = decode(value);
+
+        match name {
+            "token" => form.token = value,
+            "confirm" => form.confirmed = value == "yes",
+            "title" => form.title = value.trim().to_owned(),
+            "body" => form.body = value.replace("\r\n", "\n").trim().to_owned(),
+            "issues" => form.issues = split(&value),
+            "reviewer" => form.reviewers.push(value),
+            "reviewers" => form.reviewers.extend(split(&value)),
+            _ => {}
+        }
+    }
+
+    form
+}
+
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match value
+                .get(i + 1..i + 3)
+                .map(|hex| u8::from_str_radix(hex, 16))
+            {
+                Some(Ok(byte)) => {
+                    decoded.push(byte);
+                    i += 2;
+                }
+                _ => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_form_test() {
+        let form = parse_form(
+            "title=feat%3A+add+login&body=Adds+it%0D%0A%0D%0A100%25+done&issues=AUTH-1%2C+AUTH-2&reviewer=alice&reviewer=bob&reviewers=carol%2C+%40backend",
+        );
+
+        assert_eq!("feat: add login", form.title);
+        assert_eq!("Adds it\n\n100% done", form.body);
+        assert_eq!(vec!["AUTH-1", "AUTH-2"], form.issues);
+        assert_eq!(vec!["alice", "bob", "carol", "@backend"], form.reviewers);
+    }
+
+    #[test]
+    fn authorized_test() {
+        let hosts = ["127.0.0.1:4000".to_owned(), "localhost:4000".to_owned()];
+        let request = |method: &str, target: &str, origin: Option<&str>, body: &str| Request {
+            method: method.to_owned(),
+            target: target.to_owned(),
+            host: Some("127.0.0.1:4000".to_owned()),
+            origin: origin.map(str::to_owned),
+            body: body.to_owned(),
+        };
+
+        assert!(authorized(
+            &request("GET", "/?token=abc", None, ""),
+            &hosts,
+            "abc"
+        ));
+        assert!(authorized(
+            &request(
+                "POST",
+                "/?token=abc",
+                Some("http://127.0.0.1:4000"),
+                "token=abc&title=x"
+            ),
+            &hosts,
+            "abc"
+        ));
+        assert!(!authorized(&request("GET", "/", None, ""), &hosts, "abc"));
+        assert!(!authorized(
+            &request(
+                "POST",
+                "/?token=abc",
+                Some("https://evil.example"),
+                "token=abc"
+            ),
+            &hosts,
+            "abc"
+        ));
+        assert!(!authorized(
+            &request("POST", "/?token=abc", None, "title=x"),
+            &hosts,
+            "abc"
+        ));
+        assert!(!authorized(
+            &Request {
+                host: Some("evil.example:4000".to_owned()),
+                ..request("GET", "/?token=abc", None, "")
+            },
+            &hosts,
+            "abc"
+        ));
+    }
+
+    #[test]
+    fn escape_test() {
+        assert_eq!(
+            "&lt;!-- a &amp; &quot;b&quot; --&gt;",
+            escape("<!-- a & \"b\" -->")
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use colored::Colorize;
+
+use crate::{flush_line, git, read_input};
+
+const FIELD_SEPARATOR: char = '\u{1f}';
+const NOISE_PREFIXES: [&str; 3] = ["fixup! ", "squash! ", "amend! "];
+
+#[derive(Debug, PartialEq)]
+struct Commit {
+    sha: String,
+    subject: String,
+    merge: bool,
+}
+
+pub fn get_selected_subjects(base_branch: &str) -> Vec<String> {
+    let range = format!("origin/{base_branch}..{}", git::head());
+    let format = format!("--pretty=format:%h{FIELD_SEPARATOR}%s{FIELD_SEPARATOR}%p");
+
+    let commits = match git::try_git(&["log", "--reverse", &format, &range]) {
+        Ok(log) => parse_commits(&log),
+        Err(_) => return vec![],
+    };
+
+    let mut selected: Vec<bool> = commits.iter().map(|c| !is_noise(c)).collect();
+
+    loop {
+        println!("\n{}", "** Commits **".blue());
+
+        for (index, commit) in commits.iter().enumerate() {
+            let result = format!(
+                "{} - {} {}",
+                index.to_string().purple(),
+                commit.sha,
+                commit.subject
+            );
+
+            if selected[index] {
+                println!("{}", result.cyan());
+            } else {
+                println!("{}", result);
+            }
+        }
+
+        print!(
+            "\n{}",
+            "Toggle a commit, keep only a range like 2-5 (empty to proceed): ".yellow()
+        );
+        flush_line();
+
+        let opt = read_input();
+
+        match parse_option(opt.trim(), commits.len()) {
+            Some(Selection::Done) => break,
+            Some(Selection::Toggle(index)) => selected[index] = !selected[index],
+            Some(Selection::Range(start, end)) => {
+                for (index, selected) in selected.iter_mut().enumerate() {
+                    *selected = (start..=end).contains(&index);
+                }
+            }
+            None => println!("{}", "Invalid option".red()),
+        }
+    }
+
+    commits
+        .into_iter()
+        .zip(selected)
+        .filter(|(_, selected)| *selected)
+        .map(|(commit, _)| commit.subject)
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+enum Selection {
+    Done,
+    Toggle(usize),
+    Range(usize, usize),
+}
+
+fn parse_option(opt: &str, len: usize) -> Option<Selection> {
+    if opt.is_empty() {
+        return Some(Selection::Done);
+    }
+
+    if let Some((start, end)) = opt.split_once('-') {
+        let start: usize = start.trim().parse().ok()?;
+        let end: usize = end.trim().parse().ok()?;
+
+        return (start <= end && end < len).then_some(Selection::Range(start, end));
+    }
+
+    let index: usize = opt.parse().ok()?;
+
+    (index < len).then_some(Selection::Toggle(index))
+}
+
+fn is_noise(commit: &Commit) -> bool {
+    commit.merge || NOISE_PREFIXES.iter().any(|p| commit.subject.starts_with(p))
+}
+
+fn parse_commits(log: &str) -> Vec<Commit> {
+    log.lines()
+        .filter_map(|line| {
+            let mut fields = line.split(FIELD_SEPARATOR);
+            let sha = fields.next()?;
+            let subject = fields.next()?;
+            let parents = fields.next().unwrap_or_default();
+
+            Some(Commit {
+                sha: sha.to_owned(),
+                subject: subject.to_owned(),
+                merge: parents.split_whitespace().count() > 1,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_commits_test() {
+        let log = "a1\u{1f}feat: add picker\u{1f}p1\n\
+                   b2\u{1f}fixup! feat: add picker\u{1f}a1\n\
+                   c3\u{1f}Merge branch 'main'\u{1f}b2 m1";
+
+        let noise: Vec<bool> = parse_commits(log).iter().map(is_noise).collect();
+
+        assert_eq!(noise, [false, true, true]);
+    }
+
+    #[test]
+    fn parse_option_test() {
+        assert_eq!(Some(Selection::Done), parse_option("", 3));
+        assert_eq!(Some(Selection::Toggle(2)), parse_option("2", 3));
+        assert_eq!(Some(Selection::Range(0, 1)), parse_option("0-1", 3));
+        assert_eq!(None, parse_option("1-3", 3));
+        assert_eq!(None, parse_option("2-1", 3));
+        assert_eq!(None, parse_option("x", 3));
+    }
+}
@@ -0,0 +1,141 @@
+use octocrab::Octocrab;
+use regex::Regex;
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+use crate::{git, network::with_timeout};
+
+const MAX_COMMITS: &str = "--max-count=200";
+pub const MAX_AUTHORS: usize = 10;
+static NOREPLY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:\d+\+)?([^@]+)@users\.noreply\.github\.com$").unwrap());
+
+#[derive(Deserialize)]
+struct CommitResponse {
+    author: Option<Author>,
+}
+
+#[derive(Deserialize)]
+struct Author {
+    login: String,
+}
+
+pub async fn get_recent_committers(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    base_branch: &str,
+    limit: usize,
+) -> Vec<String> {
+    if limit == 0 {
+        return vec![];
+    }
+
+    let base_ref = format!("origin/{base_branch}");
+    let files = git::changed_files(&format!("{base_ref}...{}", git::head()));
+
+    if files.is_empty() {
+        return vec![];
+    }
+
+    let mut args = vec![
+        "log",
+        "--no-merges",
+        MAX_COMMITS,
+        "--pretty=format:%H%x1f%ae",
+        &base_ref,
+        "--",
+    ];
+    args.extend(files.iter().map(String::as_str));
+
+    let Ok(log) = git::try_git(&args) else {
+        return vec![];
+    };
+
+    let mut logins: Vec<String> = vec![];
+
+    for (email, sha) in rank_authors(&log).into_iter().take(limit) {
+        let login = match noreply_login(&email) {
+            Some(login) => Some(login),
+            None => get_commit_author(octocrab, base, repo, &sha).await,
+        };
+
+        if let Some(login) = login.filter(|l| !logins.contains(l)) {
+            logins.push(login);
+        }
+    }
+
+    logins
+}
+
+async fn get_commit_author(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    sha: &str,
+) -> Option<String> {
+    let route = format!("repos/{base}/{repo}/commits/{sha}");
+    let commit: CommitResponse = with_timeout!(octocrab.get(&route, None::<&()>)).ok()?;
+
+    commit.author.map(|a| a.login)
+}
+
+fn noreply_login(email: &str) -> Option<String> {
+    NOREPLY_REGEX.captures(email).map(|c| c[1].to_owned())
+}
+
+fn rank_authors(log: &str) -> Vec<(String, String)> {
+    let mut authors: Vec<(String, String, usize)> = vec![];
+
+    for line in log.lines() {
+        let Some((sha, email)) = line.split_once('\u{1f}') else {
+            continue;
+        };
+
+        match authors.iter_mut().find(|(e, _, _)| e == email) {
+            Some((_, _, count)) => *count += 1,
+            None => authors.push((email.to_owned(), sha.to_owned(), 1)),
+        }
+    }
+
+    authors.sort_by_key(|(_, _, count)| std::cmp::Reverse(*count));
+
+    authors
+        .into_iter()
+        .map(|(email, sha, _)| (email, sha))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_authors_test() {
+        let log = "s1\u{1f}bob@example.com\n\
+                   s2\u{1f}alice@example.com\n\
+                   s3\u{1f}alice@example.com\n\
+                   s4\u{1f}carol@example.com";
+
+        let emails: Vec<String> = rank_authors(log).into_iter().map(|(e, _)| e).collect();
+
+        assert_eq!(
+            emails,
+            ["alice@example.com", "bob@example.com", "carol@example.com"]
+        );
+        assert_eq!(rank_authors(log)[0].1, "s2");
+    }
+
+    #[test]
+    fn noreply_login_test() {
+        assert_eq!(
+            Some("octocat".to_owned()),
+            noreply_login("583231+octocat@users.noreply.github.com")
+        );
+        assert_eq!(
+            Some("octocat".to_owned()),
+            noreply_login("octocat@users.noreply.github.com")
+        );
+        assert_eq!(None, noreply_login("octocat@github.com"));
+    }
+}
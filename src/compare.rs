@@ -0,0 +1,156 @@
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::network::with_timeout;
+
+#[derive(Debug, Deserialize)]
+pub struct Comparison {
+    pub status: String,
+    pub ahead_by: u64,
+    pub behind_by: u64,
+    #[serde(default)]
+    pub commits: Vec<ComparedCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComparedCommit {
+    pub sha: String,
+    pub commit: CommitDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitDetails {
+    pub message: String,
+    pub verification: Option<Verification>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Verification {
+    pub verified: bool,
+    pub reason: String,
+}
+
+impl Comparison {
+    pub fn unverified_commits(&self) -> Vec<String> {
+        self.commits
+            .iter()
+            .filter(|c| !c.commit.verification.as_ref().is_some_and(|v| v.verified))
+            .map(|c| {
+                let subject = c.commit.message.lines().next().unwrap_or_default();
+                let reason = c
+                    .commit
+                    .verification
+                    .as_ref()
+                    .map(|v| v.reason.as_str())
+                    .unwrap_or("unknown");
+
+                format!("{} {subject} ({reason})", &c.sha[..c.sha.len().min(7)])
+            })
+            .collect()
+    }
+}
+
+pub async fn compare(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    base_branch: &str,
+    head: &str,
+) -> octocrab::Result<Comparison> {
+    let route = format!("repos/{base}/{repo}/compare/{base_branch}...{head}");
+
+    with_timeout!(octocrab.get(&route, None::<&()>))
+}
+
+pub fn get_empty_pr_reason(
+    comparison: &Comparison,
+    base_branch: &str,
+    head: &str,
+) -> Option<String> {
+    if comparison.ahead_by > 0 {
+        return None;
+    }
+
+    let reason = match comparison.status.as_str() {
+        "identical" => {
+            format!("{head} is identical to {base_branch}, there is nothing to open a PR for")
+        }
+        _ if comparison.behind_by > 0 => {
+            format!(
+                "{head} has no commits ahead of {base_branch}, it looks like it was already merged"
+            )
+        }
+        _ => format!("There are no commits between {base_branch} and {head}"),
+    };
+
+    Some(reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comparison(status: &str, ahead_by: u64, behind_by: u64) -> Comparison {
+        Comparison {
+            status: status.to_owned(),
+            ahead_by,
+            behind_by,
+            commits: vec![],
+        }
+    }
+
+    #[test]
+    fn unverified_commits_test() {
+        let comparison: Comparison = serde_json::from_str(
+            r#"{
+                "status": "ahead",
+                "ahead_by": 2,
+                "behind_by": 0,
+                "commits": [
+                    {
+                        "sha": "1a2b3c4d5e",
+                        "commit": {
+                            "message": "feat: signed\n\nbody",
+                            "verification": { "verified": true, "reason": "valid" }
+                        }
+                    },
+                    {
+                        "sha": "5d6e7f8a9b",
+                        "commit": {
+                            "message": "fix: unsigned",
+                            "verification": { "verified": false, "reason": "unsigned" }
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            comparison.unverified_commits(),
+            ["5d6e7f8 fix: unsigned (unsigned)"]
+        );
+    }
+
+    #[test]
+    fn get_empty_pr_reason_test() {
+        assert_eq!(
+            None,
+            get_empty_pr_reason(&comparison("ahead", 2, 0), "next", "fix")
+        );
+        assert_eq!(
+            None,
+            get_empty_pr_reason(&comparison("diverged", 1, 3), "next", "fix")
+        );
+        assert_eq!(
+            Some("fix is identical to next, there is nothing to open a PR for".to_owned()),
+            get_empty_pr_reason(&comparison("identical", 0, 0), "next", "fix")
+        );
+        assert_eq!(
+            Some(
+                "fix has no commits ahead of next, it looks like it was already merged".to_owned()
+            ),
+            get_empty_pr_reason(&comparison("behind", 0, 4), "next", "fix")
+        );
+    }
+}
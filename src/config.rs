@@ -0,0 +1,308 @@
+use colored::Colorize;
+use serde::Deserialize;
+use std::{collections::BTreeMap, env, fs, path::PathBuf, process::exit, sync::OnceLock};
+
+use crate::{
+    checklist::Checklists,
+    flow::{self, Step},
+    forge, git,
+    late_vars::LateVars,
+    network,
+    profiles::{self, Profiles},
+    projects::Project,
+    reviewers::{ReviewerGroups, ReviewerSource},
+    schema,
+    secrets::SecretScan,
+    spelling::SpellCheck,
+    summary_comment::SummaryComment,
+    title_transform::TitleTransform,
+    validation::ValidationRules,
+};
+
+const CONFIG_VAR: &str = "PRMAKER_CONFIG";
+const REPO_CONFIG_FILE: &str = ".prmaker.toml";
+const SUGGESTED_COMMITTERS: usize = 3;
+
+static LOADED: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub base_branch: Option<String>,
+    pub body_generator: Option<String>,
+    pub sync_strategy: SyncStrategy,
+    pub projects: Vec<Project>,
+    pub companions: BTreeMap<String, Vec<PathBuf>>,
+    pub forges: forge::Mapping,
+    pub require_sign_off: bool,
+    pub reviewer_groups: ReviewerGroups,
+    pub reviewer_pool: Vec<String>,
+    pub reviewer_source: ReviewerSource,
+    pub suggested_committers: Option<usize>,
+    pub never_suggest: Vec<String>,
+    pub summary_comment: Option<SummaryComment>,
+    pub title_prefix: Option<String>,
+    pub gitmoji: bool,
+    pub title_transforms: Vec<TitleTransform>,
+    pub issue_url: Option<String>,
+    pub saved_replies: BTreeMap<String, String>,
+    pub inbox: Vec<String>,
+    pub validation: ValidationRules,
+    pub placeholders: Option<Vec<String>>,
+    pub late_vars: LateVars,
+    pub spell_check: Option<SpellCheck>,
+    pub secret_scan: SecretScan,
+    pub large_file_kb: Option<u64>,
+    pub checklists: Checklists,
+    pub steps: Vec<Step>,
+    pub timeout_secs: Option<u64>,
+    #[cfg(feature = "github-app")]
+    pub github_app: Option<crate::github_app::GithubApp>,
+    pub profiles: Profiles,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncStrategy {
+    #[default]
+    Rebase,
+    Merge,
+}
+
+impl Config {
+    pub fn base_branch(&self) -> Option<&str> {
+        self.base_branch
+            .as_deref()
+            .or(profiles::active().and_then(|p| p.base_branch.as_deref()))
+    }
+
+    pub fn suggested_committers(&self) -> usize {
+        self.suggested_committers.unwrap_or(SUGGESTED_COMMITTERS)
+    }
+
+    pub fn steps(&self) -> Vec<Step> {
+        if self.steps.is_empty() {
+            return flow::DEFAULT_STEPS.to_vec();
+        }
+
+        let errors = flow::validate(&self.steps);
+
+        if !errors.is_empty() {
+            println!("{}", "Invalid steps in config file:".red());
+
+            for error in &errors {
+                println!("  {}", error.yellow());
+            }
+
+            exit(1);
+        }
+
+        self.steps.clone()
+    }
+
+    pub fn load() -> &'static Self {
+        LOADED.get_or_init(Config::read)
+    }
+
+    pub fn try_load() -> Result<&'static Self, String> {
+        if let Some(config) = LOADED.get() {
+            return Ok(config);
+        }
+
+        let (config, _) = Config::try_read()?;
+
+        Ok(LOADED.get_or_init(|| config))
+    }
+
+    fn read() -> Self {
+        let (config, diagnostics) = Config::try_read().unwrap_or_else(|err| {
+            let (headline, detail) = err.split_once('\n').unwrap_or((&err, ""));
+            println!("{}", headline.red());
+            println!("{detail}");
+            exit(1);
+        });
+
+        for diagnostic in diagnostics {
+            eprintln!("{}", diagnostic.yellow());
+        }
+
+        config
+    }
+
+    fn try_read() -> Result<(Self, Vec<String>), String> {
+        let Some(path) = find_config_file() else {
+            return Ok((Config::default(), vec![]));
+        };
+
+        let content = fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read config file {}\n{err}", path.display()))?;
+        let config = parse(&content, |var| env::var(var).ok())
+            .map_err(|err| format!("Invalid config file {}\n{err}", path.display()))?;
+        let diagnostics = schema::check(&content)
+            .into_iter()
+            .map(|d| format!("{}:{}: {}", path.display(), d.line, d.message))
+            .collect();
+
+        if let Some(secs) = config.timeout_secs {
+            network::set_timeout(secs);
+        }
+
+        Ok((config, diagnostics))
+    }
+}
+
+fn parse(content: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+    let mut value: toml::Value = toml::from_str(content).map_err(|err| err.to_string())?;
+
+    expand_value(&mut value, &lookup)?;
+
+    value.try_into().map_err(
+        |err: toml::de::Error| match toml::from_str::<Config>(content) {
+            Err(located) if located.message() == err.message() => located.to_string(),
+            _ => err.to_string(),
+        },
+    )
+}
+
+fn expand_value(
+    value: &mut toml::Value,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), String> {
+    match value {
+        toml::Value::String(string) => {
+            *string = unlock(&expand_env(string, lookup)?)?;
+        }
+        toml::Value::Array(values) => {
+            for value in values {
+                expand_value(value, lookup)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (_, value) in table.iter_mut() {
+                expand_value(value, lookup)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn expand_env(string: &str, lookup: &impl Fn(&str) -> Option<String>) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut rest = string;
+
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            expanded.push_str(&rest[..start - 1]);
+            expanded.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+
+        let var = &rest[start + 2..start + end];
+        let var_value =
+            lookup(var).ok_or_else(|| format!("environment variable {var} is not set"))?;
+
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&var_value);
+        rest = &rest[start + end + 1..];
+    }
+
+    expanded.push_str(rest);
+
+    Ok(expanded)
+}
+
+#[cfg(feature = "vault")]
+fn unlock(value: &str) -> Result<String, String> {
+    if !crate::vault::is_encrypted(value) {
+        return Ok(value.to_owned());
+    }
+
+    crate::vault::unlock(value).map_err(|err| format!("couldn't decrypt a value: {err}"))
+}
+
+#[cfg(not(feature = "vault"))]
+fn unlock(value: &str) -> Result<String, String> {
+    if value.starts_with("enc:") {
+        return Err("encrypted values need prmaker built with the `vault` feature".to_owned());
+    }
+
+    Ok(value.to_owned())
+}
+
+pub fn find_config_file() -> Option<PathBuf> {
+    if let Ok(path) = env::var(CONFIG_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    let repo_config = PathBuf::from(git::toplevel()).join(REPO_CONFIG_FILE);
+
+    if repo_config.is_file() {
+        return Some(repo_config);
+    }
+
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .ok()?;
+
+    let user_config = config_dir.join("prmaker").join("config.toml");
+
+    user_config.is_file().then_some(user_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config_test() {
+        let config: Config = toml::from_str(r#"body_generator = "llm -m local""#).unwrap();
+
+        assert_eq!(Some("llm -m local".to_owned()), config.body_generator);
+        assert!(toml::from_str::<Config>("")
+            .unwrap()
+            .body_generator
+            .is_none());
+    }
+
+    #[test]
+    fn type_error_test() {
+        let err = parse("base_branch = \"main\"\ngitmoji = \"yes\"\n", |_| None).unwrap_err();
+
+        assert!(err.contains("line 2, column 11"));
+        assert!(err.contains("expected a boolean"));
+    }
+
+    #[test]
+    fn expand_env_test() {
+        let lookup = |var: &str| match var {
+            "TEAM" => Some("backend".to_owned()),
+            "STRATEGY" => Some("merge".to_owned()),
+            _ => None,
+        };
+        let config = parse(
+            r#"
+            body_generator = "gen --team ${TEAM}"
+            sync_strategy = "${STRATEGY}"
+            inbox = ["team:${TEAM}", "$${TEAM}"]
+            "#,
+            lookup,
+        )
+        .unwrap();
+
+        assert_eq!(Some("gen --team backend".to_owned()), config.body_generator);
+        assert_eq!(SyncStrategy::Merge, config.sync_strategy);
+        assert_eq!(config.inbox, ["team:backend", "${TEAM}"]);
+        assert_eq!(
+            Err("environment variable MISSING is not set".to_owned()),
+            expand_env("${MISSING}", &lookup)
+        );
+    }
+}
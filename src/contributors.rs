@@ -0,0 +1,141 @@
+use regex::Regex;
+use std::{fmt::Display, sync::LazyLock};
+
+use crate::git;
+
+static CO_AUTHOR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?mi)^co-authored-by:\s*(.+?)\s*<([^>]+)>\s*$").unwrap());
+static NOREPLY_EMAIL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:\d+\+)?([\w-]+)@users\.noreply\.github\.com$").unwrap());
+const AUTHOR_SEPARATOR: &str = "--prmaker-author--";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contributor {
+    pub name: String,
+    pub email: String,
+    pub co_author: bool,
+}
+
+impl Contributor {
+    pub fn login(&self) -> Option<String> {
+        NOREPLY_EMAIL_REGEX
+            .captures(&self.email)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().to_owned())
+    }
+}
+
+impl Display for Contributor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.login() {
+            Some(login) => write!(f, "{} (@{})", self.name, login),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+pub fn get_contributors(base_branch: &str) -> Vec<Contributor> {
+    let range = format!("origin/{base_branch}..{}", git::head());
+    let format = format!("--pretty=format:{AUTHOR_SEPARATOR}%an <%ae>%n%B");
+
+    match git::try_git(&["log", &format, &range]) {
+        Ok(log) => parse_contributors(&log),
+        Err(_) => vec![],
+    }
+}
+
+pub fn build_contributors_section(contributors: &[Contributor]) -> Option<String> {
+    if contributors.len() < 2 {
+        return None;
+    }
+
+    let entries: Vec<String> = contributors.iter().map(|c| format!("- {c}")).collect();
+
+    Some(format!("### Contributors\n\n{}\n", entries.join("\n")))
+}
+
+fn parse_contributors(log: &str) -> Vec<Contributor> {
+    let mut contributors: Vec<Contributor> = vec![];
+
+    let mut add = |name: &str, email: &str, co_author: bool| {
+        let known = contributors
+            .iter_mut()
+            .find(|c| c.email.eq_ignore_ascii_case(email));
+
+        match known {
+            Some(contributor) => contributor.co_author &= co_author,
+            None => contributors.push(Contributor {
+                name: name.to_owned(),
+                email: email.to_owned(),
+                co_author,
+            }),
+        }
+    };
+
+    for commit in log.split(AUTHOR_SEPARATOR).filter(|c| !c.trim().is_empty()) {
+        let (author, message) = commit.split_once('\n').unwrap_or((commit, ""));
+
+        if let Some((name, email)) = author.trim().rsplit_once(" <") {
+            add(name, email.trim_end_matches('>'), false);
+        }
+
+        for captures in CO_AUTHOR_REGEX.captures_iter(message) {
+            add(&captures[1], &captures[2], true);
+        }
+    }
+
+    contributors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_contributors_test() {
+        let log = format!(
+            "\
+{AUTHOR_SEPARATOR}Jane Doe <jane@example.com>
+fix: something
+
+Co-authored-by: John Roe <123+jroe@users.noreply.github.com>
+{AUTHOR_SEPARATOR}Jane Doe <jane@example.com>
+feat: other thing
+{AUTHOR_SEPARATOR}Alex Poe <alex@example.com>
+chore: bump
+
+co-authored-by: Jane Doe <JANE@example.com>"
+        );
+
+        let contributors = parse_contributors(&log);
+        let displayed: Vec<String> = contributors.iter().map(|c| c.to_string()).collect();
+
+        assert_eq!(displayed, ["Jane Doe", "John Roe (@jroe)", "Alex Poe"]);
+        assert_eq!(
+            contributors.iter().map(|c| c.co_author).collect::<Vec<_>>(),
+            [false, true, false]
+        );
+    }
+
+    #[test]
+    fn build_contributors_section_test() {
+        let jane = Contributor {
+            name: "Jane Doe".to_owned(),
+            email: "jane@example.com".to_owned(),
+            co_author: false,
+        };
+        let john = Contributor {
+            name: "John Roe".to_owned(),
+            email: "jroe@users.noreply.github.com".to_owned(),
+            co_author: true,
+        };
+
+        let contributors = [jane, john];
+
+        assert_eq!(None, build_contributors_section(&contributors[..1]));
+        assert_eq!(
+            Some("### Contributors\n\n- Jane Doe\n- John Roe (@jroe)\n".to_owned()),
+            build_contributors_section(&contributors)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use colored::Colorize;
+use regex::Regex;
+use std::{process::exit, sync::LazyLock};
+
+use crate::{confirm_or, git};
+
+static SIGN_OFF_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?mi)^signed-off-by:\s*\S").unwrap());
+const COMMIT_SEPARATOR: char = '\u{1e}';
+const SUBJECT_SEPARATOR: char = '\u{1f}';
+
+pub fn ensure_signed_off(base_branch: &str, branch: &str) {
+    let range = format!("origin/{base_branch}..{}", git::head());
+    let format = format!("--pretty=format:%h %s{SUBJECT_SEPARATOR}%B{COMMIT_SEPARATOR}");
+
+    let unsigned = match git::try_git(&["log", &format, &range]) {
+        Ok(log) => get_unsigned_commits(&log),
+        Err(_) => return,
+    };
+
+    if unsigned.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{}",
+        "These commits are missing a Signed-off-by trailer:".red()
+    );
+
+    for commit in &unsigned {
+        println!("  {}", commit.yellow());
+    }
+
+    if git::head() == "HEAD"
+        && confirm_or(
+            "Sign them off? This rebases and force-pushes the branch",
+            false,
+        )
+    {
+        let upstream = format!("origin/{base_branch}");
+        let fork_point = git::try_git(&["merge-base", "HEAD", &upstream]).unwrap_or_else(|err| {
+            println!(
+                "{}",
+                format!("Couldn't find where the branch forked from {upstream}").red()
+            );
+            println!("{err}");
+            exit(1);
+        });
+
+        git::run_or_exit(&["rebase", "--signoff", &fork_point]);
+        git::run_or_exit(&["push", "--force-with-lease", "origin", branch]);
+
+        println!("\n{}", "Commits signed off successfully".green());
+    } else if !confirm_or("The DCO check will fail, continue anyway?", false) {
+        println!("\nClosing...");
+        exit(0);
+    }
+}
+
+pub fn sign_off_note() -> Option<String> {
+    let name = git::try_git(&["config", "user.name"]).ok()?;
+    let email = git::try_git(&["config", "user.email"]).ok()?;
+
+    Some(format!("Signed-off-by: {name} <{email}>"))
+}
+
+fn get_unsigned_commits(log: &str) -> Vec<String> {
+    log.split(COMMIT_SEPARATOR)
+        .filter_map(|commit| commit.trim().split_once(SUBJECT_SEPARATOR))
+        .filter(|(_, message)| !SIGN_OFF_REGEX.is_match(message))
+        .map(|(subject, _)| subject.to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_unsigned_commits_test() {
+        let log = format!(
+            "\
+1a2b3c4 fix: signed{SUBJECT_SEPARATOR}fix: signed
+
+Signed-off-by: Jane Doe <jane@example.com>{COMMIT_SEPARATOR}
+5d6e7f8 feat: unsigned{SUBJECT_SEPARATOR}feat: unsigned
+
+Mentions signed-off-by in the middle of a line{COMMIT_SEPARATOR}"
+        );
+
+        assert_eq!(get_unsigned_commits(&log), ["5d6e7f8 feat: unsigned"]);
+    }
+}
@@ -0,0 +1,80 @@
+use colored::Colorize;
+
+#[derive(Debug, PartialEq)]
+pub enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+pub fn lines<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = vec![];
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+
+    lines
+}
+
+pub fn render(old: &str, new: &str) -> String {
+    lines(old, new)
+        .into_iter()
+        .map(|line| match line {
+            Line::Same(line) => format!("  {line}"),
+            Line::Removed(line) => format!("- {line}").red().to_string(),
+            Line::Added(line) => format!("+ {line}").green().to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_test() {
+        assert_eq!(
+            lines(
+                "## What\n{body}\n## Issue",
+                "## What\nAdds login\n## Issue\nABC-1"
+            ),
+            [
+                Line::Same("## What"),
+                Line::Removed("{body}"),
+                Line::Added("Adds login"),
+                Line::Same("## Issue"),
+                Line::Added("ABC-1"),
+            ]
+        );
+        assert!(lines("a\nb", "a\nb")
+            .iter()
+            .all(|l| matches!(l, Line::Same(_))));
+    }
+}
@@ -0,0 +1,79 @@
+use colored::Colorize;
+use futures_util::StreamExt;
+use octocrab::{models::pulls::PullRequest, Octocrab};
+use std::{collections::BTreeSet, pin::pin};
+
+use crate::{confirm_or, conventional, gitmoji, paginate, pr};
+
+const MIN_SIMILARITY: f64 = 0.6;
+
+pub async fn confirm_no_duplicate(octocrab: &Octocrab, pr: &pr::PR) -> bool {
+    let similar = find_similar(octocrab, pr).await;
+
+    if similar.is_empty() {
+        return true;
+    }
+
+    println!("\n{}", "A similar PR already exists:".yellow());
+
+    for pull in &similar {
+        println!("  {}", pull.yellow());
+    }
+
+    confirm_or("Create it anyway?", false)
+}
+
+pub async fn find_similar(octocrab: &Octocrab, pr: &pr::PR) -> Vec<String> {
+    let route = format!("repos/{}/{}/pulls", pr.base, pr.repo);
+    let mut pulls = pin!(paginate::paginate::<PullRequest>(octocrab, route));
+    let mut similar = vec![];
+
+    while let Some(Ok(pull)) = pulls.next().await {
+        let Some(title) = pull.title.as_deref() else {
+            continue;
+        };
+
+        if pull.head.ref_field != pr.branch && similarity(title, &pr.title) >= MIN_SIMILARITY {
+            similar.push(format!("#{} {title}", pull.number));
+        }
+    }
+
+    similar
+}
+
+fn tokens(title: &str) -> BTreeSet<String> {
+    let (_, subject) = conventional::split_prefix(gitmoji::strip(title));
+
+    subject
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (tokens(a), tokens(b));
+    let union = a.union(&b).count();
+
+    if union == 0 {
+        return 0.0;
+    }
+
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similarity_test() {
+        assert_eq!(
+            1.0,
+            similarity("feat(web): Add login page", "✨ fix: add login page")
+        );
+        assert!(similarity("Add the login page", "add login page") >= MIN_SIMILARITY);
+        assert!(similarity("Add login page", "Fix checkout crash") < MIN_SIMILARITY);
+        assert_eq!(0.0, similarity("", "feat: "));
+    }
+}
@@ -0,0 +1,28 @@
+use std::{env, fs, process::Command};
+
+pub fn edit(initial: &str) -> Result<String, String> {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_owned());
+
+    let path = env::temp_dir().join(format!("prmaker-{}.md", std::process::id()));
+
+    fs::write(&path, initial).map_err(|err| err.to_string())?;
+
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$1\""))
+        .arg("sh")
+        .arg(&path)
+        .status()
+        .map_err(|err| format!("failed to run `{editor}`: {err}"))?;
+
+    let content = fs::read_to_string(&path).map_err(|err| err.to_string());
+    let _ = fs::remove_file(&path);
+
+    if status.success() {
+        content.map(|c| c.trim().to_owned())
+    } else {
+        Err(format!("`{editor}` exited with {status}"))
+    }
+}
@@ -0,0 +1,143 @@
+use serde::Deserialize;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Base,
+    Title,
+    Issue,
+    Body,
+    Related,
+    Review,
+    Create,
+    Reviewers,
+    Labels,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Step::Base => "base",
+            Step::Title => "title",
+            Step::Issue => "issue",
+            Step::Body => "body",
+            Step::Related => "related",
+            Step::Review => "review",
+            Step::Create => "create",
+            Step::Reviewers => "reviewers",
+            Step::Labels => "labels",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+pub const DEFAULT_STEPS: [Step; 8] = [
+    Step::Base,
+    Step::Title,
+    Step::Issue,
+    Step::Body,
+    Step::Review,
+    Step::Create,
+    Step::Reviewers,
+    Step::Labels,
+];
+
+pub fn validate(steps: &[Step]) -> Vec<String> {
+    let mut errors = vec![];
+
+    for (index, step) in steps.iter().enumerate() {
+        if steps[..index].contains(step) {
+            errors.push(format!("`{step}` is listed more than once"));
+        }
+    }
+
+    if steps.first() != Some(&Step::Base) {
+        errors.push("`base` must be the first step".to_owned());
+    }
+
+    let Some(create) = steps.iter().position(|s| *s == Step::Create) else {
+        errors.push("`create` is required".to_owned());
+        return errors;
+    };
+
+    for step in [
+        Step::Title,
+        Step::Issue,
+        Step::Body,
+        Step::Related,
+        Step::Review,
+    ] {
+        if steps[create..].contains(&step) {
+            errors.push(format!("`{step}` must come before `create`"));
+        }
+    }
+
+    errors
+}
+
+pub fn reviewed_before_create(steps: &[Step]) -> bool {
+    let Some(create) = steps.iter().position(|s| *s == Step::Create) else {
+        return false;
+    };
+    let Some(review) = steps[..create].iter().rposition(|s| *s == Step::Review) else {
+        return false;
+    };
+
+    !steps[review..create]
+        .iter()
+        .any(|s| matches!(s, Step::Title | Step::Issue | Step::Body | Step::Related))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_test() {
+        assert!(validate(&DEFAULT_STEPS).is_empty());
+        assert!(validate(&[Step::Base, Step::Reviewers, Step::Title, Step::Create]).is_empty());
+
+        assert_eq!(
+            validate(&[
+                Step::Title,
+                Step::Base,
+                Step::Create,
+                Step::Body,
+                Step::Title
+            ]),
+            [
+                "`title` is listed more than once",
+                "`base` must be the first step",
+                "`title` must come before `create`",
+                "`body` must come before `create`",
+            ]
+        );
+        assert_eq!(validate(&[Step::Base]), ["`create` is required"]);
+    }
+
+    #[test]
+    fn reviewed_before_create_test() {
+        assert!(reviewed_before_create(&DEFAULT_STEPS));
+        assert!(reviewed_before_create(&[
+            Step::Base,
+            Step::Title,
+            Step::Review,
+            Step::Reviewers,
+            Step::Create
+        ]));
+        assert!(!reviewed_before_create(&[
+            Step::Base,
+            Step::Review,
+            Step::Title,
+            Step::Body,
+            Step::Create
+        ]));
+        assert!(!reviewed_before_create(&[
+            Step::Base,
+            Step::Title,
+            Step::Create
+        ]));
+    }
+}
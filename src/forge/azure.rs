@@ -0,0 +1,361 @@
+use regex::Regex;
+use reqwest::{Client, Method, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{env, sync::LazyLock};
+
+use super::{credential, Capabilities, Created, Forge, NewPullRequest};
+use crate::network;
+
+const API_URL: &str = "https://dev.azure.com/";
+const IDENTITIES_URL: &str = "https://vssps.dev.azure.com/";
+const API_URL_VAR: &str = "AZURE_DEVOPS_API_URL";
+const PAT_VAR: &str = "AZURE_DEVOPS_PAT";
+const API_VERSION: &str = "7.1";
+
+static REMOTE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:https://(?:[^@/]+@)?dev\.azure\.com/([^/]+)/([^/]+)/_git/([^/]+?)|git@ssh\.dev\.azure\.com:v3/([^/]+)/([^/]+)/([^/]+?)|https://(?:[^@/]+@)?([^./]+)\.visualstudio\.com/(?:DefaultCollection/)?([^/]+)/_git/([^/]+?))(?:\.git)?/?$").unwrap()
+});
+
+static WORK_ITEM_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bAB#(\d+)\b").unwrap());
+
+pub struct AzureDevOps {
+    client: Client,
+    api_url: String,
+    identities_url: String,
+    pat: String,
+    org: String,
+    project: String,
+    repo: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Remote {
+    pub org: String,
+    pub project: String,
+    pub repo: String,
+}
+
+#[derive(Deserialize)]
+struct List<T> {
+    value: Vec<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repository {
+    default_branch: String,
+}
+
+#[derive(Deserialize)]
+struct Identity {
+    id: String,
+    #[serde(default)]
+    properties: IdentityProperties,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct IdentityProperties {
+    account: Option<Property>,
+    mail: Option<Property>,
+}
+
+#[derive(Deserialize)]
+struct Property {
+    #[serde(rename = "$value")]
+    value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Reviewer {
+    id: String,
+    is_required: bool,
+}
+
+#[derive(Serialize)]
+struct WorkItemRef {
+    id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestRequest<'a> {
+    source_ref_name: String,
+    target_ref_name: String,
+    title: &'a str,
+    description: &'a str,
+    reviewers: Vec<Reviewer>,
+    work_item_refs: Vec<WorkItemRef>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequest {
+    pull_request_id: u64,
+    repository: PullRequestRepository,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestRepository {
+    web_url: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+pub fn parse_remote(remote_url: &str) -> Option<Remote> {
+    let captures = REMOTE_REGEX.captures(remote_url.trim())?;
+    let mut parts = captures.iter().skip(1).flatten().map(|m| m.as_str());
+
+    Some(Remote {
+        org: parts.next()?.to_owned(),
+        project: parts.next()?.to_owned(),
+        repo: parts.next()?.to_owned(),
+    })
+}
+
+fn work_items(texts: &[&str]) -> Vec<String> {
+    let mut ids: Vec<String> = vec![];
+
+    for text in texts {
+        for captures in WORK_ITEM_REGEX.captures_iter(text) {
+            if !ids.iter().any(|id| *id == captures[1]) {
+                ids.push(captures[1].to_owned());
+            }
+        }
+    }
+
+    ids
+}
+
+impl AzureDevOps {
+    pub fn from_env(remote: Remote) -> Result<Self, String> {
+        let (api_url, identities_url) = match env::var(API_URL_VAR) {
+            Ok(url) => {
+                let url = format!("{}/", url.trim_end_matches('/'));
+                (url.clone(), url)
+            }
+            Err(_) => (API_URL.to_owned(), IDENTITIES_URL.to_owned()),
+        };
+
+        Ok(AzureDevOps {
+            client: Client::new(),
+            api_url,
+            identities_url,
+            pat: credential(PAT_VAR)?,
+            org: remote.org,
+            project: remote.project,
+            repo: remote.repo,
+        })
+    }
+
+    fn repo_url(&self, route: &str) -> String {
+        format!(
+            "{}{}/{}/_apis/git/repositories/{}{route}?api-version={API_VERSION}",
+            self.api_url, self.org, self.project, self.repo
+        )
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .basic_auth("", Some(&self.pat))
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, String> {
+        let resp = network::send(&self.client, request).await?;
+        let status = resp.status();
+        let text = resp.text().await.map_err(|err| err.to_string())?;
+
+        if !status.is_success() {
+            return Err(error_message(&text).unwrap_or(format!("Azure DevOps answered {status}")));
+        }
+
+        serde_json::from_str(&text).map_err(|err| err.to_string())
+    }
+
+    async fn resolve_reviewers(&self, names: &[String]) -> Result<Vec<Reviewer>, String> {
+        let mut reviewers = vec![];
+
+        for name in names {
+            let url = format!("{}{}/_apis/identities", self.identities_url, self.org);
+            let request = self.request(Method::GET, &url).query(&[
+                ("searchFilter", "General"),
+                ("filterValue", name),
+                ("api-version", API_VERSION),
+            ]);
+            let identities: List<Identity> = self.send(request).await?;
+
+            reviewers.push(Reviewer {
+                id: pick_identity(name, identities.value)?,
+                is_required: true,
+            });
+        }
+
+        Ok(reviewers)
+    }
+}
+
+impl Forge for AzureDevOps {
+    const CAPABILITIES: Capabilities = Capabilities {
+        drafts: false,
+        teams: false,
+        auto_merge: false,
+        projects: false,
+    };
+
+    async fn default_branch(&self) -> Result<String, String> {
+        let repository: Repository = self
+            .send(self.request(Method::GET, &self.repo_url("")))
+            .await?;
+
+        Ok(repository
+            .default_branch
+            .trim_start_matches("refs/heads/")
+            .to_owned())
+    }
+
+    async fn default_reviewers(&self) -> Result<Vec<String>, String> {
+        Ok(vec![])
+    }
+
+    async fn create_pull_request(&self, pr: &NewPullRequest) -> Result<Created, String> {
+        let body = PullRequestRequest {
+            source_ref_name: format!("refs/heads/{}", pr.head),
+            target_ref_name: format!("refs/heads/{}", pr.base),
+            title: &pr.title,
+            description: &pr.body,
+            reviewers: self.resolve_reviewers(&pr.reviewers).await?,
+            work_item_refs: work_items(&[&pr.title, &pr.body])
+                .into_iter()
+                .map(|id| WorkItemRef { id })
+                .collect(),
+        };
+
+        let url = self.repo_url("/pullrequests");
+        let created: PullRequest = self
+            .send(self.request(Method::POST, &url).json(&body))
+            .await?;
+
+        Ok(Created {
+            number: created.pull_request_id,
+            link: format!(
+                "{}/pullrequest/{}",
+                created.repository.web_url, created.pull_request_id
+            ),
+        })
+    }
+}
+
+fn pick_identity(name: &str, identities: Vec<Identity>) -> Result<String, String> {
+    let matches = |property: &Option<Property>| {
+        property
+            .as_ref()
+            .is_some_and(|p| p.value.eq_ignore_ascii_case(name))
+    };
+
+    if let Some(identity) = identities
+        .iter()
+        .find(|i| matches(&i.properties.account) || matches(&i.properties.mail))
+    {
+        return Ok(identity.id.clone());
+    }
+
+    match <[Identity; 1]>::try_from(identities) {
+        Ok([identity]) => Ok(identity.id),
+        Err(identities) if identities.is_empty() => Err(format!("unknown reviewer {name}")),
+        Err(_) => Err(format!(
+            "reviewer {name} matches several users, use their email instead"
+        )),
+    }
+}
+
+fn error_message(body: &str) -> Option<String> {
+    serde_json::from_str::<ErrorResponse>(body)
+        .ok()
+        .map(|e| e.message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_remote_test() {
+        let remote = Remote {
+            org: "acme".to_owned(),
+            project: "Platform".to_owned(),
+            repo: "api".to_owned(),
+        };
+
+        assert_eq!(
+            Some(&remote),
+            parse_remote("https://acme@dev.azure.com/acme/Platform/_git/api").as_ref()
+        );
+        assert_eq!(
+            Some(&remote),
+            parse_remote("git@ssh.dev.azure.com:v3/acme/Platform/api").as_ref()
+        );
+        assert_eq!(
+            Some(&remote),
+            parse_remote("https://acme.visualstudio.com/Platform/_git/api").as_ref()
+        );
+        assert_eq!(None, parse_remote("git@github.com:acme/api.git"));
+    }
+
+    #[test]
+    fn work_items_test() {
+        assert_eq!(
+            vec!["123", "45"],
+            work_items(&["Fix login AB#123", "Closes AB#45 and AB#123, not XAB#9"])
+        );
+    }
+
+    #[test]
+    fn created_pull_request_test() {
+        let created: PullRequest = serde_json::from_str(
+            r#"{"pullRequestId": 12, "repository": {"id": "1", "webUrl": "https://dev.azure.com/acme/Platform/_git/api"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(12, created.pull_request_id);
+        assert_eq!(
+            "https://dev.azure.com/acme/Platform/_git/api",
+            created.repository.web_url
+        );
+    }
+
+    #[test]
+    fn pick_identity_test() {
+        let identities = || -> Vec<Identity> {
+            serde_json::from_str(
+                r#"[
+                    {"id": "1", "properties": {"Account": {"$value": "jane.doe@acme.com"}, "Mail": {"$value": "jane.doe@acme.com"}}},
+                    {"id": "2", "properties": {"Account": {"$value": "jane@acme.com"}, "Mail": {"$value": "jane@acme.com"}}}
+                ]"#,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            Ok("2".to_owned()),
+            pick_identity("Jane@acme.com", identities())
+        );
+        assert_eq!(
+            Err("reviewer jane matches several users, use their email instead".to_owned()),
+            pick_identity("jane", identities())
+        );
+        assert_eq!(
+            Ok("1".to_owned()),
+            pick_identity("jane", identities().into_iter().take(1).collect())
+        );
+        assert_eq!(
+            Err("unknown reviewer jane".to_owned()),
+            pick_identity("jane", vec![])
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use reqwest::{Client, Method, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::env;
+
+use super::{credential, Capabilities, Created, Forge, NewPullRequest};
+use crate::network;
+
+const API_URL: &str = "https://api.bitbucket.org/2.0/";
+const API_URL_VAR: &str = "BITBUCKET_API_URL";
+const USER_VAR: &str = "BITBUCKET_USER";
+const APP_PASSWORD_VAR: &str = "BITBUCKET_APP_PASSWORD";
+
+pub struct Bitbucket {
+    client: Client,
+    api_url: String,
+    user: String,
+    app_password: String,
+    workspace: String,
+    repo: String,
+}
+
+#[derive(Deserialize)]
+struct Page<T> {
+    values: Vec<T>,
+    next: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Repository {
+    mainbranch: Branch,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Branch {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Account {
+    uuid: String,
+    nickname: String,
+}
+
+#[derive(Deserialize)]
+struct Membership {
+    user: Account,
+}
+
+#[derive(Serialize)]
+struct Endpoint {
+    branch: Branch,
+}
+
+#[derive(Serialize)]
+struct Reviewer {
+    uuid: String,
+}
+
+#[derive(Serialize)]
+struct PullRequestRequest<'a> {
+    title: &'a str,
+    description: &'a str,
+    source: Endpoint,
+    destination: Endpoint,
+    reviewers: Vec<Reviewer>,
+}
+
+#[derive(Deserialize)]
+struct PullRequest {
+    id: u64,
+    links: Links,
+}
+
+#[derive(Deserialize)]
+struct Links {
+    html: Link,
+}
+
+#[derive(Deserialize)]
+struct Link {
+    href: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct ErrorDetail {
+    message: String,
+}
+
+impl Bitbucket {
+    pub fn from_env(workspace: String, repo: String) -> Result<Self, String> {
+        let api_url = env::var(API_URL_VAR).unwrap_or_else(|_| API_URL.to_owned());
+
+        Ok(Bitbucket {
+            client: Client::new(),
+            api_url: format!("{}/", api_url.trim_end_matches('/')),
+            user: credential(USER_VAR)?,
+            app_password: credential(APP_PASSWORD_VAR)?,
+            workspace,
+            repo,
+        })
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .basic_auth(&self.user, Some(&self.app_password))
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, String> {
+        let resp = network::send(&self.client, request).await?;
+        let status = resp.status();
+        let text = resp.text().await.map_err(|err| err.to_string())?;
+
+        if !status.is_success() {
+            return Err(error_message(&text).unwrap_or(format!("Bitbucket answered {status}")));
+        }
+
+        serde_json::from_str(&text).map_err(|err| err.to_string())
+    }
+
+    async fn get_all<T: DeserializeOwned>(&self, route: &str) -> Result<Vec<T>, String> {
+        let mut url = Some(format!("{}{route}", self.api_url));
+        let mut values = vec![];
+
+        while let Some(next) = url {
+            let page: Page<T> = self.send(self.request(Method::GET, &next)).await?;
+
+            values.extend(page.values);
+            url = page.next;
+        }
+
+        Ok(values)
+    }
+
+    async fn resolve_reviewers(&self, nicknames: &[String]) -> Result<Vec<Reviewer>, String> {
+        if nicknames.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let route = format!("workspaces/{}/members", self.workspace);
+        let members: Vec<Membership> = self.get_all(&route).await?;
+
+        nicknames
+            .iter()
+            .map(|nickname| {
+                members
+                    .iter()
+                    .find(|m| m.user.nickname == *nickname || m.user.uuid == *nickname)
+                    .map(|m| Reviewer {
+                        uuid: m.user.uuid.clone(),
+                    })
+                    .ok_or_else(|| format!("unknown reviewer {nickname}"))
+            })
+            .collect()
+    }
+}
+
+impl Forge for Bitbucket {
+    const CAPABILITIES: Capabilities = Capabilities {
+        drafts: false,
+        teams: false,
+        auto_merge: false,
+        projects: false,
+    };
+
+    async fn default_branch(&self) -> Result<String, String> {
+        let url = format!(
+            "{}repositories/{}/{}",
+            self.api_url, self.workspace, self.repo
+        );
+        let repository: Repository = self.send(self.request(Method::GET, &url)).await?;
+
+        Ok(repository.mainbranch.name)
+    }
+
+    async fn default_reviewers(&self) -> Result<Vec<String>, String> {
+        let route = format!(
+            "repositories/{}/{}/default-reviewers",
+            self.workspace, self.repo
+        );
+        let accounts: Vec<Account> = self.get_all(&route).await?;
+
+        Ok(accounts.into_iter().map(|a| a.nickname).collect())
+    }
+
+    async fn create_pull_request(&self, pr: &NewPullRequest) -> Result<Created, String> {
+        let body = PullRequestRequest {
+            title: &pr.title,
+            description: &pr.body,
+            source: Endpoint {
+                branch: Branch {
+                    name: pr.head.clone(),
+                },
+            },
+            destination: Endpoint {
+                branch: Branch {
+                    name: pr.base.clone(),
+                },
+            },
+            reviewers: self.resolve_reviewers(&pr.reviewers).await?,
+        };
+
+        let url = format!(
+            "{}repositories/{}/{}/pullrequests",
+            self.api_url, self.workspace, self.repo
+        );
+        let created: PullRequest = self
+            .send(self.request(Method::POST, &url).json(&body))
+            .await?;
+
+        Ok(Created {
+            number: created.id,
+            link: created.links.html.href,
+        })
+    }
+}
+
+fn error_message(body: &str) -> Option<String> {
+    serde_json::from_str::<ErrorResponse>(body)
+        .ok()
+        .map(|e| e.error.message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_message_test() {
+        assert_eq!(
+            Some("There are no changes to be pulled".to_owned()),
+            error_message(
+                r#"{"type": "error", "error": {"message": "There are no changes to be pulled"}}"#
+            )
+        );
+        assert_eq!(None, error_message("<html>Bad gateway</html>"));
+    }
+}
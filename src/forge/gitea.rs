@@ -0,0 +1,154 @@
+use reqwest::{Client, Method, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::env;
+
+use super::{credential, Capabilities, Created, Forge, NewPullRequest};
+use crate::network;
+
+const API_URL_VAR: &str = "GITEA_API_URL";
+const TOKEN_VAR: &str = "GITEA_TOKEN";
+
+pub struct Gitea {
+    client: Client,
+    api_url: String,
+    token: String,
+    owner: String,
+    repo: String,
+}
+
+#[derive(Deserialize)]
+struct Repository {
+    default_branch: String,
+}
+
+#[derive(Serialize)]
+struct PullRequestRequest<'a> {
+    title: &'a str,
+    body: &'a str,
+    head: &'a str,
+    base: &'a str,
+}
+
+#[derive(Deserialize)]
+struct PullRequest {
+    number: u64,
+    html_url: String,
+}
+
+#[derive(Serialize)]
+struct ReviewRequest {
+    reviewers: Vec<String>,
+    team_reviewers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+impl Gitea {
+    pub fn from_env(host: &str, owner: String, repo: String) -> Result<Self, String> {
+        let api_url = env::var(API_URL_VAR).unwrap_or_else(|_| format!("https://{host}/api/v1/"));
+
+        Ok(Gitea {
+            client: Client::new(),
+            api_url: format!("{}/", api_url.trim_end_matches('/')),
+            token: credential(TOKEN_VAR)?,
+            owner,
+            repo,
+        })
+    }
+
+    fn repo_url(&self, route: &str) -> String {
+        format!("{}repos/{}/{}{route}", self.api_url, self.owner, self.repo)
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .header("Authorization", format!("token {}", self.token))
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, String> {
+        let resp = network::send(&self.client, request).await?;
+        let status = resp.status();
+        let text = resp.text().await.map_err(|err| err.to_string())?;
+
+        if !status.is_success() {
+            return Err(error_message(&text).unwrap_or(format!("Gitea answered {status}")));
+        }
+
+        serde_json::from_str(&text).map_err(|err| err.to_string())
+    }
+}
+
+impl Forge for Gitea {
+    const CAPABILITIES: Capabilities = Capabilities {
+        drafts: false,
+        teams: true,
+        auto_merge: false,
+        projects: false,
+    };
+
+    async fn default_branch(&self) -> Result<String, String> {
+        let repository: Repository = self
+            .send(self.request(Method::GET, &self.repo_url("")))
+            .await?;
+
+        Ok(repository.default_branch)
+    }
+
+    async fn default_reviewers(&self) -> Result<Vec<String>, String> {
+        Ok(vec![])
+    }
+
+    async fn create_pull_request(&self, pr: &NewPullRequest) -> Result<Created, String> {
+        let body = PullRequestRequest {
+            title: &pr.title,
+            body: &pr.body,
+            head: &pr.head,
+            base: &pr.base,
+        };
+
+        let created: PullRequest = self
+            .send(
+                self.request(Method::POST, &self.repo_url("/pulls"))
+                    .json(&body),
+            )
+            .await?;
+
+        if !pr.reviewers.is_empty() {
+            let url = self.repo_url(&format!("/pulls/{}/requested_reviewers", created.number));
+            let (teams, users): (Vec<&String>, Vec<&String>) =
+                pr.reviewers.iter().partition(|r| r.contains('/'));
+            let request = ReviewRequest {
+                reviewers: users.into_iter().cloned().collect(),
+                team_reviewers: teams
+                    .into_iter()
+                    .filter_map(|t| t.split_once('/'))
+                    .map(|(_, slug)| slug.to_owned())
+                    .collect(),
+            };
+
+            self.send::<serde_json::Value>(self.request(Method::POST, &url).json(&request))
+                .await
+                .map_err(|err| {
+                    format!(
+                        "PR {} was created but requesting reviewers failed: {err}",
+                        created.html_url
+                    )
+                })?;
+        }
+
+        Ok(Created {
+            number: created.number,
+            link: created.html_url,
+        })
+    }
+}
+
+fn error_message(body: &str) -> Option<String> {
+    serde_json::from_str::<ErrorResponse>(body)
+        .ok()
+        .map(|e| e.message)
+}
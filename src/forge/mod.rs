@@ -0,0 +1,421 @@
+mod azure;
+mod bitbucket;
+mod gitea;
+
+use colored::Colorize;
+use serde::Deserialize;
+use std::{collections::BTreeMap, env, fmt, process::exit};
+
+use crate::{
+    cli::CreateArgs, config::Config, git, issues, network, pr, proceed_question, profiles,
+};
+
+pub use azure::AzureDevOps;
+pub use bitbucket::Bitbucket;
+pub use gitea::Gitea;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum Host {
+    #[serde(rename = "github")]
+    GitHub,
+    #[serde(rename = "gitea")]
+    Gitea,
+    #[serde(rename = "bitbucket")]
+    Bitbucket,
+    #[serde(rename = "azure-devops")]
+    AzureDevOps,
+}
+
+pub type Mapping = BTreeMap<String, Host>;
+
+pub struct NewPullRequest {
+    pub title: String,
+    pub body: String,
+    pub head: String,
+    pub base: String,
+    pub reviewers: Vec<String>,
+}
+
+pub struct Created {
+    pub number: u64,
+    pub link: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capabilities {
+    pub drafts: bool,
+    pub teams: bool,
+    pub auto_merge: bool,
+    pub projects: bool,
+}
+
+impl Capabilities {
+    pub fn missing(&self) -> Vec<&'static str> {
+        [
+            (self.drafts, "drafts"),
+            (self.teams, "team reviewers"),
+            (self.auto_merge, "auto-merge"),
+            (self.projects, "projects"),
+        ]
+        .into_iter()
+        .filter(|(supported, _)| !supported)
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Feature {
+    Drafts,
+    AutoMerge,
+    GitHubApi,
+}
+
+pub trait Forge {
+    const CAPABILITIES: Capabilities;
+
+    async fn default_branch(&self) -> Result<String, String>;
+    async fn default_reviewers(&self) -> Result<Vec<String>, String>;
+    async fn create_pull_request(&self, pr: &NewPullRequest) -> Result<Created, String>;
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Host::GitHub => write!(f, "GitHub"),
+            Host::Gitea => write!(f, "Gitea"),
+            Host::Bitbucket => write!(f, "Bitbucket"),
+            Host::AzureDevOps => write!(f, "Azure DevOps"),
+        }
+    }
+}
+
+impl Host {
+    pub fn unsupported(&self, feature: Feature) -> Option<&'static str> {
+        let capabilities = self.capabilities();
+
+        match feature {
+            _ if *self == Host::GitHub => None,
+            Feature::Drafts if !capabilities.drafts => Some("drafts"),
+            Feature::AutoMerge if !capabilities.auto_merge => Some("auto-merge"),
+            _ => Some("this command"),
+        }
+    }
+
+    pub fn capabilities(&self) -> Capabilities {
+        match self {
+            Host::GitHub => Capabilities {
+                drafts: true,
+                teams: true,
+                auto_merge: true,
+                projects: true,
+            },
+            Host::Gitea => Gitea::CAPABILITIES,
+            Host::Bitbucket => Bitbucket::CAPABILITIES,
+            Host::AzureDevOps => AzureDevOps::CAPABILITIES,
+        }
+    }
+}
+
+pub fn ensure_supported(remote_url: &str, mapping: &Mapping, feature: Feature) {
+    let Ok(host) = resolve(remote_url, mapping) else {
+        return;
+    };
+
+    if let Some(missing) = host.unsupported(feature) {
+        println!("{}", format!("{host} doesn't support {missing}").red());
+        println!("This command only works with GitHub repositories");
+        exit(1);
+    }
+}
+
+pub fn detect(remote_url: &str, mapping: &Mapping) -> Host {
+    match resolve(remote_url, mapping) {
+        Ok(forge) => forge,
+        Err(host) if host.is_empty() => or_exit(Err(invalid_remote(remote_url))),
+        Err(host) => unsupported(&host),
+    }
+}
+
+pub fn resolve(remote_url: &str, mapping: &Mapping) -> Result<Host, String> {
+    let host = profiles::host(remote_url).unwrap_or_default();
+    let github_host = network::api_url().is_some()
+        || profiles::active().is_some_and(|p| p.api_url.is_some() || p.hosts.contains(&host));
+
+    match lookup(&host, mapping) {
+        Some(forge) => Ok(forge),
+        None if github_host => Ok(Host::GitHub),
+        None => Err(host),
+    }
+}
+
+fn lookup(host: &str, mapping: &Mapping) -> Option<Host> {
+    if let Some(forge) = mapping.get(host) {
+        return Some(*forge);
+    }
+
+    match host {
+        "github.com" | "ssh.github.com" => Some(Host::GitHub),
+        "codeberg.org" | "gitea.com" => Some(Host::Gitea),
+        "bitbucket.org" => Some(Host::Bitbucket),
+        "dev.azure.com" | "ssh.dev.azure.com" => Some(Host::AzureDevOps),
+        host if host.ends_with(".visualstudio.com") => Some(Host::AzureDevOps),
+        _ => None,
+    }
+}
+
+fn unsupported(host: &str) -> ! {
+    println!("{}", format!("Unsupported forge {host}").red());
+    println!(
+        "prmaker works with GitHub, Gitea, Bitbucket and Azure DevOps, if {host} runs one of them map it in the config:"
+    );
+    println!("\n[forges]\n\"{host}\" = \"gitea\"\n");
+    println!("Valid values are github, gitea, bitbucket and azure-devops");
+    exit(1);
+}
+
+pub async fn create(host: Host, remote_url: &str, args: CreateArgs) {
+    match host {
+        Host::GitHub => unreachable!(),
+        Host::Gitea => run(&or_exit(gitea(remote_url)), host, args).await,
+        Host::Bitbucket => run(&or_exit(bitbucket(remote_url)), host, args).await,
+        Host::AzureDevOps => run(&or_exit(azure_devops(remote_url)), host, args).await,
+    }
+}
+
+pub async fn open_pull_request(
+    host: Host,
+    remote_url: &str,
+    new_pr: NewPullRequest,
+) -> Result<(Created, Vec<String>), String> {
+    match host {
+        Host::GitHub => unreachable!(),
+        Host::Gitea => open(&gitea(remote_url)?, host, new_pr).await,
+        Host::Bitbucket => open(&bitbucket(remote_url)?, host, new_pr).await,
+        Host::AzureDevOps => open(&azure_devops(remote_url)?, host, new_pr).await,
+    }
+}
+
+async fn open<F: Forge>(
+    forge: &F,
+    host: Host,
+    mut new_pr: NewPullRequest,
+) -> Result<(Created, Vec<String>), String> {
+    let mut warnings = vec![];
+
+    if new_pr.base.is_empty() {
+        new_pr.base = forge.default_branch().await?;
+    }
+
+    if !F::CAPABILITIES.teams {
+        let (teams, users): (Vec<String>, Vec<String>) =
+            new_pr.reviewers.into_iter().partition(|r| r.contains('/'));
+
+        if !teams.is_empty() {
+            warnings.push(format!(
+                "{host} doesn't support team reviewers, skipped {}",
+                teams.join(", ")
+            ));
+        }
+
+        new_pr.reviewers = users;
+    }
+
+    let created = forge.create_pull_request(&new_pr).await?;
+
+    Ok((created, warnings))
+}
+
+fn gitea(remote_url: &str) -> Result<Gitea, String> {
+    let (owner, repo) = pr::parse_remote(remote_url).ok_or_else(|| invalid_remote(remote_url))?;
+    let host = profiles::host(remote_url).unwrap_or_default();
+
+    Gitea::from_env(&host, owner, repo)
+}
+
+fn bitbucket(remote_url: &str) -> Result<Bitbucket, String> {
+    let (workspace, repo) =
+        pr::parse_remote(remote_url).ok_or_else(|| invalid_remote(remote_url))?;
+
+    Bitbucket::from_env(workspace, repo)
+}
+
+fn azure_devops(remote_url: &str) -> Result<AzureDevOps, String> {
+    let remote = azure::parse_remote(remote_url).ok_or_else(|| invalid_remote(remote_url))?;
+
+    AzureDevOps::from_env(remote)
+}
+
+fn invalid_remote(remote_url: &str) -> String {
+    format!("Couldn't get the repo from remote url {remote_url}")
+}
+
+fn or_exit<F>(forge: Result<F, String>) -> F {
+    forge.unwrap_or_else(|err| {
+        println!("{}", err.red());
+        exit(1);
+    })
+}
+
+async fn run<F: Forge>(forge: &F, host: Host, args: CreateArgs) {
+    let config = Config::load();
+    let head = args.head.clone().unwrap_or_else(git::current_branch);
+    let base = match args.base.as_deref().or(config.base_branch()) {
+        Some(base) => base.to_owned(),
+        None => forge.default_branch().await.unwrap_or_else(|err| {
+            println!("{}", "Couldn't get the default branch".red());
+            println!("{err}");
+            exit(1);
+        }),
+    };
+
+    println!();
+
+    let title = pr::get_pr_title(&git::last_commit());
+    let issues = match args.issues.is_empty() {
+        true => issues::get_yt_issues(&head, &base),
+        false => args.issues.clone(),
+    };
+    let body = match args.skip_body {
+        true => pr::DEFAULT_BODY.to_owned(),
+        false => pr::get_pr_body(config, &base),
+    };
+    let body = pr::build_full_body(config, &[], &body, &issues, &[]);
+
+    let mut reviewers = match forge.default_reviewers().await {
+        Ok(reviewers) => reviewers,
+        Err(_) => {
+            println!(
+                "\n{}",
+                "Error fetching default reviewers, ignoring...".red()
+            );
+            vec![]
+        }
+    };
+
+    for reviewer in &args.reviewers {
+        if !reviewers.contains(reviewer) {
+            reviewers.push(reviewer.clone());
+        }
+    }
+
+    if args.no_reviewers {
+        reviewers.clear();
+    }
+
+    if !F::CAPABILITIES.teams {
+        let (teams, users): (Vec<String>, Vec<String>) =
+            reviewers.into_iter().partition(|r| r.contains('/'));
+
+        if !teams.is_empty() {
+            println!(
+                "\n{}",
+                format!(
+                    "{host} doesn't support team reviewers, skipping {}",
+                    teams.join(", ")
+                )
+                .yellow()
+            );
+        }
+
+        reviewers = users;
+    }
+
+    println!("\n{}", "** Review PR **".blue());
+    println!("Title: {}", title.cyan());
+    println!("Youtrack issue: {}", issues.join(", ").cyan());
+    println!("Remote branch: {}", head.cyan());
+    println!("Base branch: {}", base.cyan());
+    println!("Reviewers: {}", reviewers.join(", ").cyan());
+
+    proceed_question();
+
+    println!("\nCreating PR...");
+
+    let new_pr = NewPullRequest {
+        title,
+        body,
+        head,
+        base,
+        reviewers,
+    };
+
+    match forge.create_pull_request(&new_pr).await {
+        Ok(created) => {
+            println!(
+                "\n{} #{}",
+                "PR created successfully:".green(),
+                created.number
+            );
+            println!("\nPR: {}", created.link);
+        }
+        Err(err) => {
+            println!("\n{}", "Something went wrong, error message: ".red());
+            println!("{err}");
+            exit(1);
+        }
+    }
+}
+
+fn credential(var: &str) -> Result<String, String> {
+    env::var(var).map_err(|_| format!("Couldn't get {var} environment variable"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_test() {
+        let mapping: Mapping = toml::from_str(r#""git.acme.com" = "gitea""#).unwrap();
+        let host = |remote_url: &str| lookup(&profiles::host(remote_url).unwrap(), &mapping);
+
+        assert_eq!(Some(Host::GitHub), host("git@github.com:acme/api.git"));
+        assert_eq!(Some(Host::Gitea), host("https://codeberg.org/acme/api.git"));
+        assert_eq!(Some(Host::Gitea), host("git@git.acme.com:acme/api.git"));
+        assert_eq!(
+            Some(Host::Bitbucket),
+            host("git@bitbucket.org:acme/api.git")
+        );
+        assert_eq!(
+            Some(Host::Bitbucket),
+            host("https://jdoe@bitbucket.org/acme/api.git")
+        );
+        assert_eq!(
+            Some(Host::AzureDevOps),
+            host("https://acme@dev.azure.com/acme/Platform/_git/api")
+        );
+        assert_eq!(
+            Some(Host::AzureDevOps),
+            host("git@ssh.dev.azure.com:v3/acme/Platform/api")
+        );
+        assert_eq!(None, host("git@git.sr.ht:~jdoe/api"));
+    }
+
+    #[test]
+    fn unsupported_feature_test() {
+        assert_eq!(None, Host::GitHub.unsupported(Feature::Drafts));
+        assert_eq!(Some("drafts"), Host::Gitea.unsupported(Feature::Drafts));
+        assert_eq!(
+            Some("auto-merge"),
+            Host::Bitbucket.unsupported(Feature::AutoMerge)
+        );
+        assert_eq!(
+            Some("this command"),
+            Host::AzureDevOps.unsupported(Feature::GitHubApi)
+        );
+    }
+
+    #[test]
+    fn missing_capabilities_test() {
+        assert!(Host::GitHub.capabilities().missing().is_empty());
+        assert_eq!(
+            vec!["drafts", "team reviewers", "auto-merge", "projects"],
+            Host::Bitbucket.capabilities().missing()
+        );
+        assert_eq!(
+            vec!["drafts", "auto-merge", "projects"],
+            Host::Gitea.capabilities().missing()
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use colored::Colorize;
+use std::{
+    io,
+    process::{exit, Command, Output},
+    sync::OnceLock,
+    time::Instant,
+};
+
+use crate::audit;
+
+static HEAD_REF: OnceLock<String> = OnceLock::new();
+static TOPLEVEL: OnceLock<String> = OnceLock::new();
+
+pub fn remote_url() -> String {
+    git(&["config", "--get", "remote.origin.url"])
+}
+
+pub fn toplevel() -> String {
+    TOPLEVEL
+        .get_or_init(|| git(&["rev-parse", "--show-toplevel"]))
+        .clone()
+}
+
+pub fn git_path(path: &str) -> String {
+    git(&["rev-parse", "--git-path", path])
+}
+
+pub fn superproject() -> Option<String> {
+    let superproject = git(&["rev-parse", "--show-superproject-working-tree"]);
+
+    (!superproject.is_empty()).then_some(superproject)
+}
+
+pub fn set_head(branch: &str) {
+    let _ = HEAD_REF.set(format!("origin/{branch}"));
+}
+
+pub fn head() -> &'static str {
+    HEAD_REF.get().map(|h| h.as_str()).unwrap_or("HEAD")
+}
+
+pub fn head_sha() -> String {
+    git(&["rev-parse", head()])
+}
+
+pub fn current_branch() -> String {
+    git(&["branch", "--show-current"])
+}
+
+pub fn branches(refs: &str) -> Vec<String> {
+    let prefix = format!("{}/", refs.trim_end_matches('/'));
+
+    git(&["for-each-ref", "--format=%(refname)", refs])
+        .lines()
+        .filter_map(|r| r.strip_prefix(&prefix))
+        .filter(|b| *b != "HEAD")
+        .map(|b| b.to_owned())
+        .collect()
+}
+
+pub fn last_commit() -> String {
+    git(&["log", "-1", "--pretty=format:%s", head()])
+}
+
+pub fn commit_subjects(range: &str) -> Result<Vec<String>, String> {
+    let output = try_git(&["log", "--pretty=format:%s", range])?;
+
+    Ok(output.lines().map(|l| l.to_owned()).collect())
+}
+
+pub fn changed_files(range: &str) -> Vec<String> {
+    match try_git(&["diff", "--name-only", range]) {
+        Ok(output) => output.lines().map(|l| l.to_owned()).collect(),
+        Err(_) => vec![],
+    }
+}
+
+pub fn conflicted_files() -> Vec<String> {
+    git(&["diff", "--name-only", "--diff-filter=U"])
+        .lines()
+        .map(|l| l.to_owned())
+        .collect()
+}
+
+pub fn merge_conflicts(base: &str, head: &str) -> Result<Vec<String>, String> {
+    let output = run(&[
+        "merge-tree",
+        "--write-tree",
+        "--name-only",
+        "--no-messages",
+        base,
+        head,
+    ])
+    .map_err(|err| format!("failed to run `git merge-tree`: {err}"))?;
+
+    match output.status.code() {
+        Some(0) => Ok(vec![]),
+        Some(1) => Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .skip(1)
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.to_owned())
+            .collect()),
+        _ => Err(String::from_utf8_lossy(&output.stderr).trim().to_owned()),
+    }
+}
+
+pub fn run_or_exit(args: &[&str]) {
+    if let Err(err) = try_git(args) {
+        println!(
+            "{}",
+            format!("Failed to run `git {}`:", args.join(" ")).red()
+        );
+        println!("{err}");
+        exit(1);
+    }
+}
+
+fn git(args: &[&str]) -> String {
+    let stdout = run(args)
+        .unwrap_or_else(|_| panic!("failed to run `git {}`", args.join(" ")))
+        .stdout;
+
+    String::from_utf8(stdout).unwrap().trim().to_owned()
+}
+
+pub fn try_git(args: &[&str]) -> Result<String, String> {
+    let output =
+        run(args).map_err(|err| format!("failed to run `git {}`: {err}", args.join(" ")))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_owned())
+    }
+}
+
+fn run(args: &[&str]) -> io::Result<Output> {
+    let started = Instant::now();
+    let output = Command::new("git").args(args).output();
+
+    let outcome = match &output {
+        Ok(output) => match output.status.code() {
+            Some(code) => format!("exit {code}"),
+            None => "killed".to_owned(),
+        },
+        Err(_) => "failed to start".to_owned(),
+    };
+
+    audit::record("git", &args.join(" "), &outcome, started.elapsed());
+
+    output
+}
@@ -0,0 +1,64 @@
+use colored::Colorize;
+use jsonwebtoken::EncodingKey;
+use octocrab::{
+    models::{AppId, InstallationId},
+    OctocrabBuilder,
+};
+use secrecy::ExposeSecret;
+use serde::Deserialize;
+use std::{env, fs, path::PathBuf, process::exit};
+
+use crate::{git, network::with_timeout, pr, GITHUB_TOKEN_VAR, GITHUB_USER_VAR};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubApp {
+    pub app_id: u64,
+    pub private_key_path: PathBuf,
+    pub installation_id: Option<u64>,
+}
+
+pub async fn authenticate(app: &GithubApp) {
+    let (token, login) = installation_token(app).await.unwrap_or_else(|err| {
+        println!("{}", "Couldn't authenticate as the GitHub App".red());
+        println!("{err}");
+        exit(1);
+    });
+
+    env::set_var(GITHUB_TOKEN_VAR, token);
+
+    if env::var(GITHUB_USER_VAR).is_err() {
+        env::set_var(GITHUB_USER_VAR, login);
+    }
+}
+
+async fn installation_token(app: &GithubApp) -> Result<(String, String), String> {
+    let pem = fs::read(&app.private_key_path)
+        .map_err(|err| format!("{}: {err}", app.private_key_path.display()))?;
+    let key =
+        EncodingKey::from_rsa_pem(&pem).map_err(|err| format!("invalid private key: {err}"))?;
+    let octocrab = OctocrabBuilder::new()
+        .app(AppId(app.app_id), key)
+        .build()
+        .map_err(|_| "couldn't build the GitHub client".to_owned())?;
+
+    let installation_id = match app.installation_id {
+        Some(id) => InstallationId(id),
+        None => {
+            let (base, repo) = pr::parse_remote(&git::remote_url())
+                .ok_or("installation_id is required outside of a GitHub repository")?;
+
+            with_timeout!(octocrab.apps().get_repository_installation(&base, &repo))
+                .map_err(|_| format!("the app isn't installed on {base}/{repo}"))?
+                .id
+        }
+    };
+
+    let slug = with_timeout!(octocrab.current().app())
+        .ok()
+        .and_then(|a| a.slug)
+        .ok_or("couldn't fetch the app")?;
+    let (_, token) = with_timeout!(octocrab.installation_and_token(installation_id))
+        .map_err(|_| "couldn't create an installation token".to_owned())?;
+
+    Ok((token.expose_secret().to_owned(), format!("{slug}[bot]")))
+}
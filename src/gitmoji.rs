@@ -0,0 +1,55 @@
+use colored::Colorize;
+use prmaker_core::gitmoji::GITMOJIS;
+
+use crate::{conventional, flush_line, read_input};
+
+pub use prmaker_core::gitmoji::strip;
+
+pub fn pick(title: &str) -> String {
+    let title = strip(title);
+    let mut selected = conventional::commit_type(title)
+        .and_then(|commit_type| GITMOJIS.iter().position(|(t, _, _)| *t == commit_type));
+
+    loop {
+        println!("\n{}", "** Gitmojis **".blue());
+
+        for (index, (commit_type, emoji, description)) in GITMOJIS.iter().enumerate() {
+            let result = format!(
+                "{} - {emoji} {commit_type}: {description}",
+                index.to_string().purple()
+            );
+
+            if selected == Some(index) {
+                println!("{}", result.cyan());
+            } else {
+                println!("{}", result);
+            }
+        }
+
+        print!("\n{}", "Select a gitmoji (empty to proceed): ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.trim().is_empty() {
+            break;
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < GITMOJIS.len() => {
+                if selected == Some(index) {
+                    selected = None
+                } else {
+                    selected = Some(index)
+                }
+            }
+            Ok(_) => println!("{}", "Gitmoji not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+
+    match selected {
+        Some(index) => format!("{} {title}", GITMOJIS[index].1),
+        None => title.to_owned(),
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+};
+
+use crate::run_log;
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub created_at: DateTime<Utc>,
+    pub repo: String,
+    pub branch: String,
+    pub number: u64,
+    pub title: String,
+    pub link: String,
+    pub reviewers: Vec<String>,
+}
+
+pub fn append(entry: &Entry) {
+    let Some(path) = run_log::state_path(HISTORY_FILE) else {
+        return;
+    };
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    let Ok(line) = serde_json::to_string(entry) else {
+        return;
+    };
+
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "{line}");
+    }
+}
+
+pub fn load() -> Vec<Entry> {
+    let Some(content) = run_log::state_path(HISTORY_FILE).and_then(|p| fs::read_to_string(p).ok())
+    else {
+        return vec![];
+    };
+
+    parse(&content)
+}
+
+pub fn search<'a>(entries: &'a [Entry], query: Option<&str>) -> Vec<&'a Entry> {
+    let Some(query) = query.map(|q| q.to_lowercase()) else {
+        return entries.iter().collect();
+    };
+
+    entries
+        .iter()
+        .filter(|e| {
+            [&e.repo, &e.branch, &e.title, &e.link]
+                .iter()
+                .any(|field| field.to_lowercase().contains(&query))
+                || e.reviewers.iter().any(|r| r.to_lowercase() == query)
+        })
+        .collect()
+}
+
+fn parse(content: &str) -> Vec<Entry> {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_test() {
+        let content = r#"{"created_at":"2026-10-06T09:00:00Z","repo":"acme/api","branch":"fix/CT-1/login","number":1,"title":"fix: login","link":"https://github.com/acme/api/pull/1","reviewers":["jane"]}
+not json
+{"created_at":"2026-10-07T09:00:00Z","repo":"acme/web","branch":"feat/CT-2/theme","number":2,"title":"feat: theme","link":"https://github.com/acme/web/pull/2","reviewers":[]}
+"#;
+
+        let entries = parse(content);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(search(&entries, None).len(), 2);
+        assert_eq!(search(&entries, Some("WEB"))[0].number, 2);
+        assert_eq!(search(&entries, Some("jane"))[0].number, 1);
+        assert!(search(&entries, Some("missing")).is_empty());
+    }
+
+    #[test]
+    fn json_output_snapshot_test() {
+        let entries = parse(
+            r#"{"created_at":"2026-10-06T09:00:00Z","repo":"acme/api","branch":"fix/CT-1/login","number":1,"title":"fix: login","link":"https://github.com/acme/api/pull/1","reviewers":["jane"]}"#,
+        );
+
+        insta::assert_snapshot!(
+            "json_output",
+            serde_json::to_string_pretty(&search(&entries, None)).unwrap()
+        );
+    }
+}
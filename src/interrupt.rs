@@ -0,0 +1,131 @@
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    process::{exit, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use crate::run_log::state_path;
+
+const DRAFT_FILE: &str = "draft.toml";
+const RESET_TERMINAL: &str = "\x1b[0m\x1b[?25h";
+
+static DRAFT: Mutex<Option<Draft>> = Mutex::new(None);
+static ECHO_OFF: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Draft {
+    pub branch: String,
+    pub title: String,
+    pub body: String,
+}
+
+#[cfg(feature = "multi-thread")]
+pub fn install_handler() {
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            on_interrupt();
+        }
+    });
+}
+
+#[cfg(not(feature = "multi-thread"))]
+pub fn install_handler() {
+    std::thread::spawn(|| {
+        let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        else {
+            return;
+        };
+
+        if runtime.block_on(tokio::signal::ctrl_c()).is_ok() {
+            on_interrupt();
+        }
+    });
+}
+
+pub fn track(draft: Draft) {
+    *DRAFT.lock().unwrap() = Some(draft);
+}
+
+pub fn take_saved_draft(branch: &str) -> Option<Draft> {
+    let path = state_path(DRAFT_FILE)?;
+    let content = fs::read_to_string(&path).ok()?;
+    let draft: Draft = toml::from_str(&content).ok()?;
+
+    if draft.branch != branch {
+        return None;
+    }
+
+    let _ = fs::remove_file(path);
+
+    Some(draft)
+}
+
+pub fn finish() {
+    *DRAFT.lock().unwrap() = None;
+}
+
+#[cfg(feature = "vault")]
+pub struct HiddenInput;
+
+#[cfg(feature = "vault")]
+impl HiddenInput {
+    pub fn start() -> Self {
+        stty("-echo");
+        ECHO_OFF.store(true, Ordering::SeqCst);
+
+        HiddenInput
+    }
+}
+
+#[cfg(feature = "vault")]
+impl Drop for HiddenInput {
+    fn drop(&mut self) {
+        ECHO_OFF.store(false, Ordering::SeqCst);
+        stty("echo");
+    }
+}
+
+fn stty(mode: &str) {
+    let _ = Command::new("stty")
+        .arg(mode)
+        .stdin(Stdio::inherit())
+        .status();
+}
+
+fn on_interrupt() {
+    if ECHO_OFF.load(Ordering::SeqCst) {
+        stty("echo");
+    }
+
+    print!("{RESET_TERMINAL}");
+    println!("\n\n{}", "Interrupted, closing...".yellow());
+
+    let draft = DRAFT.lock().map(|d| d.clone()).unwrap_or_default();
+
+    if let Some(draft) = draft {
+        if save(&draft) {
+            println!("The title and body were saved and will be offered on the next run");
+        }
+    }
+
+    exit(130);
+}
+
+fn save(draft: &Draft) -> bool {
+    let Some(path) = state_path(DRAFT_FILE) else {
+        return false;
+    };
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    toml::to_string(draft).is_ok_and(|content| fs::write(path, content).is_ok())
+}
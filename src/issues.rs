@@ -0,0 +1,137 @@
+use colored::Colorize;
+use prmaker_core::issues::{from_branch_name, from_commits, looks_wrong, parse_list};
+
+use crate::{flush_line, git, read_input};
+
+pub fn get_yt_issues(branch: &str, base_branch: &str) -> Vec<String> {
+    let mut candidates = from_branch_name(branch);
+
+    let messages = git::try_git(&[
+        "log",
+        "--pretty=format:%B",
+        &format!("origin/{base_branch}..{}", git::head()),
+    ])
+    .unwrap_or_default();
+
+    for issue in from_commits(&messages) {
+        if !candidates.contains(&issue) {
+            candidates.push(issue);
+        }
+    }
+
+    let issues = match candidates.len() {
+        0 => {
+            println!(
+                "\n{}",
+                "Couldn't get Youtrack issue from branch name. Please provide one or leave it empty".red()
+            );
+            request_yt_issues()
+        }
+        1 => candidates,
+        _ => get_selected_yt_issues(candidates),
+    };
+
+    if issues.iter().any(|i| looks_wrong(i, branch)) {
+        return review_yt_issues(issues, branch);
+    }
+
+    issues
+}
+
+fn request_yt_issues() -> Vec<String> {
+    print!("Youtrack issue: ");
+    flush_line();
+
+    parse_list(&read_input())
+}
+
+fn review_yt_issues(issues: Vec<String>, branch: &str) -> Vec<String> {
+    println!(
+        "\n{}",
+        format!(
+            "Youtrack issue {} from branch {branch} looks unusual",
+            issues.join(", ")
+        )
+        .red()
+    );
+    print!(
+        "Digit the right issues, {} to clear them or leave it blank to keep: ",
+        "-".purple()
+    );
+    flush_line();
+
+    let input = read_input();
+
+    match input.trim() {
+        "" => issues,
+        "-" => vec![],
+        input => parse_list(input),
+    }
+}
+
+fn get_selected_yt_issues(candidates: Vec<String>) -> Vec<String> {
+    let mut selected = vec![true; candidates.len()];
+
+    loop {
+        println!("\n{}", "** Youtrack issues **".blue());
+
+        for (index, issue) in candidates.iter().enumerate() {
+            let result = format!("{} - {}", index.to_string().purple(), issue);
+
+            if selected[index] {
+                println!("{}", result.cyan());
+            } else {
+                println!("{}", result);
+            }
+        }
+
+        print!("\n{}", "Toggle an issue (empty to proceed): ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.trim().is_empty() {
+            break;
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < candidates.len() => selected[index] = !selected[index],
+            Ok(_) => println!("{}", "Issue not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+
+    candidates
+        .into_iter()
+        .zip(selected)
+        .filter(|(_, selected)| *selected)
+        .map(|(issue, _)| issue)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection, prelude::*, sample};
+
+    proptest! {
+        #[test]
+        fn generated_branch_issues_test(
+            issues in collection::vec("[A-Z][A-Z0-9]{0,4}-[0-9]{1,5}", 1..=3),
+            description in "[a-z][a-z-]{0,19}",
+            branch_type in sample::select(vec!["", "fix/", "feat/", "chore/"]),
+            nested in any::<bool>(),
+        ) {
+            let branch = match nested {
+                true => format!("{branch_type}{}/{description}", issues.join("/")),
+                false => format!("{branch_type}{}-{description}", issues[0]),
+            };
+            let expected = match branch.ends_with(&format!("/{description}")) {
+                true => issues,
+                false => issues[..1].to_vec(),
+            };
+
+            prop_assert_eq!(expected, from_branch_name(&branch), "{}", branch);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use colored::Colorize;
+use octocrab::{models::Label, Octocrab};
+
+use crate::{flush_line, paginate, read_input};
+
+pub async fn get_repo_labels(octocrab: &Octocrab, base: &str, repo: &str) -> Option<Vec<String>> {
+    let route = format!("repos/{base}/{repo}/labels");
+    let labels_resp: octocrab::Result<Vec<Label>> = paginate::collect(octocrab, route).await;
+
+    match labels_resp {
+        Ok(labels) => Some(labels.into_iter().map(|l| l.name).collect()),
+        Err(_) => {
+            println!("\n{}", "Error fetching labels".red());
+            None
+        }
+    }
+}
+
+pub fn get_selected_labels(available: &[String], preselected: &[String]) -> Vec<String> {
+    let mut labels: Vec<String> = available.to_vec();
+
+    for label in preselected {
+        if !labels.contains(label) {
+            labels.push(label.clone());
+        }
+    }
+
+    let mut selected: Vec<bool> = labels.iter().map(|l| preselected.contains(l)).collect();
+
+    loop {
+        println!("\n{}", "** Labels **".blue());
+
+        for (index, label) in labels.iter().enumerate() {
+            let result = format!("{} - {}", index.to_string().purple(), label);
+
+            if selected[index] {
+                println!("{}", result.cyan());
+            } else {
+                println!("{}", result);
+            }
+        }
+
+        print!("\n{}", "Toggle a label (empty to proceed): ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.trim().is_empty() {
+            break;
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < labels.len() => selected[index] = !selected[index],
+            Ok(_) => println!("{}", "Label not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+
+    labels
+        .into_iter()
+        .zip(selected)
+        .filter(|(_, selected)| *selected)
+        .map(|(label, _)| label)
+        .collect()
+}
+
+pub fn get_changes(before: &[String], after: &[String]) -> (Vec<String>, Vec<String>) {
+    let added = after
+        .iter()
+        .filter(|l| !before.contains(l))
+        .cloned()
+        .collect();
+    let removed = before
+        .iter()
+        .filter(|l| !after.contains(l))
+        .cloned()
+        .collect();
+
+    (added, removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_changes_test() {
+        let before = ["bug".to_owned(), "backend".to_owned()];
+        let after = ["backend".to_owned(), "urgent".to_owned()];
+
+        let (added, removed) = get_changes(&before, &after);
+
+        assert_eq!(added, ["urgent"]);
+        assert_eq!(removed, ["bug"]);
+    }
+}
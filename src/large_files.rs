@@ -0,0 +1,101 @@
+use colored::Colorize;
+use std::process::exit;
+
+use crate::{confirm_or, git};
+
+const DEFAULT_THRESHOLD_KB: u64 = 1024;
+
+#[derive(Debug, PartialEq)]
+struct ChangedFile {
+    path: String,
+    binary: bool,
+}
+
+pub fn warn(base_branch: &str, threshold_kb: Option<u64>) {
+    let threshold = threshold_kb.unwrap_or(DEFAULT_THRESHOLD_KB) * 1024;
+    let range = format!("origin/{base_branch}...{}", git::head());
+
+    let Ok(numstat) = git::try_git(&["diff", "--numstat", "--diff-filter=AM", &range]) else {
+        return;
+    };
+
+    let mut warnings = vec![];
+
+    for file in parse_numstat(&numstat) {
+        let object = format!("{}:{}", git::head(), file.path);
+        let size: u64 = git::try_git(&["cat-file", "-s", &object])
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(0);
+        let lfs =
+            is_lfs(&git::try_git(&["check-attr", "filter", "--", &file.path]).unwrap_or_default());
+
+        if size > threshold {
+            warnings.push(format!("{} ({} KB)", file.path, size / 1024));
+        } else if file.binary && !lfs {
+            warnings.push(format!("{} (binary, not tracked by LFS)", file.path));
+        }
+    }
+
+    if warnings.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "These files are large or binary:".red());
+
+    for warning in &warnings {
+        println!("  {}", warning.yellow());
+    }
+
+    if !confirm_or("Continue anyway?", false) {
+        println!("\nClosing...");
+        exit(0);
+    }
+}
+
+fn parse_numstat(numstat: &str) -> Vec<ChangedFile> {
+    numstat
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let added = parts.next()?;
+            let deleted = parts.next()?;
+            let path = parts.next()?;
+
+            Some(ChangedFile {
+                path: path.to_owned(),
+                binary: added == "-" && deleted == "-",
+            })
+        })
+        .collect()
+}
+
+fn is_lfs(check_attr: &str) -> bool {
+    check_attr.trim().ends_with(": filter: lfs")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_numstat_test() {
+        let numstat = "10\t2\tsrc/main.rs\n-\t-\tassets/logo.png\n";
+
+        assert_eq!(
+            parse_numstat(numstat),
+            [
+                ChangedFile {
+                    path: "src/main.rs".to_owned(),
+                    binary: false
+                },
+                ChangedFile {
+                    path: "assets/logo.png".to_owned(),
+                    binary: true
+                },
+            ]
+        );
+        assert!(is_lfs("assets/logo.png: filter: lfs\n"));
+        assert!(!is_lfs("assets/logo.png: filter: unspecified\n"));
+    }
+}
@@ -0,0 +1,107 @@
+use regex::Regex;
+use std::{collections::BTreeMap, sync::LazyLock};
+
+const BUILT_IN: [&str; 3] = ["pr_number", "pr_url", "head_sha"];
+static VAR_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*([\w.-]+)\s*\}\}").unwrap());
+
+pub type LateVars = BTreeMap<String, String>;
+
+pub struct Created<'a> {
+    pub number: u64,
+    pub link: &'a str,
+    pub head_sha: &'a str,
+}
+
+pub fn strip(body: &str, custom: &LateVars) -> String {
+    VAR_REGEX
+        .replace_all(body, |captures: &regex::Captures| {
+            if is_late(&captures[1], custom) {
+                String::new()
+            } else {
+                captures[0].to_owned()
+            }
+        })
+        .into_owned()
+}
+
+pub fn substitute(body: &str, custom: &LateVars, created: &Created) -> Option<String> {
+    let has_late = VAR_REGEX
+        .captures_iter(body)
+        .any(|captures| is_late(&captures[1], custom));
+
+    if !has_late {
+        return None;
+    }
+
+    let expanded = VAR_REGEX.replace_all(body, |captures: &regex::Captures| {
+        match custom.get(&captures[1]) {
+            Some(value) => value.clone(),
+            None => captures[0].to_owned(),
+        }
+    });
+
+    let substituted =
+        VAR_REGEX.replace_all(&expanded, |captures: &regex::Captures| match &captures[1] {
+            "pr_number" => created.number.to_string(),
+            "pr_url" => created.link.to_owned(),
+            "head_sha" => created.head_sha.to_owned(),
+            _ => captures[0].to_owned(),
+        });
+
+    Some(substituted.into_owned())
+}
+
+pub fn is_late(name: &str, custom: &LateVars) -> bool {
+    BUILT_IN.contains(&name) || custom.contains_key(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn created() -> Created<'static> {
+        Created {
+            number: 7,
+            link: "https://github.com/acme/api/pull/7",
+            head_sha: "abc123",
+        }
+    }
+
+    #[test]
+    fn substitute_test() {
+        let custom = LateVars::from([(
+            "preview".to_owned(),
+            "https://preview-{{pr_number}}.example.com".to_owned(),
+        )]);
+
+        assert_eq!(
+            Some(
+                "Preview: https://preview-7.example.com at abc123, PR https://github.com/acme/api/pull/7 {{owner}}"
+                    .to_owned()
+            ),
+            substitute(
+                "Preview: {{preview}} at {{head_sha}}, PR {{ pr_url }} {{owner}}",
+                &custom,
+                &created()
+            )
+        );
+        assert_eq!(
+            None,
+            substitute("No previews {{owner}}", &custom, &created())
+        );
+    }
+
+    #[test]
+    fn strip_test() {
+        let custom = LateVars::from([("preview".to_owned(), String::new())]);
+
+        assert_eq!(
+            "preview-.example.com  {{owner}}",
+            strip(
+                "preview-{{pr_number}}.example.com {{preview}} {{owner}}",
+                &custom
+            )
+        );
+    }
+}
@@ -0,0 +1,791 @@
+mod api;
+mod audit;
+mod base_branch;
+mod body_generator;
+mod capabilities;
+mod checklist;
+mod cli;
+mod codeowners;
+mod commands;
+mod commit_picker;
+mod committers;
+mod compare;
+mod config;
+mod contributors;
+mod dco;
+mod diff;
+mod duplicates;
+mod editor;
+mod flow;
+mod forge;
+mod git;
+#[cfg(feature = "github-app")]
+mod github_app;
+mod gitmoji;
+mod history;
+mod interrupt;
+mod issues;
+mod labels;
+mod large_files;
+mod late_vars;
+mod milestone;
+mod network;
+/// Follows GitHub's `Link` headers to list every page of a route.
+pub mod paginate;
+mod placeholders;
+mod pr;
+mod profiles;
+mod projects;
+mod protection;
+mod recovery;
+mod related;
+#[cfg(feature = "replay")]
+mod replay;
+mod repo_picker;
+mod reviewers;
+mod run_log;
+mod schedule;
+mod schema;
+mod secrets;
+mod spelling;
+mod stdin_json;
+mod submodule;
+mod summary_comment;
+mod title_transform;
+mod token;
+mod validation;
+#[cfg(feature = "vault")]
+mod vault;
+
+use chrono::{Local, Utc};
+use clap::Parser;
+use cli::{Cli, Command, CreateArgs, ReviewersAction};
+use colored::Colorize;
+use config::Config;
+use flow::Step;
+use octocrab::{Octocrab, OctocrabBuilder};
+use prmaker_core::conventional;
+use recovery::Action;
+use run_log::RunLog;
+use std::{
+    env,
+    io::{self, Write},
+    path::Path,
+    process::exit,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+pub use api::{create_pull_request, PrRequest, PrResult};
+
+const GITHUB_TOKEN_VAR: &str = "GITHUB_TOKEN";
+const GITHUB_USER_VAR: &str = "GITHUB_USER";
+
+static ASSUME_DEFAULTS: AtomicBool = AtomicBool::new(false);
+
+pub async fn run() {
+    let cli = Cli::parse();
+
+    interrupt::install_handler();
+
+    if cli.audit {
+        match audit::enable() {
+            Some(path) => println!("Auditing to {}", path.display()),
+            None => println!("{}", "Couldn't open the audit log".red()),
+        }
+    }
+
+    if let Some(directory) = &cli.directory {
+        change_dir(directory);
+    }
+
+    if needs_workspace(&cli.command) {
+        enter_workspace();
+    }
+
+    let config = Config::load();
+
+    profiles::activate(&config.profiles, cli.profile.as_deref());
+
+    if let Some(feature) = cli.command.as_ref().and_then(github_feature) {
+        forge::ensure_supported(&git::remote_url(), &config.forges, feature);
+    }
+
+    #[cfg(feature = "replay")]
+    let replay_mode = {
+        let upstream = profiles::active()
+            .and_then(|p| p.api_url.clone())
+            .unwrap_or_else(|| replay::GITHUB_API_URL.to_owned());
+
+        match (&cli.record, &cli.replay) {
+            (Some(fixtures), _) => Some(replay::Mode::Record {
+                upstream,
+                fixtures: fixtures.clone(),
+            }),
+            (None, Some(fixtures)) => Some(replay::Mode::Replay {
+                fixtures: fixtures.clone(),
+            }),
+            (None, None) if cli.audit => Some(replay::Mode::Forward { upstream }),
+            (None, None) => None,
+        }
+    };
+
+    #[cfg(feature = "replay")]
+    if let Some(mode) = replay_mode {
+        network::set_api_url(replay::start(mode).await);
+    }
+
+    #[cfg(not(feature = "replay"))]
+    if cli.audit {
+        println!(
+            "{}",
+            "GitHub API calls are only audited in builds with the replay feature".yellow()
+        );
+    }
+
+    #[cfg(feature = "github-app")]
+    if env::var(GITHUB_TOKEN_VAR).is_err() {
+        if let Some(app) = &config.github_app {
+            github_app::authenticate(app).await;
+        }
+    }
+
+    match cli.command {
+        None if git::toplevel().is_empty() => commands::remote::run(cli.create).await,
+        None => {
+            enter_workspace();
+            create(cli.create).await
+        }
+        Some(Command::Release { from, to, into }) => {
+            commands::release::run(&from, &to, &into).await
+        }
+        Some(Command::Backport { number, to }) => commands::backport::run(number, &to).await,
+        Some(Command::Sync { base }) => commands::sync::run(base).await,
+        Some(Command::AmendBody) => commands::amend_body::run().await,
+        Some(Command::Ready { number, rerequest }) => {
+            commands::draft::ready(number, rerequest).await
+        }
+        Some(Command::Draft { number }) => commands::draft::draft(number).await,
+        Some(Command::Close { number, message }) => {
+            commands::lifecycle::close(number, message).await
+        }
+        Some(Command::Reopen { number, message }) => {
+            commands::lifecycle::reopen(number, message).await
+        }
+        Some(Command::Cleanup) => commands::cleanup::run().await,
+        Some(Command::Comment { number, message }) => commands::comment::run(number, message).await,
+        Some(Command::Inbox) => commands::inbox::run().await,
+        Some(Command::History { query, json, limit }) => commands::history::run(query, json, limit),
+        Some(Command::Stats { since, until }) => commands::stats::run(since, until).await,
+        Some(Command::Queue { number }) => commands::queue::run(number).await,
+        Some(Command::Undo) => commands::undo::run().await,
+        Some(Command::Flush { due }) => commands::flush::run(due).await,
+        Some(Command::Labels { number }) => commands::labels::run(number).await,
+        Some(Command::Open { print }) => commands::open::run(print).await,
+        Some(Command::Rereview { number, summary }) => {
+            commands::rereview::run(number, summary).await
+        }
+        Some(Command::Review { number }) => commands::review::run(number).await,
+        Some(Command::Reviewers { action }) => match action {
+            ReviewersAction::Add { number } => commands::reviewers::add(number).await,
+            ReviewersAction::Remove { number } => commands::reviewers::remove(number).await,
+        },
+        #[cfg(feature = "vault")]
+        Some(Command::Encrypt) => commands::encrypt::run(),
+        Some(Command::Doctor) => commands::doctor::run().await,
+        #[cfg(feature = "self-update")]
+        Some(Command::SelfUpdate) => commands::self_update::run().await,
+        Some(Command::Batch {
+            repos,
+            manifest,
+            yes,
+        }) => commands::batch::run(repos, manifest, yes).await,
+        Some(Command::Companion {
+            set,
+            issue,
+            reviewers,
+            yes,
+        }) => commands::companion::run(&set, issue, reviewers, yes).await,
+        Some(Command::LintTemplate) => commands::lint_template::run(),
+        Some(Command::Rebalance { days, dry_run }) => commands::rebalance::run(days, dry_run).await,
+        Some(Command::Bot { manifest }) => commands::bot::run(&manifest).await,
+        Some(Command::External(args)) => commands::plugin::run(&args),
+        Some(Command::InstallHooks { prompt, force }) => {
+            commands::install_hooks::run(prompt, force)
+        }
+        Some(Command::Watch { interval, once }) => commands::watch::run(interval, once).await,
+        #[cfg(feature = "web")]
+        Some(Command::Web { port, no_open }) => commands::web::run(cli.create, port, no_open).await,
+    }
+}
+
+async fn create(args: CreateArgs) {
+    ASSUME_DEFAULTS.store(args.yes, Ordering::Relaxed);
+
+    let config = Config::load();
+    let remote_url = git::remote_url();
+
+    if args.stdin_json {
+        return stdin_json::run().await;
+    }
+
+    match forge::detect(&remote_url, &config.forges) {
+        forge::Host::GitHub => {}
+        host => return forge::create(host, &remote_url, args).await,
+    }
+
+    let user = get_user();
+    let token = get_token();
+    let octocrab = build_octocrab(token);
+
+    let due = args.schedule.as_deref().map(|when| {
+        schedule::local_due(when).unwrap_or_else(|| {
+            println!(
+                "{}",
+                format!("Invalid schedule {when}, use e.g. \"Mon 09:00\" or \"2026-10-20 09:00\"")
+                    .red()
+            );
+            exit(1);
+        })
+    });
+
+    token::ensure_can_create(&octocrab).await;
+
+    let steps = config.steps();
+    let create_at = steps.iter().position(|s| *s == Step::Create).unwrap();
+
+    let mut pr = pr::PR::build(config, &args, &octocrab).await;
+    let mut selection = None;
+
+    if !args.no_reviewers && !args.reviewers.is_empty() {
+        validate_reviewers_or_exit(&octocrab, &pr, config, &args.reviewers).await;
+    }
+
+    if let Some(draft) = interrupt::take_saved_draft(&pr.branch) {
+        println!(
+            "\n{} {}",
+            "Draft from an interrupted run:".yellow(),
+            draft.title
+        );
+
+        if confirm("Restore its title and body?") {
+            pr.restore(&draft.title, &draft.body, config);
+        }
+    }
+
+    for step in &steps[..create_at] {
+        interrupt::track(pr.draft());
+
+        match step {
+            Step::Title => pr.ask_title(config),
+            Step::Issue if args.issues.is_empty() => pr.ask_issues(config),
+            Step::Body if !args.skip_body => pr.ask_body(config),
+            Step::Related => pr.ask_related(&octocrab, config).await,
+            Step::Review => review(&mut pr, config),
+            Step::Reviewers if !args.no_reviewers => {
+                selection = Some(select_reviewers(&octocrab, &pr, config, &args, &user).await)
+            }
+            _ => {}
+        }
+    }
+
+    interrupt::track(pr.draft());
+
+    if !flow::reviewed_before_create(&steps) {
+        validate_or_exit(&pr, config);
+        check_placeholders(&mut pr, config);
+    }
+
+    if let Some(due) = due {
+        let selection = match selection {
+            Some(selection) => selection,
+            None if args.no_reviewers || !steps.contains(&Step::Reviewers) => {
+                reviewers::Selection::default()
+            }
+            None => preselected_reviewers(&pr, config, &args),
+        };
+
+        let mut scheduled = schedule::load();
+        scheduled.push(schedule::ScheduledPR {
+            due,
+            base: pr.base,
+            repo: pr.repo,
+            branch: pr.branch,
+            base_branch: pr.base_branch,
+            title: pr.title,
+            body: pr.full_body,
+            reviewers: selection.usernames,
+            teams: selection.teams,
+        });
+        schedule::save(&scheduled);
+        interrupt::finish();
+
+        println!(
+            "\n{} {}",
+            "PR scheduled for".green(),
+            due.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        );
+        println!(
+            "Run {} from cron to create it",
+            "prmaker flush --due".cyan()
+        );
+        return;
+    }
+
+    if !duplicates::confirm_no_duplicate(&octocrab, &pr).await {
+        println!("\nClosing...");
+        exit(0);
+    }
+
+    println!("\nCreating PR...");
+
+    if pr.create(&octocrab).await.is_err() {
+        exit(1)
+    }
+
+    interrupt::finish();
+    pr.patch_late_vars(&octocrab, &config.late_vars).await;
+
+    let mut run_log = RunLog {
+        base: pr.base.clone(),
+        repo: pr.repo.clone(),
+        number: pr.number.unwrap(),
+        link: pr.link.clone().unwrap(),
+        ..RunLog::default()
+    };
+
+    run_log.save();
+
+    if !args.no_assign {
+        println!("\nAssigning to you...");
+
+        let mut assignees = vec![user.clone()];
+
+        while !pr.assign(&octocrab, &assignees).await {
+            match recovery::ask("Assigning") {
+                Action::Retry => {}
+                Action::Skip => break,
+                Action::Edit => assignees = recovery::edit("Assignees", &assignees),
+            }
+        }
+    }
+
+    for step in &steps {
+        match step {
+            Step::Reviewers if !args.no_reviewers => {
+                let selection = match selection.take() {
+                    Some(selection) => selection,
+                    None => select_reviewers(&octocrab, &pr, config, &args, &user).await,
+                };
+
+                let mut pending = selection;
+
+                loop {
+                    let requested = reviewers::request_reviews(
+                        &octocrab,
+                        &pr.base,
+                        &pr.repo,
+                        pr.number.unwrap(),
+                        pending.clone(),
+                    )
+                    .await;
+                    let failed = pending.without(&requested);
+
+                    run_log.reviewers.extend(requested.usernames);
+                    run_log.teams.extend(requested.teams);
+                    run_log.save();
+
+                    if failed.is_empty() {
+                        break;
+                    }
+
+                    pending = match recovery::ask("Requesting reviewers") {
+                        Action::Retry => failed,
+                        Action::Skip => break,
+                        Action::Edit => {
+                            pick_reviewers(&octocrab, &pr, config, &args, &user, failed).await
+                        }
+                    };
+                }
+            }
+            Step::Labels if !args.no_labels => {
+                let mut labels = pr.project_labels();
+
+                loop {
+                    if pr.add_labels(&octocrab, &labels).await {
+                        run_log.labels = labels;
+                        run_log.save();
+                        break;
+                    }
+
+                    match recovery::ask("Adding labels") {
+                        Action::Retry => {}
+                        Action::Skip => break,
+                        Action::Edit => labels = recovery::edit("Labels", &labels),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pr.credit_co_authors(&octocrab, &user).await;
+
+    if let Some(summary) = &config.summary_comment {
+        summary_comment::post(&octocrab, &pr, summary).await;
+    }
+
+    match milestone::get_milestones(&octocrab, &pr.base, &pr.repo).await {
+        Ok(milestones) if milestones.is_empty() => {}
+        Ok(milestones) => match milestone::get_selected_milestone(&milestones) {
+            Some(milestone) => pr.set_milestone(&octocrab, milestone).await,
+            None => println!("\nNo milestone to set"),
+        },
+        Err(_) => println!("\n{}", "Error fetching milestones, ignoring...".red()),
+    }
+
+    submodule::offer_superproject_bump(&octocrab, &pr).await;
+
+    history::append(&history::Entry {
+        created_at: Utc::now(),
+        repo: format!("{}/{}", pr.base, pr.repo),
+        branch: pr.branch.clone(),
+        number: run_log.number,
+        title: pr.title.clone(),
+        link: run_log.link.clone(),
+        reviewers: run_log.reviewers.clone(),
+    });
+
+    println!("\nPR: {}", pr.link.unwrap())
+}
+
+fn review(pr: &mut pr::PR, config: &Config) {
+    println!("\n{}", "** Review PR **".blue());
+    println!("{pr}");
+
+    if let Some(diff) = pr.template_diff() {
+        println!("\n{}", "** Body vs template **".blue());
+        println!("{diff}");
+    }
+
+    if let Some(spell_check) = &config.spell_check {
+        let typos = spelling::check(&[&pr.title, &pr.body], spell_check);
+
+        if !typos.is_empty() {
+            println!("\n{}", "** Possible typos **".blue());
+
+            for typo in &typos {
+                println!("{typo}");
+            }
+        }
+    }
+
+    validate_or_exit(pr, config);
+    check_placeholders(pr, config);
+    proceed_question();
+}
+
+fn check_placeholders(pr: &mut pr::PR, config: &Config) {
+    loop {
+        let placeholders = pr.placeholders(config);
+
+        if placeholders.is_empty() {
+            return;
+        }
+
+        println!("\n{}", "The body still has placeholders:".yellow());
+
+        for placeholder in &placeholders {
+            println!("  {}", placeholder.yellow());
+        }
+
+        if !confirm_or("Edit the body to fill them in?", false) {
+            return;
+        }
+
+        pr.edit_body(config);
+    }
+}
+
+fn validate_or_exit(pr: &pr::PR, config: &Config) {
+    let errors = validation::validate(&pr.title, &pr.full_body, &config.validation);
+
+    if !errors.is_empty() {
+        println!("\n{}", "The PR doesn't pass validation:".red());
+
+        for error in &errors {
+            println!("  {}", error.yellow());
+        }
+
+        exit(1);
+    }
+}
+
+async fn validate_reviewers_or_exit(
+    octocrab: &Octocrab,
+    pr: &pr::PR,
+    config: &Config,
+    names: &[String],
+) {
+    let errors =
+        reviewers::validate(octocrab, &pr.base, &pr.repo, names, &config.reviewer_groups).await;
+
+    if !errors.is_empty() {
+        println!("\n{}", "Invalid reviewers:".red());
+
+        for error in &errors {
+            println!("  {}", error.yellow());
+        }
+
+        exit(1);
+    }
+}
+
+fn preselected_reviewers(pr: &pr::PR, config: &Config, args: &CreateArgs) -> reviewers::Selection {
+    let suggested: Vec<String> = pr
+        .suggested_reviewers()
+        .into_iter()
+        .chain(args.reviewers.clone())
+        .collect();
+
+    reviewers::expand(&suggested, &config.reviewer_groups)
+}
+
+async fn select_reviewers(
+    octocrab: &Octocrab,
+    pr: &pr::PR,
+    config: &Config,
+    args: &CreateArgs,
+    user: &str,
+) -> reviewers::Selection {
+    let preselected = preselected_reviewers(pr, config, args);
+    let teams = codeowners::get_owning_teams(&pr.base_branch);
+
+    if !teams.is_empty() && skip_reviewer_picker(&teams) {
+        preselected
+    } else {
+        pick_reviewers(octocrab, pr, config, args, user, preselected).await
+    }
+}
+
+async fn pick_reviewers(
+    octocrab: &Octocrab,
+    pr: &pr::PR,
+    config: &Config,
+    args: &CreateArgs,
+    user: &str,
+    preselected: reviewers::Selection,
+) -> reviewers::Selection {
+    let source = args.reviewer_source.unwrap_or(config.reviewer_source);
+    let candidates =
+        reviewers::get_candidates(octocrab, &pr.base, &pr.repo, &pr.base_branch, source).await;
+
+    let Some(candidates) = candidates else {
+        return preselected;
+    };
+
+    let candidates = reviewers::without_ignored(candidates, &config.never_suggest);
+    let suggested: Vec<String> = committers::get_recent_committers(
+        octocrab,
+        &pr.base,
+        &pr.repo,
+        &pr.base_branch,
+        config.suggested_committers(),
+    )
+    .await
+    .into_iter()
+    .filter(|s| s != user)
+    .collect();
+    let suggested = reviewers::without_ignored(suggested, &config.never_suggest);
+
+    let logins: Vec<String> = suggested.iter().chain(&candidates).cloned().collect();
+    let details = reviewers::get_details(octocrab, &pr.base, &logins).await;
+
+    reviewers::get_selected_reviewers(
+        candidates,
+        &suggested,
+        preselected,
+        &config.reviewer_groups,
+        &details,
+    )
+}
+
+fn skip_reviewer_picker(teams: &[String]) -> bool {
+    println!(
+        "\n{} {}",
+        "Reviewers will be auto-assigned from CODEOWNERS teams:".yellow(),
+        teams.join(", ")
+    );
+
+    confirm("Skip picking reviewers manually?")
+}
+
+fn github_feature(command: &Command) -> Option<forge::Feature> {
+    match command {
+        Command::Draft { .. } | Command::Ready { .. } => Some(forge::Feature::Drafts),
+        Command::Queue { .. } => Some(forge::Feature::AutoMerge),
+        Command::Release { .. }
+        | Command::Backport { .. }
+        | Command::AmendBody
+        | Command::Close { .. }
+        | Command::Reopen { .. }
+        | Command::Cleanup
+        | Command::Comment { .. }
+        | Command::Stats { .. }
+        | Command::Labels { .. }
+        | Command::Open { .. }
+        | Command::Rereview { .. }
+        | Command::Review { .. }
+        | Command::Reviewers { .. }
+        | Command::Rebalance { .. }
+        | Command::Watch { .. } => Some(forge::Feature::GitHubApi),
+        #[cfg(feature = "web")]
+        Command::Web { .. } => Some(forge::Feature::GitHubApi),
+        _ => None,
+    }
+}
+
+fn needs_workspace(command: &Option<Command>) -> bool {
+    match command {
+        None
+        | Some(
+            Command::Batch { .. }
+            | Command::Bot { .. }
+            | Command::Doctor
+            | Command::Inbox
+            | Command::History { .. }
+            | Command::Undo
+            | Command::External(_),
+        ) => false,
+        #[cfg(feature = "vault")]
+        Some(Command::Encrypt) => false,
+        #[cfg(feature = "self-update")]
+        Some(Command::SelfUpdate) => false,
+        _ => true,
+    }
+}
+
+fn change_dir(path: &Path) {
+    if let Err(err) = env::set_current_dir(path) {
+        println!(
+            "{}",
+            format!("Couldn't change to directory {}", path.display()).red()
+        );
+        println!("{err}");
+        exit(1);
+    }
+}
+
+fn enter_workspace() {
+    let toplevel = git::toplevel();
+
+    if toplevel.is_empty() {
+        println!("{}", "Not inside a git repository".red());
+        exit(1);
+    }
+
+    change_dir(Path::new(&toplevel));
+}
+
+fn build_octocrab(token: String) -> Octocrab {
+    try_build_octocrab(token).unwrap_or_else(|err| {
+        println!("{}", err.red());
+        exit(1);
+    })
+}
+
+fn try_build_octocrab(token: String) -> Result<Octocrab, String> {
+    let builder = OctocrabBuilder::new().personal_token(token);
+
+    let api_url = network::api_url().or(profiles::active().and_then(|p| p.api_url.as_deref()));
+
+    match api_url {
+        Some(api_url) => builder
+            .base_url(api_url)
+            .map_err(|_| format!("Invalid api_url {api_url}"))?,
+        None => builder,
+    }
+    .build()
+    .map_err(|err| err.to_string())
+}
+
+fn get_user() -> String {
+    env::var(GITHUB_USER_VAR).unwrap_or_else(|_err| {
+        println!(
+            "{}",
+            format!("Couldn't get {} environment variable", GITHUB_USER_VAR).red()
+        );
+        exit(1);
+    })
+}
+
+fn get_token() -> String {
+    env::var(GITHUB_TOKEN_VAR).unwrap_or_else(|_err| {
+        println!(
+            "{}",
+            format!("Couldn't get {} environment variable", GITHUB_TOKEN_VAR).red()
+        );
+        println!("Please ensure the variable is available and it is a valid token");
+        exit(1);
+    })
+}
+
+fn proceed_question() {
+    if !confirm("Proceed?") {
+        println!("\nClosing...");
+        exit(0);
+    }
+}
+
+fn confirm(question: &str) -> bool {
+    confirm_or(question, true)
+}
+
+fn confirm_or(question: &str, default: bool) -> bool {
+    print!("\n{}", format!("{question} (y/n): ").yellow());
+    flush_line();
+
+    if assume_defaults() {
+        println!("{}", if default { "y" } else { "n" });
+        return default;
+    }
+
+    loop {
+        let opt = read_input();
+
+        match opt.trim() {
+            "y" => return true,
+            "n" => return false,
+            _ => {
+                println!(
+                    "Please digit {} for {} and {} for {}",
+                    "y".green(),
+                    "yes".green(),
+                    "n".red(),
+                    "no".red()
+                );
+
+                continue;
+            }
+        }
+    }
+}
+
+fn assume_defaults() -> bool {
+    ASSUME_DEFAULTS.load(Ordering::Relaxed)
+}
+
+fn read_input() -> String {
+    let mut input = String::new();
+
+    if assume_defaults() {
+        println!();
+    } else {
+        io::stdin().read_line(&mut input).unwrap();
+    }
+
+    input
+}
+
+fn flush_line() {
+    io::stdout().flush().unwrap();
+}
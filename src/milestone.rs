@@ -0,0 +1,185 @@
+use chrono::{DateTime, Datelike, Utc};
+use colored::Colorize;
+use octocrab::{models::Milestone, Octocrab};
+use regex::Regex;
+use std::sync::LazyLock;
+
+use crate::{flush_line, network::with_timeout, read_input};
+
+static SPRINT_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:sprint|iteration)\s*#?(\d+)\b").unwrap());
+
+pub async fn get_milestones(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+) -> octocrab::Result<Vec<Milestone>> {
+    let route = format!("repos/{base}/{repo}/milestones");
+
+    with_timeout!(octocrab.get(&route, Some(&[("state", "open"), ("per_page", "100")])))
+}
+
+pub fn get_selected_milestone(milestones: &[Milestone]) -> Option<&Milestone> {
+    let mut selected = suggest_milestone(milestones, Utc::now());
+
+    loop {
+        println!("\n{}", "** Milestones **".blue());
+
+        for (index, milestone) in milestones.iter().enumerate() {
+            let result = format!(
+                "{} - {}{}",
+                index.to_string().purple(),
+                milestone.title,
+                format_due_on(milestone.due_on)
+            );
+
+            if selected == Some(index) {
+                println!("{}", result.cyan());
+            } else {
+                println!("{}", result);
+            }
+        }
+
+        print!("\n{}", "Select a milestone (empty to proceed): ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.trim().is_empty() {
+            break;
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < milestones.len() => {
+                if selected == Some(index) {
+                    selected = None
+                } else {
+                    selected = Some(index)
+                }
+            }
+            Ok(_) => println!("{}", "Milestone not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+
+    selected.map(|index| &milestones[index])
+}
+
+fn suggest_milestone(milestones: &[Milestone], now: DateTime<Utc>) -> Option<usize> {
+    let due_dates = milestones
+        .iter()
+        .enumerate()
+        .filter_map(|(index, m)| m.due_on.map(|due_on| (index, due_on)));
+
+    let (upcoming, overdue): (Vec<_>, Vec<_>) = due_dates.partition(|(_, due_on)| *due_on >= now);
+
+    upcoming
+        .into_iter()
+        .min_by_key(|(_, due_on)| *due_on)
+        .or_else(|| overdue.into_iter().max_by_key(|(_, due_on)| *due_on))
+        .map(|(index, _)| index)
+        .or_else(|| suggest_by_title(milestones, now))
+}
+
+fn suggest_by_title(milestones: &[Milestone], now: DateTime<Utc>) -> Option<usize> {
+    let week = now.iso_week();
+    let dated = [
+        format!("{}-W{:02}", week.year(), week.week()),
+        now.format("%Y-%m").to_string(),
+        now.format("%B %Y").to_string(),
+    ];
+
+    let current = milestones.iter().position(|m| {
+        dated
+            .iter()
+            .any(|d| m.title.to_lowercase().contains(&d.to_lowercase()))
+    });
+
+    current.or_else(|| {
+        milestones
+            .iter()
+            .enumerate()
+            .filter_map(|(index, m)| {
+                let number: u64 = SPRINT_REGEX.captures(&m.title)?[1].parse().ok()?;
+                Some((index, number))
+            })
+            .min_by_key(|(_, number)| *number)
+            .map(|(index, _)| index)
+    })
+}
+
+fn format_due_on(due_on: Option<DateTime<Utc>>) -> String {
+    match due_on {
+        Some(due_on) => format!(" (due {})", due_on.format("%Y-%m-%d")),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn milestone(title: &str, due_on: Option<DateTime<Utc>>) -> Milestone {
+        serde_json::from_value(serde_json::json!({
+            "url": "https://api.github.com/repos/o/r/milestones/1",
+            "html_url": "https://github.com/o/r/milestone/1",
+            "id": 1,
+            "node_id": "MI_1",
+            "number": 1,
+            "title": title,
+            "created_at": "2026-01-01T00:00:00Z",
+            "due_on": due_on,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn suggest_milestone_test() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 14, 0, 0, 0).unwrap();
+        let day = |d| Some(Utc.with_ymd_and_hms(2026, 10, d, 0, 0, 0).unwrap());
+
+        let milestones = [
+            milestone("Backlog", None),
+            milestone("Sprint 12", day(28)),
+            milestone("Sprint 11", day(20)),
+            milestone("Sprint 10", day(6)),
+        ];
+
+        assert_eq!(Some(2), suggest_milestone(&milestones, now));
+
+        let overdue = [
+            milestone("Sprint 9", day(1)),
+            milestone("Sprint 10", day(6)),
+        ];
+
+        assert_eq!(Some(1), suggest_milestone(&overdue, now));
+        assert_eq!(None, suggest_milestone(&milestones[..1], now));
+    }
+
+    #[test]
+    fn suggest_by_title_test() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 14, 0, 0, 0).unwrap();
+
+        let sprints = [
+            milestone("Backlog", None),
+            milestone("Sprint 12", None),
+            milestone("Sprint 11", None),
+        ];
+
+        assert_eq!(Some(2), suggest_milestone(&sprints, now));
+
+        let dated = [
+            milestone("2026-W41", None),
+            milestone("2026-W42", None),
+            milestone("Iteration 3", None),
+        ];
+
+        assert_eq!(Some(1), suggest_milestone(&dated, now));
+        assert_eq!(
+            Some(0),
+            suggest_milestone(&[milestone("October 2026", None)], now)
+        );
+        assert_eq!(None, suggest_milestone(&[milestone("Backlog", None)], now));
+    }
+}
@@ -0,0 +1,80 @@
+use colored::Colorize;
+use reqwest::{Client, RequestBuilder, Response};
+use std::{
+    future::Future,
+    process::exit,
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
+use crate::{audit, confirm_or};
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+static TIMEOUT_SECS: OnceLock<u64> = OnceLock::new();
+static API_URL: OnceLock<String> = OnceLock::new();
+
+macro_rules! with_timeout {
+    ($request:expr) => {
+        loop {
+            match tokio::time::timeout($crate::network::timeout(), $request).await {
+                Ok(response) => break response,
+                Err(_) => $crate::network::on_timeout(),
+            }
+        }
+    };
+}
+
+pub(crate) use with_timeout;
+
+pub fn set_timeout(secs: u64) {
+    let _ = TIMEOUT_SECS.set(secs);
+}
+
+#[cfg(feature = "replay")]
+pub fn set_api_url(url: String) {
+    let _ = API_URL.set(url);
+}
+
+pub fn api_url() -> Option<&'static str> {
+    API_URL.get().map(|u| u.as_str())
+}
+
+pub fn timeout() -> Duration {
+    Duration::from_secs(*TIMEOUT_SECS.get().unwrap_or(&DEFAULT_TIMEOUT_SECS))
+}
+
+pub async fn within_timeout<F: Future>(request: F) -> Result<F::Output, String> {
+    tokio::time::timeout(timeout(), request)
+        .await
+        .map_err(|_| format!("no response within {}s", timeout().as_secs()))
+}
+
+pub async fn send(client: &Client, request: RequestBuilder) -> Result<Response, String> {
+    let request = request.build().map_err(|err| err.to_string())?;
+    let call = format!("{} {}", request.method(), request.url());
+    let started = Instant::now();
+    let resp = within_timeout(client.execute(request)).await;
+
+    let outcome = match &resp {
+        Ok(Ok(resp)) => resp.status().as_u16().to_string(),
+        Ok(Err(_)) => "error".to_owned(),
+        Err(_) => "timeout".to_owned(),
+    };
+
+    audit::record("http", &call, &outcome, started.elapsed());
+
+    resp?.map_err(|err| err.to_string())
+}
+
+pub fn on_timeout() {
+    println!(
+        "\n{}",
+        format!("GitHub did not respond within {}s", timeout().as_secs()).red()
+    );
+
+    if !confirm_or("Retry?", false) {
+        println!("\nClosing...");
+        exit(1);
+    }
+}
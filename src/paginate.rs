@@ -0,0 +1,61 @@
+use futures_util::{stream, Stream, TryStreamExt};
+use octocrab::{Octocrab, Page};
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use std::vec::IntoIter;
+
+use crate::network::with_timeout;
+
+const PER_PAGE: u8 = 100;
+
+enum State<T> {
+    First(String),
+    Page(IntoIter<T>, Option<Url>),
+    Done,
+}
+
+/// Streams the items of `route`, fetching the next page once the current one
+/// runs out.
+pub fn paginate<'a, T: DeserializeOwned + 'a>(
+    octocrab: &'a Octocrab,
+    route: String,
+) -> impl Stream<Item = octocrab::Result<T>> + 'a {
+    stream::unfold(State::First(route), move |mut state| async move {
+        loop {
+            state = match state {
+                State::First(route) => {
+                    let page: octocrab::Result<Page<T>> =
+                        with_timeout!(octocrab.get(&route, Some(&[("per_page", PER_PAGE)])));
+
+                    match page {
+                        Ok(page) => State::Page(page.items.into_iter(), page.next),
+                        Err(err) => return Some((Err(err), State::Done)),
+                    }
+                }
+                State::Page(mut items, next) => {
+                    if let Some(item) = items.next() {
+                        return Some((Ok(item), State::Page(items, next)));
+                    }
+
+                    let url = next?;
+                    let page: octocrab::Result<Page<T>> =
+                        with_timeout!(octocrab.get(&url, None::<&()>));
+
+                    match page {
+                        Ok(page) => State::Page(page.items.into_iter(), page.next),
+                        Err(err) => return Some((Err(err), State::Done)),
+                    }
+                }
+                State::Done => return None,
+            }
+        }
+    })
+}
+
+/// Fetches every page of `route` into one list.
+pub async fn collect<T: DeserializeOwned>(
+    octocrab: &Octocrab,
+    route: String,
+) -> octocrab::Result<Vec<T>> {
+    paginate(octocrab, route).try_collect().await
+}
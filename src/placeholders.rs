@@ -0,0 +1,75 @@
+use colored::Colorize;
+use regex::Regex;
+
+const DEFAULT_PATTERNS: [&str; 3] = [r"\bTODO\b", r"<!--[\s\S]*?-->", r"\{\{\s*[\w.-]+\s*\}\}"];
+const MAX_SNIPPET_LENGTH: usize = 60;
+
+pub fn find(body: &str, patterns: Option<&[String]>, ignored: &str) -> Vec<String> {
+    let patterns: Vec<String> = match patterns {
+        Some(patterns) => patterns.to_vec(),
+        None => DEFAULT_PATTERNS.iter().map(|p| p.to_string()).collect(),
+    };
+
+    let mut found: Vec<String> = vec![];
+
+    for pattern in &patterns {
+        let Ok(regex) = Regex::new(pattern) else {
+            println!(
+                "{}",
+                format!("Invalid placeholder pattern {pattern}, ignoring...").red()
+            );
+            continue;
+        };
+
+        for placeholder in regex.find_iter(body).map(|m| m.as_str()) {
+            if ignored.contains(placeholder) {
+                continue;
+            }
+
+            let snippet = shorten(placeholder);
+
+            if !found.contains(&snippet) {
+                found.push(snippet);
+            }
+        }
+    }
+
+    found
+}
+
+fn shorten(placeholder: &str) -> String {
+    let first_line = placeholder.lines().next().unwrap_or_default().trim();
+
+    if placeholder.trim() != first_line || first_line.chars().count() > MAX_SNIPPET_LENGTH {
+        let start: String = first_line.chars().take(MAX_SNIPPET_LENGTH).collect();
+        format!("{start}...")
+    } else {
+        first_line.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_test() {
+        let body = "Adds login\nTODO: screenshots\n<!-- fill this in -->\nOwner: {{ owner }}\n<!--\nguide\n-->";
+
+        assert_eq!(
+            find(body, None, "<!--\nguide\n-->"),
+            ["TODO", "<!-- fill this in -->", "{{ owner }}"]
+        );
+        assert_eq!(
+            find(body, Some(&[r"fill this".to_owned()]), ""),
+            ["fill this"]
+        );
+        assert!(find("Adds login", None, "").is_empty());
+    }
+
+    #[test]
+    fn shorten_test() {
+        assert_eq!(shorten("<!--\nguide\n-->"), "<!--...");
+        assert_eq!(shorten("TODO"), "TODO");
+    }
+}
@@ -1,65 +1,307 @@
 use colored::Colorize;
-use octocrab::{models::pulls::PullRequest, Error::GitHub, Octocrab};
-use regex::Regex;
-use std::{fmt::Display, io, process::Command};
-
-use crate::flush_line;
-
-const YT_ISSUE_REGEX: &str = r"^\w+/([a-zA-Z\-\d+/]+)/\w+";
-const BASE_REGEX: &str = r":([\w-]+)/";
-const REPO_REGEX: &str = r"/([\w-]+)(.git)?$";
+use octocrab::{
+    models::{pulls::PullRequest, Milestone},
+    params::State,
+    Error::GitHub,
+    Octocrab,
+};
+use prmaker_core::template::{self, add_issue_prefix, link_issues};
+use std::{fmt::Display, fs, path::Path, process::exit};
+
+use crate::{
+    base_branch, body_generator, checklist,
+    cli::CreateArgs,
+    compare,
+    config::Config,
+    confirm, confirm_or,
+    contributors::{self, Contributor},
+    conventional, dco, diff, editor, flush_line, git, gitmoji,
+    interrupt::Draft,
+    issues, large_files,
+    late_vars::{self, LateVars},
+    network::with_timeout,
+    placeholders,
+    projects::{self, Project},
+    protection::{self, Protection},
+    read_input,
+    related::{self, RelatedPR},
+    secrets, title_transform,
+};
+
+pub use prmaker_core::{
+    remote::parse_remote,
+    template::{replace_generated, DEFAULT_TEMPLATE, GENERATED_END, GENERATED_START},
+};
+
+pub const DEFAULT_BODY: &str = "Title";
 
 pub struct PR {
     pub branch: String,
     pub title: String,
-    pub yt_issue: String,
+    pub yt_issues: Vec<String>,
     pub body: String,
     pub full_body: String,
     pub link: Option<String>,
+    pub head_sha: Option<String>,
     pub number: Option<u64>,
     pub base: String,
     pub repo: String,
+    pub base_branch: String,
+    pub contributors: Vec<Contributor>,
+    pub protection: Option<Protection>,
+    pub projects: Vec<Project>,
+    pub related: Vec<RelatedPR>,
+    applied_prefix: String,
 }
 
 impl PR {
-    pub fn build() -> Self {
-        let remote_url = get_remote_url();
+    pub async fn build(config: &Config, args: &CreateArgs, octocrab: &Octocrab) -> Self {
+        let related = related::resolve_or_exit(octocrab, &args.related).await;
+        let remote_url = git::remote_url();
         let base = get_base(&remote_url);
         let repo = get_repo(&remote_url);
-        let current_branch = get_current_branch();
+        let current_branch = match &args.head {
+            Some(head) => {
+                fetch_remote_branch(head);
+                head.clone()
+            }
+            None => git::current_branch(),
+        };
+        let configured_base = args.base.as_deref().or(config.base_branch());
+        let base_branch = base_branch::resolve(octocrab, &base, &repo, configured_base).await;
+
+        let (has_commits, protection) = tokio::join!(
+            ensure_has_commits(octocrab, &base, &repo, &base_branch, &current_branch),
+            protection::get_protection(octocrab, &base, &repo, &base_branch)
+        );
+
+        if has_commits.is_err() {
+            exit(1)
+        }
+
+        warn_about_conflicts(&base_branch);
+        secrets::scan(&base_branch, config.secret_scan);
+        large_files::warn(&base_branch, config.large_file_kb);
+
+        if config.require_sign_off {
+            dco::ensure_signed_off(&base_branch, &current_branch);
+        }
+
+        if protection.as_ref().is_some_and(|p| p.requires_signatures()) {
+            warn_about_unverified_commits(octocrab, &base, &repo, &base_branch, &current_branch)
+                .await;
+        }
+
+        let toplevel = git::toplevel();
+        let changed_files = git::changed_files(&format!("origin/{base_branch}...{}", git::head()));
+        let projects =
+            projects::get_touched_projects(&config.projects, &changed_files, Path::new(&toplevel));
+
+        let title = default_title(projects::get_scope(&projects).as_deref());
+        let contributors = contributors::get_contributors(&base_branch);
 
+        let mut pr = PR {
+            branch: current_branch,
+            title,
+            yt_issues: args.issues.clone(),
+            body: DEFAULT_BODY.to_owned(),
+            full_body: String::new(),
+            base,
+            repo,
+            base_branch,
+            contributors,
+            protection,
+            projects,
+            related,
+            link: None,
+            head_sha: None,
+            number: None,
+            applied_prefix: String::new(),
+        };
+
+        pr.refresh(config);
+        pr
+    }
+
+    pub fn ask_title(&mut self, config: &Config) {
         println!();
 
-        let title = get_pr_title();
-        let yt_issue = get_yt_issue(&current_branch);
-        let body = get_pr_body();
-        let full_body = build_full_pr_body(&body, &yt_issue);
+        self.title = get_pr_title(&self.title);
+        self.title = title_transform::preview(&self.title, &config.title_transforms);
+
+        if config.gitmoji {
+            self.title = gitmoji::pick(&self.title);
+        }
+
+        self.refresh(config);
+    }
+
+    pub fn ask_issues(&mut self, config: &Config) {
+        self.yt_issues = issues::get_yt_issues(&self.branch, &self.base_branch);
+        self.refresh(config);
+    }
+
+    pub fn ask_body(&mut self, config: &Config) {
+        self.body = get_pr_body(config, &self.base_branch);
+        self.refresh(config);
+    }
+
+    pub fn restore(&mut self, title: &str, body: &str, config: &Config) {
+        title.clone_into(&mut self.title);
+        body.clone_into(&mut self.body);
+        self.refresh(config);
+    }
+
+    pub fn restore_with_template(
+        &mut self,
+        title: &str,
+        body: &str,
+        config: &Config,
+        template: &str,
+        toplevel: &Path,
+    ) {
+        title.clone_into(&mut self.title);
+        body.clone_into(&mut self.body);
+        self.refresh_with_template(config, template, toplevel);
+    }
+
+    pub fn draft(&self) -> Draft {
+        Draft {
+            branch: self.branch.clone(),
+            title: self.title.clone(),
+            body: self.body.clone(),
+        }
+    }
+
+    pub fn edit_body(&mut self, config: &Config) {
+        match editor::edit(&self.body) {
+            Ok(body) => {
+                self.body = body;
+                self.refresh(config);
+            }
+            Err(err) => {
+                println!("{}", "Couldn't edit the body".red());
+                println!("{err}");
+            }
+        }
+    }
+
+    pub fn placeholders(&self, config: &Config) -> Vec<String> {
+        let body = self
+            .full_body
+            .replace(GENERATED_START, "")
+            .replace(GENERATED_END, "");
+
+        placeholders::find(
+            &late_vars::strip(&body, &config.late_vars),
+            config.placeholders.as_deref(),
+            DEFAULT_TEMPLATE,
+        )
+    }
+
+    pub fn template_diff(&self) -> Option<String> {
+        if self.body == DEFAULT_BODY {
+            return None;
+        }
+
+        let template = get_template(&self.projects, Path::new(&git::toplevel()));
+        let submitted = self
+            .full_body
+            .lines()
+            .filter(|line| *line != GENERATED_START && *line != GENERATED_END)
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        Some(diff::render(&template, &submitted))
+    }
+
+    fn refresh(&mut self, config: &Config) {
+        let toplevel = git::toplevel();
+        let template = get_template(&self.projects, Path::new(&toplevel));
+
+        self.refresh_with_template(config, &template, Path::new(&toplevel));
+    }
+
+    fn refresh_with_template(&mut self, config: &Config, template: &str, toplevel: &Path) {
+        if let Some(format) = &config.title_prefix {
+            let issues = self.yt_issues.join(", ");
+            let title = self
+                .title
+                .strip_prefix(&self.applied_prefix)
+                .unwrap_or(&self.title)
+                .to_owned();
+            let prefix = match issues.is_empty() {
+                true => String::new(),
+                false => format.replace("{issue}", &issues),
+            };
+
+            self.title = add_issue_prefix(&title, &issues, format);
+            self.applied_prefix = match self.title.starts_with(&prefix) {
+                true => prefix,
+                false => String::new(),
+            };
+        }
 
+        self.full_body = render_full_body(
+            config,
+            template,
+            toplevel,
+            &self.projects,
+            &self.body,
+            &self.yt_issues,
+            &self.contributors,
+        );
+
+        if let Some(section) = related::build_section(&self.related) {
+            self.full_body = format!("{}\n{section}", self.full_body);
+        }
+    }
+
+    pub async fn ask_related(&mut self, octocrab: &Octocrab, config: &Config) {
+        related::ask(octocrab, &mut self.related).await;
+        self.refresh(config);
+    }
+
+    pub fn new(
+        base: String,
+        repo: String,
+        branch: String,
+        base_branch: String,
+        title: String,
+        body: String,
+    ) -> Self {
         PR {
-            branch: current_branch,
+            branch,
             title,
-            yt_issue,
-            body,
-            full_body,
+            yt_issues: vec![],
+            body: body.clone(),
+            full_body: body,
             base,
             repo,
+            base_branch,
+            contributors: vec![],
+            protection: None,
+            projects: vec![],
+            related: vec![],
             link: None,
+            head_sha: None,
             number: None,
+            applied_prefix: String::new(),
         }
     }
 
     pub async fn create(&mut self, octocrab: &Octocrab) -> Result<(), ()> {
-        let pr_resp = octocrab
+        let pr_resp = with_timeout!(octocrab
             .pulls(&self.base, &self.repo)
-            .create(&self.title, &self.branch, "next")
+            .create(&self.title, &self.branch, &self.base_branch)
             .body(&self.full_body)
-            .send()
-            .await;
+            .send());
 
         match pr_resp {
             Ok(github_pr) => {
                 self.number = Some(github_pr.number);
                 self.link = Some(get_pr_link(&github_pr));
+                self.head_sha = Some(github_pr.head.sha.clone());
 
                 print!("\n{}", "PR created successfully: ".green());
                 println!("{}", self.link.as_ref().unwrap());
@@ -76,162 +318,412 @@ impl PR {
         }
     }
 
-    pub async fn assign_self(&self, octocrab: &Octocrab, user: &str) {
-        let assign_resp = octocrab
+    pub async fn patch_late_vars(&mut self, octocrab: &Octocrab, custom: &LateVars) {
+        let (Some(number), Some(link), Some(head_sha)) = (self.number, &self.link, &self.head_sha)
+        else {
+            return;
+        };
+
+        let created = late_vars::Created {
+            number,
+            link,
+            head_sha,
+        };
+
+        let Some(body) = late_vars::substitute(&self.full_body, custom, &created) else {
+            return;
+        };
+
+        let update_resp = with_timeout!(octocrab
+            .pulls(&self.base, &self.repo)
+            .update(number)
+            .body(&body)
+            .send());
+
+        match update_resp {
+            Ok(_) => self.full_body = body,
+            Err(_) => println!(
+                "\n{}",
+                "Error when filling in the PR number in the body".red()
+            ),
+        }
+    }
+
+    pub async fn assign_self(&self, octocrab: &Octocrab, user: &str) -> bool {
+        self.assign(octocrab, &[user.to_owned()]).await
+    }
+
+    pub async fn assign(&self, octocrab: &Octocrab, logins: &[String]) -> bool {
+        let logins: Vec<&str> = logins.iter().map(|l| l.as_str()).collect();
+        let assign_resp = with_timeout!(octocrab
             .issues(&self.base, &self.repo)
-            .add_assignees(self.number.unwrap(), &[&user])
-            .await;
+            .add_assignees(self.number.unwrap(), &logins));
 
         match assign_resp {
             Ok(_) => println!("\n{}", "Assigned successfully".green()),
             Err(_) => println!("\n{}", "Error when assigning".red()),
         }
+
+        assign_resp.is_ok()
+    }
+
+    pub async fn credit_co_authors(&self, octocrab: &Octocrab, user: &str) {
+        let logins: Vec<String> = self
+            .contributors
+            .iter()
+            .filter(|c| c.co_author)
+            .filter_map(|c| c.login())
+            .filter(|login| login != user)
+            .collect();
+
+        if logins.is_empty() {
+            return;
+        }
+
+        let names = logins.join(", ");
+
+        if confirm(&format!("Request co-authors {names} as reviewers?")) {
+            let reviews_resp = with_timeout!(octocrab
+                .pulls(&self.base, &self.repo)
+                .request_reviews(self.number.unwrap(), logins.clone(), []));
+
+            match reviews_resp {
+                Ok(_) => println!("\n{}", "Co-authors requested successfully".green()),
+                Err(_) => println!("\n{}", "Failed to request co-authors".red()),
+            }
+        }
+
+        if confirm(&format!("Assign co-authors {names}?")) {
+            let logins: Vec<&str> = logins.iter().map(|l| l.as_str()).collect();
+            let assign_resp = with_timeout!(octocrab
+                .issues(&self.base, &self.repo)
+                .add_assignees(self.number.unwrap(), &logins));
+
+            match assign_resp {
+                Ok(_) => println!("\n{}", "Co-authors assigned successfully".green()),
+                Err(_) => println!("\n{}", "Error when assigning co-authors".red()),
+            }
+        }
+    }
+
+    pub fn suggested_reviewers(&self) -> Vec<String> {
+        let mut reviewers: Vec<String> = vec![];
+
+        for reviewer in self.projects.iter().flat_map(|p| &p.reviewers) {
+            if !reviewers.contains(reviewer) {
+                reviewers.push(reviewer.clone());
+            }
+        }
+
+        reviewers
+    }
+
+    pub fn project_labels(&self) -> Vec<String> {
+        let mut labels: Vec<String> = vec![];
+
+        for label in self.projects.iter().flat_map(|p| &p.labels) {
+            if !labels.contains(label) {
+                labels.push(label.clone());
+            }
+        }
+
+        labels
+    }
+
+    pub async fn add_labels(&self, octocrab: &Octocrab, labels: &[String]) -> bool {
+        if labels.is_empty() {
+            return true;
+        }
+
+        let labels_resp = with_timeout!(octocrab
+            .issues(&self.base, &self.repo)
+            .add_labels(self.number.unwrap(), labels));
+
+        match labels_resp {
+            Ok(_) => println!("\n{}", "Labels added successfully".green()),
+            Err(_) => println!("\n{}", "Error when adding labels".red()),
+        }
+
+        labels_resp.is_ok()
+    }
+
+    pub async fn set_milestone(&self, octocrab: &Octocrab, milestone: &Milestone) {
+        let milestone_resp = with_timeout!(octocrab
+            .issues(&self.base, &self.repo)
+            .update(self.number.unwrap())
+            .milestone(milestone.number as u64)
+            .send());
+
+        match milestone_resp {
+            Ok(_) => println!("\n{}", "Milestone set successfully".green()),
+            Err(_) => println!("\n{}", "Error when setting milestone".red()),
+        }
     }
 }
 
 impl Display for PR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let result = format!(
+        let mut result = format!(
             "\
 Title: {}
 Body: {}
 Youtrack issue: {}
 Remote branch: {}
+Base branch: {}
 Remote: {}",
             self.title.cyan(),
             self.body.cyan(),
-            self.yt_issue.cyan(),
+            self.yt_issues.join(", ").cyan(),
             self.branch.cyan(),
+            self.base_branch.cyan(),
             format!("{}/{}", self.base, self.repo).cyan()
         );
 
+        if !self.projects.is_empty() {
+            let paths: Vec<&str> = self.projects.iter().map(|p| p.path.as_str()).collect();
+            result.push_str(&format!("\nProjects: {}", paths.join(", ").cyan()));
+        }
+
+        if !self.related.is_empty() {
+            let references: Vec<&str> = self.related.iter().map(|r| r.reference.as_str()).collect();
+            result.push_str(&format!("\nRelated PRs: {}", references.join(", ").cyan()));
+        }
+
+        if self.contributors.len() > 1 {
+            let names: Vec<String> = self.contributors.iter().map(|c| c.to_string()).collect();
+            result.push_str(&format!("\nContributors: {}", names.join(", ").cyan()));
+        }
+
+        if let Some(protection) = &self.protection {
+            let summary = protection.summary();
+
+            if !summary.is_empty() {
+                result.push_str(&format!(
+                    "\nBranch protection: {}",
+                    summary.join("; ").cyan()
+                ));
+            }
+
+            if let Some(warning) = protection.title_warning(&self.title) {
+                result.push_str(&format!("\n{}", warning.red()));
+            }
+        }
+
         write!(f, "{}", result)
     }
 }
 
-fn get_remote_url() -> String {
-    let stdout = Command::new("git")
-        .args(["config", "--get", "remote.origin.url"])
-        .output()
-        .expect("failed to run `git config --get remote.origin.url`")
-        .stdout;
+pub async fn find_open_pr(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    branch: &str,
+) -> octocrab::Result<Option<PullRequest>> {
+    let pulls = with_timeout!(octocrab
+        .pulls(base, repo)
+        .list()
+        .head(format!("{base}:{branch}"))
+        .state(State::Open)
+        .send())?;
+
+    Ok(pulls.into_iter().next())
+}
 
-    let remote_url = String::from_utf8(stdout).unwrap();
+pub async fn get_branch_pr(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    number: Option<u64>,
+) -> PullRequest {
+    if let Some(number) = number {
+        return with_timeout!(octocrab.pulls(base, repo).get(number)).unwrap_or_else(|_| {
+            println!("{}", format!("Couldn't fetch PR #{number}").red());
+            exit(1);
+        });
+    }
 
-    remote_url.trim().to_owned()
-}
+    let branch = git::current_branch();
 
-fn get_base(remote_url: &str) -> String {
-    let err = format!("Failed to get the user/org name from remote url: {remote_url}");
-    let re = Regex::new(BASE_REGEX).unwrap();
+    match find_open_pr(octocrab, base, repo, &branch).await {
+        Ok(Some(open_pr)) => open_pr,
+        Ok(None) => {
+            println!("{}", format!("No open PR found for branch {branch}").red());
+            exit(1);
+        }
+        Err(_) => {
+            println!("{}", "Couldn't list the open PRs".red());
+            exit(1);
+        }
+    }
+}
 
-    re.captures(remote_url)
-        .expect(&err)
-        .get(1)
-        .expect(&err)
-        .as_str()
-        .to_owned()
+pub fn get_base(remote_url: &str) -> String {
+    parse_remote(remote_url)
+        .unwrap_or_else(|| panic!("Failed to get the user/org name from remote url: {remote_url}"))
+        .0
 }
 
-fn get_repo(remote_url: &str) -> String {
-    let err = format!("Failed to get the repo name from remote url: {remote_url}");
-    let re = Regex::new(REPO_REGEX).unwrap();
+pub fn get_repo(remote_url: &str) -> String {
+    parse_remote(remote_url)
+        .unwrap_or_else(|| panic!("Failed to get the repo name from remote url: {remote_url}"))
+        .1
+}
 
-    re.captures(remote_url)
-        .expect(&err)
-        .get(1)
-        .expect(&err)
-        .as_str()
-        .to_owned()
+async fn ensure_has_commits(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    base_branch: &str,
+    branch: &str,
+) -> Result<(), ()> {
+    let comparison = compare::compare(octocrab, base, repo, base_branch, branch).await;
+
+    match comparison {
+        Ok(comparison) => match compare::get_empty_pr_reason(&comparison, base_branch, branch) {
+            Some(reason) => {
+                println!("\n{}", reason.red());
+                Err(())
+            }
+            None => Ok(()),
+        },
+        Err(_) => {
+            println!(
+                "\n{}",
+                format!(
+                    "Couldn't compare {branch} with {base_branch}, make sure the branch is pushed"
+                )
+                .red()
+            );
+            Ok(())
+        }
+    }
 }
 
-fn get_current_branch() -> String {
-    let stdout = Command::new("git")
-        .args(["branch", "--show-current"])
-        .output()
-        .expect("failed to run `git branch --show-current`")
-        .stdout;
+async fn warn_about_unverified_commits(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    base_branch: &str,
+    branch: &str,
+) {
+    let Ok(comparison) = compare::compare(octocrab, base, repo, base_branch, branch).await else {
+        return;
+    };
+
+    let unverified = comparison.unverified_commits();
+
+    if unverified.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{}",
+        format!("{base_branch} requires signed commits, but these aren't verified:").red()
+    );
 
-    let current_branch = String::from_utf8(stdout).unwrap();
+    for commit in unverified {
+        println!("  {}", commit.yellow());
+    }
 
-    current_branch.trim().to_owned()
+    println!(
+        "Sign them with {} and push again",
+        format!("git rebase --exec 'git commit --amend --no-edit -S' origin/{base_branch}").cyan()
+    );
+
+    if !confirm_or("Continue anyway?", false) {
+        println!("\nClosing...");
+        exit(0);
+    }
+}
+
+fn fetch_remote_branch(branch: &str) {
+    if git::try_git(&["fetch", "--quiet", "origin", branch]).is_err() {
+        println!(
+            "{}",
+            format!("Branch {branch} doesn't exist on the remote, push it first").red()
+        );
+        exit(1);
+    }
+
+    git::set_head(branch);
 }
 
-fn get_yt_issue(branch: &str) -> String {
-    match get_yt_issue_from_branch_name(branch) {
-        Some(yt_issue) => yt_issue,
-        None => {
+fn warn_about_conflicts(base_branch: &str) {
+    let upstream = format!("origin/{base_branch}");
+
+    let _ = git::try_git(&["fetch", "--quiet", "origin", base_branch]);
+
+    let conflicts = match git::merge_conflicts(&upstream, git::head()) {
+        Ok(conflicts) => conflicts,
+        Err(_) => {
             println!(
                 "\n{}",
-                "Couldn't get Youtrack issue from branch name. Please provide one or leave it empty".red()
+                format!("Couldn't check for conflicts with {upstream}, ignoring...").red()
             );
-            request_yt_issue()
+            return;
         }
-    }
-}
+    };
 
-fn get_yt_issue_from_branch_name(branch: &str) -> Option<String> {
-    let re = Regex::new(YT_ISSUE_REGEX).unwrap();
-    let issues = re
-        .captures(branch)?
-        .get(1)?
-        .as_str()
-        .split('/')
-        .collect::<Vec<&str>>()
-        .join(", ");
-
-    Some(issues.to_owned())
-}
+    if conflicts.is_empty() {
+        return;
+    }
 
-fn request_yt_issue() -> String {
-    let mut issue = String::new();
+    println!(
+        "\n{}",
+        format!("This branch conflicts with {upstream} in:").red()
+    );
 
-    print!("Youtrack issue: ");
-    flush_line();
+    for file in conflicts {
+        println!("  {}", file.yellow());
+    }
 
-    io::stdin().read_line(&mut issue).unwrap();
+    println!(
+        "Run {} to resolve them before opening the PR",
+        "prmaker sync".cyan()
+    );
 
-    issue.trim().to_owned()
+    if !confirm_or("Continue anyway?", false) {
+        println!("\nClosing...");
+        exit(0);
+    }
 }
 
-fn get_pr_title() -> String {
-    let last_commit = get_last_commit();
-    let mut pr_title = String::new();
+fn default_title(scope: Option<&str>) -> String {
+    match scope {
+        Some(scope) => conventional::add_scope(&git::last_commit(), scope),
+        None => git::last_commit(),
+    }
+}
 
-    println!("PR title: {}", last_commit.purple());
+pub fn get_pr_title(default_title: &str) -> String {
+    println!("PR title: {}", default_title.purple());
     print!("Leave it blank to use the title above or digit a new one: ");
     flush_line();
 
-    io::stdin().read_line(&mut pr_title).unwrap();
+    let pr_title = read_input();
 
     if pr_title.trim().is_empty() {
-        last_commit.to_string()
+        default_title.to_owned()
     } else {
         pr_title.trim().to_owned()
     }
 }
 
-fn get_last_commit() -> String {
-    let stdout = Command::new("git")
-        .args(["log", "-1", "--pretty=format:%s"])
-        .output()
-        .expect("failed to run `git log -1 --pretty=%s`")
-        .stdout;
-
-    let current_branch = String::from_utf8(stdout).unwrap();
-
-    current_branch.trim().to_owned()
+pub fn default_body(config: &Config, base_branch: &str) -> String {
+    match &config.body_generator {
+        Some(command) => generate_pr_body(command, base_branch),
+        None => DEFAULT_BODY.to_owned(),
+    }
 }
 
-fn get_pr_body() -> String {
-    let default_body = "Title".to_owned();
-    let mut pr_body = String::new();
+pub fn get_pr_body(config: &Config, base_branch: &str) -> String {
+    let default_body = default_body(config, base_branch);
 
     println!("\nPR body: {}", default_body.purple());
     print!("Leave it blank to use the body above or digit a new one: ");
     flush_line();
 
-    io::stdin().read_line(&mut pr_body).unwrap();
+    let pr_body = read_input();
 
     if pr_body.trim().is_empty() {
         default_body
@@ -240,16 +732,121 @@ fn get_pr_body() -> String {
     }
 }
 
-fn build_full_pr_body(body: &str, issue: &str) -> String {
-    let template = include_str!("../pull_request_template.md");
+fn generate_pr_body(command: &str, base_branch: &str) -> String {
+    println!("\nGenerating PR body with `{command}`...");
+
+    let body = match body_generator::generate(command, base_branch) {
+        Ok(body) => body,
+        Err(err) => {
+            println!(
+                "{}",
+                "Couldn't generate the PR body, using the default one".red()
+            );
+            println!("{err}");
+            return DEFAULT_BODY.to_owned();
+        }
+    };
+
+    println!("\n{body}");
+
+    if !confirm_or("Edit the generated body?", false) {
+        return body;
+    }
+
+    editor::edit(&body).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            "Couldn't edit the body, using the generated one".red()
+        );
+        println!("{err}");
+        body
+    })
+}
+
+pub fn build_full_body(
+    config: &Config,
+    projects: &[Project],
+    body: &str,
+    yt_issues: &[String],
+    contributors: &[Contributor],
+) -> String {
+    let toplevel = git::toplevel();
+    let template = get_template(projects, Path::new(&toplevel));
+
+    render_full_body(
+        config,
+        &template,
+        Path::new(&toplevel),
+        projects,
+        body,
+        yt_issues,
+        contributors,
+    )
+}
+
+pub fn render_full_body(
+    config: &Config,
+    template: &str,
+    toplevel: &Path,
+    projects: &[Project],
+    body: &str,
+    yt_issues: &[String],
+    contributors: &[Contributor],
+) -> String {
+    let issue = match &config.issue_url {
+        Some(issue_url) => link_issues(yt_issues, issue_url),
+        None => yt_issues.join(", "),
+    };
+    let checklist = checklist::get_checklist(&config.checklists, projects, toplevel);
+    let checklist_section = checklist::build_section(&checklist).unwrap_or_default();
+    let mut full_body = template::render(template, body, &issue);
+
+    if full_body.contains("{checklist}") {
+        full_body = full_body.replace("{checklist}", &checklist_section);
+    } else if !checklist_section.is_empty() {
+        full_body = format!("{full_body}\n{checklist_section}");
+    }
+
+    if let Some(section) = contributors::build_contributors_section(contributors) {
+        full_body = format!("{full_body}\n{section}");
+    }
+
+    if config.require_sign_off {
+        if let Some(note) = dco::sign_off_note() {
+            full_body = format!("{full_body}\n{note}\n");
+        }
+    }
+
+    format!("{GENERATED_START}\n{full_body}\n{GENERATED_END}\n")
+}
+
+fn get_template(projects: &[Project], toplevel: &Path) -> String {
+    let (template, warning) = read_template(projects, toplevel);
+
+    if let Some(warning) = warning {
+        println!("\n{}", warning.red());
+    }
 
     template
-        .replace("{body}", body)
-        .replace("{issue}", issue)
-        .to_owned()
 }
 
-fn get_pr_link(pr: &PullRequest) -> String {
+pub fn read_template(projects: &[Project], toplevel: &Path) -> (String, Option<String>) {
+    let Some(path) = projects.iter().find_map(|p| p.template.as_ref()) else {
+        return (DEFAULT_TEMPLATE.to_owned(), None);
+    };
+
+    match fs::read_to_string(toplevel.join(path)) {
+        Ok(template) => (template, None),
+        Err(_) => (
+            DEFAULT_TEMPLATE.to_owned(),
+            Some(format!(
+                "Couldn't read template {path}, using the default one"
+            )),
+        ),
+    }
+}
+
+pub fn get_pr_link(pr: &PullRequest) -> String {
     let html_url = pr.html_url.as_ref().unwrap();
 
     format!(
@@ -263,37 +860,115 @@ fn get_pr_link(pr: &PullRequest) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::{prelude::*, sample};
 
     #[test]
-    fn test_yt_issue_regex() {
-        let branches = [
-            "fix/CT-1111/fix-something",
-            "fix/CT-1111/CT-2222/CT-3333/another-fix",
-        ];
+    fn refresh_title_prefix_test() {
+        let config = Config {
+            title_prefix: Some("[{issue}] ".to_owned()),
+            ..Config::default()
+        };
+        let mut pr = PR::new(
+            "acme".to_owned(),
+            "api".to_owned(),
+            "feat/login".to_owned(),
+            "main".to_owned(),
+            "feat: add login".to_owned(),
+            DEFAULT_BODY.to_owned(),
+        );
 
-        let yt_issues = branches.map(|b| get_yt_issue_from_branch_name(b).unwrap());
+        pr.yt_issues = vec!["CT-1".to_owned()];
+        pr.refresh(&config);
+        assert_eq!("[CT-1] feat: add login", pr.title);
 
-        assert_eq!(yt_issues, ["CT-1111", "CT-1111, CT-2222, CT-3333"]);
-        assert_eq!(None, get_yt_issue_from_branch_name("fix/something"));
+        pr.yt_issues = vec!["CT-1".to_owned(), "CT-2".to_owned()];
+        pr.refresh(&config);
+        assert_eq!("[CT-1, CT-2] feat: add login", pr.title);
+
+        pr.yt_issues = vec![];
+        pr.refresh(&config);
+        assert_eq!("feat: add login", pr.title);
     }
 
     #[test]
-    fn build_full_pr_body_test() {
-        let expected = "### What does this PR do?
-
-Title
-
-<!--
-Please include a summary of the change and/or which issue is fixed. Please also include relevant motivation and context. List any dependencies that are required for this change, also provide (if appropriate) any evidence - screenshots, gifs, logs, etc.
-
-Oh, remember to follow conventional commits (https://conventionalcommits.org) on pull request title ;)
--->
+    fn review_screen_snapshot_test() {
+        let pr = PR {
+            branch: "feat/CT-1/login".to_owned(),
+            title: "[CT-1] feat(web): add login".to_owned(),
+            yt_issues: vec!["CT-1".to_owned()],
+            body: "Adds the login page".to_owned(),
+            full_body: String::new(),
+            related: vec![],
+            link: None,
+            head_sha: None,
+            number: None,
+            applied_prefix: "[CT-1] ".to_owned(),
+            base: "acme".to_owned(),
+            repo: "api".to_owned(),
+            base_branch: "main".to_owned(),
+            contributors: vec![
+                Contributor {
+                    name: "Jane".to_owned(),
+                    email: "jane@example.com".to_owned(),
+                    co_author: false,
+                },
+                Contributor {
+                    name: "John".to_owned(),
+                    email: "john@example.com".to_owned(),
+                    co_author: true,
+                },
+            ],
+            protection: None,
+            projects: vec![Project {
+                path: "web".to_owned(),
+                ..Project::default()
+            }],
+        };
+
+        insta::with_settings!({ filters => vec![(r"\x1b\[[0-9;]*m", "")] }, {
+            insta::assert_snapshot!("review_screen", pr.to_string());
+        });
+    }
 
----
+    #[test]
+    fn body_template_snapshot_test() {
+        let issue = link_issues(
+            &["CT-1".to_owned(), "CT-2".to_owned()],
+            "https://yt.example.com/issue/",
+        );
+        let body = template::render(DEFAULT_TEMPLATE, "Adds the login page", &issue);
+        let checklist = checklist::build_section(&["Log in with SSO".to_owned()]).unwrap();
 
-**Related issue:** CT-1111
-";
+        insta::assert_snapshot!(
+            "body_template",
+            format!("{GENERATED_START}\n{body}\n{checklist}\n{GENERATED_END}\n")
+        );
+    }
 
-        assert_eq!(expected, build_full_pr_body("Title", "CT-1111"));
+    proptest! {
+        #[test]
+        fn parse_generated_remotes_test(
+            base in "[a-zA-Z0-9][a-zA-Z0-9-]{0,11}",
+            repo in "[a-zA-Z0-9][a-zA-Z0-9_.-]{0,15}",
+            host in sample::select(vec!["github.com", "ghe.example.com", "git.corp.internal"]),
+            suffix in sample::select(vec!["", ".git", "/", ".git/"]),
+            port in sample::select(vec!["", ":7999"]),
+            credentials in sample::select(vec!["", "git@", "user:t0k3n@"]),
+            prefix in sample::select(vec!["", "scm/", "a/b/"]),
+            form in 0..3,
+        ) {
+            let repo = match repo.ends_with(".git") {
+                true => format!("{repo}x"),
+                false => repo,
+            };
+
+            let remote = match form {
+                0 => format!("git@{host}:{base}/{repo}{}", suffix.trim_end_matches('/')),
+                1 => format!("ssh://git@{host}{port}/{prefix}{base}/{repo}{suffix}"),
+                _ => format!("https://{credentials}{host}{port}/{prefix}{base}/{repo}{suffix}"),
+            };
+
+            prop_assert_eq!(Some((base, repo)), parse_remote(&remote), "{}", remote);
+        }
     }
 }
@@ -0,0 +1,100 @@
+use colored::Colorize;
+use serde::Deserialize;
+use std::{collections::BTreeMap, env, process::exit, sync::OnceLock};
+
+use crate::{git, pr, GITHUB_TOKEN_VAR, GITHUB_USER_VAR};
+
+pub use prmaker_core::remote::host;
+
+static ACTIVE: OnceLock<Profile> = OnceLock::new();
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub hosts: Vec<String>,
+    pub orgs: Vec<String>,
+    pub token: Option<String>,
+    pub user: Option<String>,
+    pub api_url: Option<String>,
+    pub base_branch: Option<String>,
+}
+
+pub type Profiles = BTreeMap<String, Profile>;
+
+pub fn activate(profiles: &Profiles, name: Option<&str>) {
+    let profile = match name {
+        Some(name) => profiles.get(name).unwrap_or_else(|| {
+            println!("{}", format!("Unknown profile {name}").red());
+            exit(1);
+        }),
+        None => match find_profile(profiles, &git::remote_url()) {
+            Some(profile) => profile,
+            None => return,
+        },
+    };
+
+    if let Some(token) = &profile.token {
+        env::set_var(GITHUB_TOKEN_VAR, token);
+    }
+
+    if let Some(user) = &profile.user {
+        env::set_var(GITHUB_USER_VAR, user);
+    }
+
+    let _ = ACTIVE.set(profile.clone());
+}
+
+pub fn select(profiles: &Profiles, remote_url: &str) -> Option<&'static Profile> {
+    if let Some(active) = active() {
+        return Some(active);
+    }
+
+    let profile = find_profile(profiles, remote_url)?;
+
+    Some(ACTIVE.get_or_init(|| profile.clone()))
+}
+
+pub fn active() -> Option<&'static Profile> {
+    ACTIVE.get()
+}
+
+fn find_profile<'a>(profiles: &'a Profiles, remote_url: &str) -> Option<&'a Profile> {
+    let host = host(remote_url)?;
+    let org = pr::parse_remote(remote_url).map(|(base, _)| base);
+
+    profiles.values().find(|profile| {
+        profile.hosts.contains(&host) || org.as_ref().is_some_and(|o| profile.orgs.contains(o))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_profile_test() {
+        let profiles: Profiles = toml::from_str(
+            r#"
+            work = { hosts = ["github.acme.com"], user = "jdoe" }
+            oss = { orgs = ["rust-lang"], user = "john" }
+            "#,
+        )
+        .unwrap();
+
+        let user = |remote_url: &str| find_profile(&profiles, remote_url)?.user.clone();
+
+        assert_eq!(
+            Some("jdoe".to_owned()),
+            user("git@github.acme.com:team/api.git")
+        );
+        assert_eq!(
+            Some("jdoe".to_owned()),
+            user("https://github.acme.com/team/api")
+        );
+        assert_eq!(
+            Some("john".to_owned()),
+            user("git@github.com:rust-lang/cargo.git")
+        );
+        assert_eq!(None, user("git@github.com:john/dotfiles.git"));
+    }
+}
@@ -0,0 +1,138 @@
+use serde::Deserialize;
+use std::path::Path;
+
+const MANIFESTS: [&str; 6] = [
+    "Cargo.toml",
+    "package.json",
+    "mix.exs",
+    "go.mod",
+    "pyproject.toml",
+    "pom.xml",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Project {
+    pub path: String,
+    pub scope: Option<String>,
+    pub template: Option<String>,
+    pub labels: Vec<String>,
+    pub reviewers: Vec<String>,
+}
+
+impl Project {
+    pub fn scope(&self) -> String {
+        self.scope.clone().unwrap_or_else(|| {
+            self.path
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_owned()
+        })
+    }
+
+    fn contains(&self, file: &str) -> bool {
+        let path = self.path.trim_end_matches('/');
+
+        file.strip_prefix(path)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+pub fn get_touched_projects(
+    configured: &[Project],
+    changed_files: &[String],
+    toplevel: &Path,
+) -> Vec<Project> {
+    if configured.is_empty() {
+        detect_projects(changed_files, |dir| {
+            MANIFESTS
+                .iter()
+                .any(|m| toplevel.join(dir).join(m).is_file())
+        })
+    } else {
+        configured
+            .iter()
+            .filter(|p| changed_files.iter().any(|f| p.contains(f)))
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn get_scope(projects: &[Project]) -> Option<String> {
+    match projects {
+        [project] => Some(project.scope()),
+        _ => None,
+    }
+}
+
+fn detect_projects(changed_files: &[String], has_manifest: impl Fn(&str) -> bool) -> Vec<Project> {
+    let mut projects: Vec<Project> = vec![];
+
+    for file in changed_files {
+        let dirs = file.match_indices('/').map(|(index, _)| &file[..index]);
+
+        if let Some(dir) = dirs.filter(|d| has_manifest(d)).last() {
+            if !projects.iter().any(|p| p.path == dir) {
+                projects.push(Project {
+                    path: dir.to_owned(),
+                    ..Project::default()
+                });
+            }
+        }
+    }
+
+    projects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(files: &[&str]) -> Vec<String> {
+        files.iter().map(|f| f.to_string()).collect()
+    }
+
+    #[test]
+    fn detect_projects_test() {
+        let changed = files(&[
+            "README.md",
+            "services/api/src/main.rs",
+            "services/api/Cargo.toml",
+            "apps/web/src/index.ts",
+            "apps/web/src/pages/home.ts",
+        ]);
+
+        let projects = detect_projects(&changed, |dir| dir == "services/api" || dir == "apps/web");
+        let paths: Vec<&str> = projects.iter().map(|p| p.path.as_str()).collect();
+
+        assert_eq!(paths, ["services/api", "apps/web"]);
+        assert_eq!("api", projects[0].scope());
+    }
+
+    #[test]
+    fn configured_projects_test() {
+        let configured = [
+            Project {
+                path: "services/api".to_owned(),
+                scope: Some("backend".to_owned()),
+                ..Project::default()
+            },
+            Project {
+                path: "services/api-docs".to_owned(),
+                ..Project::default()
+            },
+        ];
+
+        let touched = get_touched_projects(
+            &configured,
+            &files(&["services/api/src/lib.rs"]),
+            Path::new("/"),
+        );
+
+        assert_eq!(touched, configured[..1]);
+        assert_eq!(Some("backend".to_owned()), get_scope(&touched));
+        assert_eq!(None, get_scope(&configured));
+    }
+}
@@ -0,0 +1,144 @@
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::{conventional, network::with_timeout};
+
+const TITLE_CHECK_KEYWORDS: [&str; 4] = ["title", "semantic", "conventional", "pr-lint"];
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Protection {
+    required_status_checks: Option<StatusChecks>,
+    required_pull_request_reviews: Option<PullRequestReviews>,
+    required_linear_history: Option<Enabled>,
+    required_signatures: Option<Enabled>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct StatusChecks {
+    #[serde(default)]
+    contexts: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PullRequestReviews {
+    #[serde(default)]
+    required_approving_review_count: u64,
+    #[serde(default)]
+    require_code_owner_reviews: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Enabled {
+    enabled: bool,
+}
+
+impl Protection {
+    pub fn summary(&self) -> Vec<String> {
+        let mut summary = vec![];
+
+        if let Some(checks) = &self.required_status_checks {
+            if !checks.contexts.is_empty() {
+                summary.push(format!("Required checks: {}", checks.contexts.join(", ")));
+            }
+        }
+
+        if let Some(reviews) = &self.required_pull_request_reviews {
+            if reviews.required_approving_review_count > 0 {
+                summary.push(format!(
+                    "Required approvals: {}",
+                    reviews.required_approving_review_count
+                ));
+            }
+
+            if reviews.require_code_owner_reviews {
+                summary.push("Code owner review required".to_owned());
+            }
+        }
+
+        if self
+            .required_linear_history
+            .as_ref()
+            .is_some_and(|e| e.enabled)
+        {
+            summary.push("Linear history required".to_owned());
+        }
+
+        if self.requires_signatures() {
+            summary.push("Signed commits required".to_owned());
+        }
+
+        summary
+    }
+
+    pub fn requires_signatures(&self) -> bool {
+        self.required_signatures.as_ref().is_some_and(|e| e.enabled)
+    }
+
+    pub fn title_warning(&self, title: &str) -> Option<String> {
+        let checks = &self.required_status_checks.as_ref()?.contexts;
+        let title_check = checks.iter().find(|check| {
+            let check = check.to_lowercase();
+            TITLE_CHECK_KEYWORDS.iter().any(|k| check.contains(k))
+        })?;
+
+        if conventional::is_conventional(title) {
+            None
+        } else {
+            Some(format!(
+                "Required check `{title_check}` probably validates the title, which doesn't follow conventional commits"
+            ))
+        }
+    }
+}
+
+pub async fn get_protection(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    branch: &str,
+) -> Option<Protection> {
+    let route = format!("repos/{base}/{repo}/branches/{branch}/protection");
+
+    with_timeout!(octocrab.get(&route, None::<&()>)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protection() -> Protection {
+        serde_json::from_str(
+            r#"{
+                "required_status_checks": { "contexts": ["ci/test", "semantic-pr-title"] },
+                "required_pull_request_reviews": {
+                    "required_approving_review_count": 2,
+                    "require_code_owner_reviews": true
+                },
+                "required_linear_history": { "enabled": true },
+                "required_signatures": { "enabled": false }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn summary_test() {
+        assert_eq!(
+            protection().summary(),
+            [
+                "Required checks: ci/test, semantic-pr-title",
+                "Required approvals: 2",
+                "Code owner review required",
+                "Linear history required",
+            ]
+        );
+        assert!(Protection::default().summary().is_empty());
+    }
+
+    #[test]
+    fn title_warning_test() {
+        assert_eq!(None, protection().title_warning("feat: add protection"));
+        assert!(protection().title_warning("Add protection").is_some());
+        assert_eq!(None, Protection::default().title_warning("Add protection"));
+    }
+}
@@ -0,0 +1,81 @@
+use colored::Colorize;
+
+use crate::{flush_line, read_input};
+
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Retry,
+    Skip,
+    Edit,
+}
+
+pub fn ask(step: &str) -> Action {
+    loop {
+        print!(
+            "\n{}",
+            format!("{step} failed, (r)etry, (s)kip or (e)dit it? (empty to skip): ").yellow()
+        );
+        flush_line();
+
+        match parse_action(&read_input()) {
+            Some(action) => return action,
+            None => println!("{}", "Invalid option".red()),
+        }
+    }
+}
+
+pub fn edit(name: &str, current: &[String]) -> Vec<String> {
+    print!(
+        "\n{}",
+        format!(
+            "{name}, comma separated (empty to keep {}): ",
+            current.join(", ")
+        )
+        .yellow()
+    );
+    flush_line();
+
+    let input = read_input();
+
+    if input.trim().is_empty() {
+        return current.to_vec();
+    }
+
+    split_list(&input)
+}
+
+fn parse_action(input: &str) -> Option<Action> {
+    match input.trim().to_lowercase().as_str() {
+        "" | "s" | "skip" => Some(Action::Skip),
+        "r" | "retry" => Some(Action::Retry),
+        "e" | "edit" => Some(Action::Edit),
+        _ => None,
+    }
+}
+
+fn split_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_action_test() {
+        assert_eq!(Some(Action::Skip), parse_action("\n"));
+        assert_eq!(Some(Action::Retry), parse_action(" R\n"));
+        assert_eq!(Some(Action::Edit), parse_action("edit"));
+        assert_eq!(None, parse_action("abort"));
+    }
+
+    #[test]
+    fn split_list_test() {
+        assert_eq!(split_list(" bug, web ,,\n"), ["bug", "web"]);
+    }
+}
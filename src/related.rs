@@ -0,0 +1,131 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use regex::Regex;
+use std::{process::exit, sync::LazyLock};
+
+use crate::{flush_line, network::with_timeout, read_input};
+
+static REFERENCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([\w.-]+)/([\w.-]+)#(\d+)$").unwrap());
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedPR {
+    pub reference: String,
+    pub title: String,
+}
+
+pub async fn resolve_or_exit(octocrab: &Octocrab, references: &[String]) -> Vec<RelatedPR> {
+    let mut related = vec![];
+
+    for reference in references {
+        match resolve(octocrab, reference).await {
+            Ok(pr) => related.push(pr),
+            Err(err) => {
+                println!("{}", err.red());
+                exit(1);
+            }
+        }
+    }
+
+    related
+}
+
+pub async fn ask(octocrab: &Octocrab, related: &mut Vec<RelatedPR>) {
+    loop {
+        if !related.is_empty() {
+            println!("\n{}", "** Related PRs **".blue());
+
+            for pr in related.iter() {
+                println!("{}: {}", pr.reference.cyan(), pr.title);
+            }
+        }
+
+        print!(
+            "\n{}",
+            "Add a related PR as org/repo#123 (empty to proceed): ".yellow()
+        );
+        flush_line();
+
+        let opt = read_input();
+        let reference = opt.trim();
+
+        if reference.is_empty() {
+            break;
+        }
+
+        if related.iter().any(|r| r.reference == reference) {
+            related.retain(|r| r.reference != reference);
+            continue;
+        }
+
+        match resolve(octocrab, reference).await {
+            Ok(pr) => related.push(pr),
+            Err(err) => println!("{}", err.red()),
+        }
+    }
+}
+
+pub fn build_section(related: &[RelatedPR]) -> Option<String> {
+    if related.is_empty() {
+        return None;
+    }
+
+    let entries: Vec<String> = related
+        .iter()
+        .map(|r| format!("- {}: {}", r.reference, r.title))
+        .collect();
+
+    Some(format!("### Related PRs\n\n{}\n", entries.join("\n")))
+}
+
+async fn resolve(octocrab: &Octocrab, reference: &str) -> Result<RelatedPR, String> {
+    let (owner, repo, number) = parse_reference(reference)
+        .ok_or_else(|| format!("Invalid PR reference {reference}, use org/repo#123"))?;
+
+    let pr = with_timeout!(octocrab.pulls(&owner, &repo).get(number))
+        .map_err(|_| format!("Couldn't find PR {reference}"))?;
+
+    Ok(RelatedPR {
+        reference: reference.to_owned(),
+        title: pr.title.unwrap_or_default(),
+    })
+}
+
+fn parse_reference(reference: &str) -> Option<(String, String, u64)> {
+    let captures = REFERENCE_REGEX.captures(reference)?;
+
+    Some((
+        captures[1].to_owned(),
+        captures[2].to_owned(),
+        captures[3].parse().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reference_test() {
+        assert_eq!(
+            Some(("acme".to_owned(), "web.app".to_owned(), 123)),
+            parse_reference("acme/web.app#123")
+        );
+        assert_eq!(None, parse_reference("acme/web"));
+        assert_eq!(None, parse_reference("#123"));
+    }
+
+    #[test]
+    fn build_section_test() {
+        let related = [RelatedPR {
+            reference: "acme/web#12".to_owned(),
+            title: "feat: show the login page".to_owned(),
+        }];
+
+        assert_eq!(None, build_section(&[]));
+        assert_eq!(
+            Some("### Related PRs\n\n- acme/web#12: feat: show the login page\n".to_owned()),
+            build_section(&related)
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use colored::Colorize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+    sync::{Arc, LazyLock, Mutex},
+    time::Instant,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::audit;
+
+pub const GITHUB_API_URL: &str = "https://api.github.com";
+const FORWARDED_HEADERS: [&str; 3] = ["authorization", "accept", "content-type"];
+const RECORDED_HEADERS: [&str; 3] = ["content-type", "link", "x-oauth-scopes"];
+
+static PROXY_URL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"http://127\.0\.0\.1:\d+/").unwrap());
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<String>,
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+pub enum Mode {
+    Record { upstream: String, fixtures: PathBuf },
+    Replay { fixtures: PathBuf },
+    Forward { upstream: String },
+}
+
+struct Request {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+struct State {
+    mode: Mode,
+    address: String,
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+pub async fn start(mode: Mode) -> String {
+    let interactions = match &mode {
+        Mode::Replay { fixtures } => load(fixtures),
+        Mode::Record { .. } | Mode::Forward { .. } => vec![],
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap_or_else(|err| {
+            println!("{}", "Couldn't start the replay server".red());
+            println!("{err}");
+            exit(1);
+        });
+    let address = format!("http://{}/", listener.local_addr().unwrap());
+
+    let state = Arc::new(Mutex::new(State {
+        mode,
+        address: address.clone(),
+        used: vec![false; interactions.len()],
+        interactions,
+    }));
+
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            tokio::spawn(handle(socket, state.clone()));
+        }
+    });
+
+    address
+}
+
+fn load(fixtures: &Path) -> Vec<Interaction> {
+    let content = fs::read_to_string(fixtures).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Couldn't read fixtures {}", fixtures.display()).red()
+        );
+        println!("{err}");
+        exit(1);
+    });
+
+    serde_json::from_str(&content).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Invalid fixtures {}", fixtures.display()).red()
+        );
+        println!("{err}");
+        exit(1);
+    })
+}
+
+async fn handle(mut socket: TcpStream, state: Arc<Mutex<State>>) {
+    let Some(request) = read_request(&mut socket).await else {
+        return;
+    };
+
+    let started = Instant::now();
+    let (upstream, address) = {
+        let state = state.lock().unwrap();
+        let upstream = match &state.mode {
+            Mode::Record { upstream, .. } | Mode::Forward { upstream } => Some(upstream.clone()),
+            Mode::Replay { .. } => None,
+        };
+
+        (upstream, state.address.clone())
+    };
+
+    let interaction = match upstream {
+        Some(upstream) => {
+            let interaction = forward(&upstream, &address, &request).await;
+            let mut state = state.lock().unwrap();
+
+            if let Mode::Record { fixtures, .. } = &state.mode {
+                let fixtures = fixtures.clone();
+
+                state.interactions.push(interaction.clone());
+
+                if let Ok(content) = serde_json::to_string_pretty(&state.interactions) {
+                    let _ = fs::write(fixtures, content);
+                }
+            }
+
+            interaction
+        }
+        None => {
+            let mut state = state.lock().unwrap();
+            let State {
+                interactions, used, ..
+            } = &mut *state;
+
+            find_interaction(interactions, used, &request.method, &request.path)
+        }
+    };
+
+    audit::record(
+        "http",
+        &format!("{} {}", request.method, request.path),
+        &interaction.status.to_string(),
+        started.elapsed(),
+    );
+
+    let _ = socket
+        .write_all(&build_response(&interaction, &address))
+        .await;
+}
+
+async fn forward(upstream: &str, address: &str, request: &Request) -> Interaction {
+    let url = format!("{}{}", upstream.trim_end_matches('/'), request.path);
+    let method = reqwest::Method::from_bytes(request.method.as_bytes()).unwrap_or_default();
+    let mut builder = reqwest::Client::new()
+        .request(method, url)
+        .header("user-agent", "prmaker")
+        .body(request.body.clone());
+
+    for (name, value) in &request.headers {
+        if FORWARDED_HEADERS.contains(&name.as_str()) {
+            builder = builder.header(name, value);
+        }
+    }
+
+    let request_body =
+        (!request.body.is_empty()).then(|| String::from_utf8_lossy(&request.body).into_owned());
+
+    match builder.send().await {
+        Ok(resp) => {
+            let headers = RECORDED_HEADERS
+                .iter()
+                .filter_map(|name| {
+                    let value = resp.headers().get(*name)?.to_str().ok()?;
+                    let value = match *name {
+                        "link" => {
+                            value.replace(&format!("{}/", upstream.trim_end_matches('/')), address)
+                        }
+                        _ => value.to_owned(),
+                    };
+
+                    Some((name.to_string(), value))
+                })
+                .collect();
+
+            Interaction {
+                method: request.method.clone(),
+                path: request.path.clone(),
+                request_body,
+                status: resp.status().as_u16(),
+                headers,
+                body: resp.text().await.unwrap_or_default(),
+            }
+        }
+        Err(_) => Interaction {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            request_body,
+            status: 502,
+            headers: vec![],
+            body: r#"{"message":"prmaker couldn't reach the upstream API"}"#.to_owned(),
+        },
+    }
+}
+
+async fn read_request(socket: &mut TcpStream) -> Option<Request> {
+    let mut buffer = vec![];
+    let mut chunk = [0; 4096];
+
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.ok()?;
+
+        if read == 0 {
+            return None;
+        }
+
+        buffer.extend_from_slice(&chunk[..read]);
+
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+
+    let mut request = parse_head(&String::from_utf8_lossy(&buffer[..header_end]))?;
+    let content_length: usize = request
+        .headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = buffer[header_end..].to_vec();
+
+    while body.len() < content_length {
+        let read = socket.read(&mut chunk).await.ok()?;
+
+        if read == 0 {
+            break;
+        }
+
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    request.body = body;
+
+    Some(request)
+}
+
+fn parse_head(head: &str) -> Option<Request> {
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_owned();
+    let path = request_line.next()?.to_owned();
+
+    let headers = lines
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_lowercase(), value.trim().to_owned()))
+        })
+        .collect();
+
+    Some(Request {
+        method,
+        path,
+        headers,
+        body: vec![],
+    })
+}
+
+fn find_interaction(
+    interactions: &[Interaction],
+    used: &mut [bool],
+    method: &str,
+    path: &str,
+) -> Interaction {
+    let matches: Vec<usize> = interactions
+        .iter()
+        .enumerate()
+        .filter(|(_, i)| i.method == method && i.path == path)
+        .map(|(index, _)| index)
+        .collect();
+
+    let Some(index) = matches
+        .iter()
+        .find(|index| !used[**index])
+        .or(matches.last())
+    else {
+        return Interaction {
+            method: method.to_owned(),
+            path: path.to_owned(),
+            request_body: None,
+            status: 404,
+            headers: vec![],
+            body: format!(r#"{{"message":"No fixture for {method} {path}"}}"#),
+        };
+    };
+
+    used[*index] = true;
+    interactions[*index].clone()
+}
+
+fn build_response(interaction: &Interaction, address: &str) -> Vec<u8> {
+    let reason = reqwest::StatusCode::from_u16(interaction.status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or("Unknown");
+    let mut response = format!("HTTP/1.1 {} {reason}\r\n", interaction.status);
+
+    if !interaction
+        .headers
+        .iter()
+        .any(|(name, _)| name == "content-type")
+    {
+        response.push_str("content-type: application/json\r\n");
+    }
+
+    for (name, value) in &interaction.headers {
+        let value = match name.as_str() {
+            "link" => PROXY_URL_REGEX.replace_all(value, address),
+            _ => value.into(),
+        };
+
+        response.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    response.push_str(&format!(
+        "content-length: {}\r\nconnection: close\r\n\r\n{}",
+        interaction.body.len(),
+        interaction.body
+    ));
+
+    response.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interaction(path: &str, body: &str) -> Interaction {
+        Interaction {
+            method: "GET".to_owned(),
+            path: path.to_owned(),
+            request_body: None,
+            status: 200,
+            headers: vec![],
+            body: body.to_owned(),
+        }
+    }
+
+    #[test]
+    fn find_interaction_test() {
+        let interactions = [
+            interaction("/repos/acme/api", "first"),
+            interaction("/repos/acme/api", "second"),
+        ];
+        let mut used = [false, false];
+        let mut body = |method: &str, path: &str| {
+            find_interaction(&interactions, &mut used, method, path).body
+        };
+
+        assert_eq!(body("GET", "/repos/acme/api"), "first");
+        assert_eq!(body("GET", "/repos/acme/api"), "second");
+        assert_eq!(body("GET", "/repos/acme/api"), "second");
+        assert_eq!(
+            body("POST", "/repos/acme/api"),
+            r#"{"message":"No fixture for POST /repos/acme/api"}"#
+        );
+    }
+
+    #[test]
+    fn build_response_test() {
+        let mut recorded = interaction("/repos/acme/api/collaborators", "[]");
+        recorded.headers = vec![(
+            "link".to_owned(),
+            r#"<http://127.0.0.1:4100/repos/acme/api/collaborators?page=2>; rel="next""#.to_owned(),
+        )];
+
+        let response =
+            String::from_utf8(build_response(&recorded, "http://127.0.0.1:5200/")).unwrap();
+
+        assert!(response.contains(
+            r#"link: <http://127.0.0.1:5200/repos/acme/api/collaborators?page=2>; rel="next""#
+        ));
+    }
+
+    #[test]
+    fn parse_head_test() {
+        let request = parse_head(
+            "POST /repos/acme/api/pulls?per_page=100 HTTP/1.1\r\nContent-Length: 2\r\nAccept: */*\r\n\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/repos/acme/api/pulls?per_page=100");
+        assert_eq!(
+            request.headers,
+            [
+                ("content-length".to_owned(), "2".to_owned()),
+                ("accept".to_owned(), "*/*".to_owned())
+            ]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::Utc;
+use colored::Colorize;
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, env, fs, path::PathBuf, process::exit};
+
+use crate::{assume_defaults, flush_line, network::with_timeout, read_input};
+
+const CACHE_TTL_SECS: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cache {
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: i64,
+    names: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Named {
+    #[serde(alias = "login")]
+    name: String,
+}
+
+impl Cache {
+    fn get(&self, key: &str, now: i64) -> Option<&[String]> {
+        self.entries
+            .get(key)
+            .filter(|e| now - e.fetched_at < CACHE_TTL_SECS)
+            .map(|e| e.names.as_slice())
+    }
+}
+
+pub async fn pick(octocrab: &Octocrab, user: &str) -> (String, String) {
+    let mut cache = load_cache();
+
+    let mut owners = vec![user.to_owned()];
+    owners.extend(cached(&mut cache, "orgs", fetch_names(octocrab, "user/orgs")).await);
+
+    let owner = select("Organizations", &owners).to_owned();
+
+    let route = if owner == user {
+        "user/repos?affiliation=owner".to_owned()
+    } else {
+        format!("orgs/{owner}/repos")
+    };
+    let repos = cached(
+        &mut cache,
+        &format!("repos/{owner}"),
+        fetch_names(octocrab, &route),
+    )
+    .await;
+
+    if repos.is_empty() {
+        println!("{}", format!("No repositories found for {owner}").red());
+        exit(1);
+    }
+
+    let repo = select("Repositories", &repos).to_owned();
+
+    save_cache(&cache);
+
+    (owner, repo)
+}
+
+async fn cached(
+    cache: &mut Cache,
+    key: &str,
+    fetch: impl std::future::Future<Output = Vec<String>>,
+) -> Vec<String> {
+    let now = Utc::now().timestamp();
+
+    if let Some(names) = cache.get(key, now) {
+        return names.to_vec();
+    }
+
+    let names = fetch.await;
+
+    cache.entries.insert(
+        key.to_owned(),
+        CacheEntry {
+            fetched_at: now,
+            names: names.clone(),
+        },
+    );
+
+    names
+}
+
+async fn fetch_names(octocrab: &Octocrab, route: &str) -> Vec<String> {
+    let separator = if route.contains('?') { '&' } else { '?' };
+    let route = format!("{route}{separator}per_page=100");
+
+    match with_timeout!(octocrab.get::<Vec<Named>, _, ()>(&route, None)) {
+        Ok(named) => named.into_iter().map(|n| n.name).collect(),
+        Err(_) => {
+            println!("{}", format!("Couldn't fetch {route}").red());
+            exit(1);
+        }
+    }
+}
+
+fn select<'a>(title: &str, items: &'a [String]) -> &'a str {
+    if assume_defaults() {
+        no_prompts();
+    }
+
+    println!("\n{}", format!("** {title} **").blue());
+
+    for (index, item) in items.iter().enumerate() {
+        println!("{} - {}", index.to_string().purple(), item);
+    }
+
+    loop {
+        print!("\n{}", "Select one: ".yellow());
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.is_empty() {
+            no_prompts();
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) if index < items.len() => return &items[index],
+            Ok(_) => println!("{}", "Option not found".red()),
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+}
+
+fn no_prompts() -> ! {
+    println!(
+        "\n{}",
+        "Pass the repository with --repo to run without prompts".red()
+    );
+    exit(1);
+}
+
+fn cache_path() -> Option<PathBuf> {
+    let cache_dir = env::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .ok()?;
+
+    Some(cache_dir.join("prmaker").join("repos.toml"))
+}
+
+fn load_cache() -> Cache {
+    cache_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| toml::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(cache: &Cache) {
+    let Some(path) = cache_path() else {
+        return;
+    };
+
+    let Ok(content) = toml::to_string(cache) else {
+        return;
+    };
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    let _ = fs::write(path, content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_test() {
+        let mut cache = Cache::default();
+        cache.entries.insert(
+            "orgs".to_owned(),
+            CacheEntry {
+                fetched_at: 1000,
+                names: vec!["acme".to_owned()],
+            },
+        );
+
+        let cache: Cache = toml::from_str(&toml::to_string(&cache).unwrap()).unwrap();
+
+        assert_eq!(cache.get("orgs", 1000 + 60), Some(&["acme".to_owned()][..]));
+        assert_eq!(cache.get("orgs", 1000 + CACHE_TTL_SECS), None);
+        assert_eq!(cache.get("repos/acme", 1000), None);
+    }
+}
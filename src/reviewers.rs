@@ -0,0 +1,722 @@
+use clap::ValueEnum;
+use colored::Colorize;
+use futures_util::{stream, StreamExt};
+use octocrab::Error::GitHub;
+use octocrab::Octocrab;
+use reqwest::Method;
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{committers, flush_line, network::with_timeout, paginate, read_input};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum ReviewerGroup {
+    Members(Vec<String>),
+    Team { team: String },
+}
+
+impl Display for ReviewerGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewerGroup::Members(members) => write!(f, "{}", members.join(", ")),
+            ReviewerGroup::Team { team } => write!(f, "team {team}"),
+        }
+    }
+}
+
+pub type ReviewerGroups = BTreeMap<String, ReviewerGroup>;
+pub type Details = BTreeMap<String, String>;
+
+const MAX_NAME_LOOKUPS: usize = 100;
+const CONCURRENT_LOOKUPS: usize = 10;
+const PUSH_PERMISSIONS: [&str; 3] = ["admin", "maintain", "write"];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewerSource {
+    #[default]
+    Org,
+    Collaborators,
+    Committers,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Selection {
+    pub usernames: Vec<String>,
+    pub teams: Vec<String>,
+}
+
+impl Selection {
+    pub fn is_empty(&self) -> bool {
+        self.usernames.is_empty() && self.teams.is_empty()
+    }
+
+    pub fn without(&self, other: &Selection) -> Selection {
+        Selection {
+            usernames: self
+                .usernames
+                .iter()
+                .filter(|u| !other.usernames.contains(u))
+                .cloned()
+                .collect(),
+            teams: self
+                .teams
+                .iter()
+                .filter(|t| !other.teams.contains(t))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn add_username(&mut self, username: &str) {
+        if !self.usernames.iter().any(|u| u == username) {
+            self.usernames.push(username.to_owned());
+        }
+    }
+
+    fn add_team(&mut self, team: &str) {
+        if !self.teams.iter().any(|t| t == team) {
+            self.teams.push(team.to_owned());
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Collaborator {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct UserProfile {
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Permission {
+    permission: String,
+}
+
+#[derive(Serialize)]
+struct RemovalRequest {
+    reviewers: Vec<String>,
+    team_reviewers: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Reviewer {
+    username: String,
+    index: usize,
+    selected: bool,
+    detail: Option<String>,
+}
+
+impl Display for Reviewer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = format!("{} - {}", self.index.to_string().purple(), self.username);
+
+        if let Some(detail) = &self.detail {
+            result.push_str(&format!(" ({detail})"));
+        }
+
+        if self.selected {
+            write!(f, "{}", result.cyan())
+        } else {
+            write!(f, "{}", result)
+        }
+    }
+}
+
+pub fn expand(names: &[String], groups: &ReviewerGroups) -> Selection {
+    let (selection, unknown) = try_expand(names, groups);
+
+    for name in unknown {
+        println!(
+            "{}",
+            format!("Unknown reviewer group {name}, ignoring...").red()
+        );
+    }
+
+    selection
+}
+
+pub fn try_expand(names: &[String], groups: &ReviewerGroups) -> (Selection, Vec<String>) {
+    let mut selection = Selection::default();
+    let mut unknown = vec![];
+
+    for name in names {
+        if let Some((_, team)) = name.split_once('/') {
+            selection.add_team(team);
+            continue;
+        }
+
+        let Some(group_name) = name.strip_prefix('@') else {
+            selection.add_username(name);
+            continue;
+        };
+
+        match groups.get(group_name) {
+            Some(ReviewerGroup::Members(members)) => {
+                members.iter().for_each(|m| selection.add_username(m))
+            }
+            Some(ReviewerGroup::Team { team }) => selection.add_team(team),
+            None => unknown.push(name.clone()),
+        }
+    }
+
+    (selection, unknown)
+}
+
+pub async fn validate(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    names: &[String],
+    groups: &ReviewerGroups,
+) -> Vec<String> {
+    let (known, mut errors) = check_names(names, base, groups);
+    let selection = expand(&known, groups);
+
+    for username in &selection.usernames {
+        let route = format!("repos/{base}/{repo}/collaborators/{username}/permission");
+        let permission: octocrab::Result<Permission> =
+            with_timeout!(octocrab.get(&route, None::<&()>));
+
+        match permission {
+            Ok(p) if PUSH_PERMISSIONS.contains(&p.permission.as_str()) => {}
+            Ok(_) => errors.push(format!(
+                "{username} doesn't have push access to {base}/{repo}"
+            )),
+            Err(GitHub { source, .. }) if source.message == "Not Found" => {
+                errors.push(format!("unknown user {username}"))
+            }
+            Err(_) => errors.push(format!("couldn't check reviewer {username}")),
+        }
+    }
+
+    for team in &selection.teams {
+        let route = format!("orgs/{base}/teams/{team}");
+        let found: octocrab::Result<IgnoredAny> = with_timeout!(octocrab.get(&route, None::<&()>));
+
+        match found {
+            Ok(_) => {}
+            Err(GitHub { source, .. }) if source.message == "Not Found" => {
+                errors.push(format!("unknown team {team} in the {base} org"))
+            }
+            Err(_) => errors.push(format!("couldn't check team {team}")),
+        }
+    }
+
+    errors
+}
+
+fn check_names(
+    names: &[String],
+    base: &str,
+    groups: &ReviewerGroups,
+) -> (Vec<String>, Vec<String>) {
+    let mut known = vec![];
+    let mut errors = vec![];
+
+    for name in names {
+        match (name.strip_prefix('@'), name.split_once('/')) {
+            (Some(group), _) if !groups.contains_key(group) => {
+                errors.push(format!("unknown reviewer group {name}"))
+            }
+            (_, Some((org, _))) if org != base => {
+                errors.push(format!("team {name} isn't in the {base} org"))
+            }
+            _ => known.push(name.clone()),
+        }
+    }
+
+    (known, errors)
+}
+
+pub fn get_selected_reviewers(
+    collaborators: Vec<String>,
+    suggested: &[String],
+    preselected: Selection,
+    groups: &ReviewerGroups,
+    details: &Details,
+) -> Selection {
+    let mut reviewers = get_reviewers(collaborators, suggested, &preselected.usernames);
+    let mut teams = preselected.teams;
+
+    for reviewer in &mut reviewers {
+        reviewer.detail = details.get(&reviewer.username).cloned();
+    }
+
+    loop {
+        if !groups.is_empty() {
+            println!("\n{}", "** Reviewer groups **".blue());
+
+            for (name, group) in groups {
+                let result = format!("{} - {}", format!("@{name}").purple(), group);
+
+                if is_group_selected(&reviewers, &teams, group) {
+                    println!("{}", result.cyan());
+                } else {
+                    println!("{}", result);
+                }
+            }
+        }
+
+        let (suggested_reviewers, other_reviewers): (Vec<&Reviewer>, Vec<&Reviewer>) = reviewers
+            .iter()
+            .partition(|r| suggested.contains(&r.username));
+
+        if !suggested_reviewers.is_empty() {
+            println!("\n{}", "** Suggested reviewers **".blue());
+
+            for reviewer in suggested_reviewers {
+                println!("{}", reviewer);
+            }
+        }
+
+        println!("\n{}", "** Reviewers **".blue());
+
+        for reviewer in other_reviewers {
+            println!("{}", reviewer);
+        }
+
+        if groups.is_empty() {
+            print!("\n{}", "Add a reviewer (empty to proceed): ".yellow());
+        } else {
+            print!(
+                "\n{}",
+                "Add a reviewer or @group (empty to proceed): ".yellow()
+            );
+        }
+
+        flush_line();
+
+        let opt = read_input();
+
+        if opt.trim().is_empty() {
+            break;
+        }
+
+        if let Some(group_name) = opt.trim().strip_prefix('@') {
+            match groups.get(group_name) {
+                Some(group) => toggle_group(&mut reviewers, &mut teams, group),
+                None => println!("{}", "Reviewer group not found".red()),
+            }
+
+            continue;
+        }
+
+        match opt.trim().parse::<usize>() {
+            Ok(index) => {
+                if let Some(reviewer) = reviewers.iter_mut().find(|r| r.index == index) {
+                    reviewer.selected = !reviewer.selected
+                } else {
+                    println!("{}", "Reviewer not found".red())
+                }
+            }
+            Err(_) => println!("{}", "Invalid option, it must be a valid number".red()),
+        }
+    }
+
+    Selection {
+        usernames: reviewers
+            .into_iter()
+            .filter(|r| r.selected)
+            .map(|r| r.username)
+            .collect(),
+        teams,
+    }
+}
+
+pub async fn request_reviews(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    number: u64,
+    selection: Selection,
+) -> Selection {
+    if selection.is_empty() {
+        println!("\nNo reviewers to request");
+        return selection;
+    }
+
+    let reviews_resp = with_timeout!(octocrab.pulls(base, repo).request_reviews(
+        number,
+        selection.usernames.clone(),
+        selection.teams.clone()
+    ));
+
+    if reviews_resp.is_ok() {
+        println!("\n{}", "Reviewers requested successfully".green());
+        return selection;
+    }
+
+    if selection.usernames.len() + selection.teams.len() == 1 {
+        println!("{}", "Failed to request reviewers".red());
+        return Selection::default();
+    }
+
+    println!(
+        "\n{}",
+        "Failed to request the reviewers together, requesting them one by one...".yellow()
+    );
+
+    let mut requested = Selection::default();
+
+    for username in &selection.usernames {
+        let resp = with_timeout!(octocrab.pulls(base, repo).request_reviews(
+            number,
+            [username.clone()],
+            []
+        ));
+
+        match resp {
+            Ok(_) => requested.add_username(username),
+            Err(err) => report_failure(username, err),
+        }
+    }
+
+    for team in &selection.teams {
+        let resp =
+            with_timeout!(octocrab
+                .pulls(base, repo)
+                .request_reviews(number, [], [team.clone()]));
+
+        match resp {
+            Ok(_) => requested.add_team(team),
+            Err(err) => report_failure(&format!("team {team}"), err),
+        }
+    }
+
+    if !requested.is_empty() {
+        let names: Vec<String> = requested
+            .usernames
+            .iter()
+            .chain(&requested.teams)
+            .cloned()
+            .collect();
+
+        println!("{} {}", "Reviewers requested:".green(), names.join(", "));
+    }
+
+    requested
+}
+
+fn report_failure(reviewer: &str, err: octocrab::Error) {
+    let reason = match err {
+        GitHub { source, .. } => source.message,
+        err => err.to_string(),
+    };
+
+    println!(
+        "{} {reviewer}: {reason}",
+        "Failed to request a review from".red()
+    );
+}
+
+pub async fn remove_review_requests(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    number: u64,
+    selection: Selection,
+) {
+    if selection.is_empty() {
+        println!("\nNo review requests to remove");
+        return;
+    }
+
+    let route = format!("repos/{base}/{repo}/pulls/{number}/requested_reviewers");
+    let body = RemovalRequest {
+        reviewers: selection.usernames,
+        team_reviewers: selection.teams,
+    };
+
+    let remove_resp = match octocrab.absolute_url(route) {
+        Ok(url) => {
+            let request = || {
+                octocrab
+                    .request_builder(url.clone(), Method::DELETE)
+                    .json(&body)
+            };
+
+            match with_timeout!(octocrab.execute(request())) {
+                Ok(resp) => octocrab::map_github_error(resp).await.map(|_| ()),
+                Err(err) => Err(err),
+            }
+        }
+        Err(err) => Err(err),
+    };
+
+    match remove_resp {
+        Ok(_) => println!("\n{}", "Review requests removed successfully".green()),
+        Err(_) => println!("{}", "Failed to remove review requests".red()),
+    }
+}
+
+pub async fn get_candidates(
+    octocrab: &Octocrab,
+    base: &str,
+    repo: &str,
+    base_branch: &str,
+    source: ReviewerSource,
+) -> Option<Vec<String>> {
+    match source {
+        ReviewerSource::Org => get_org_members(octocrab, base).await,
+        ReviewerSource::Collaborators => get_collaborators(octocrab, base, repo).await,
+        ReviewerSource::Committers => {
+            let limit = committers::MAX_AUTHORS;
+            Some(committers::get_recent_committers(octocrab, base, repo, base_branch, limit).await)
+        }
+    }
+}
+
+pub fn without_ignored(logins: Vec<String>, never_suggest: &[String]) -> Vec<String> {
+    logins
+        .into_iter()
+        .filter(|login| {
+            !login.ends_with("[bot]")
+                && !never_suggest.iter().any(|i| i.eq_ignore_ascii_case(login))
+        })
+        .collect()
+}
+
+pub async fn get_details(octocrab: &Octocrab, base: &str, logins: &[String]) -> Details {
+    let admins_route = format!("orgs/{base}/members?role=admin");
+    let admins: Vec<String> = paginate::collect::<Collaborator>(octocrab, admins_route)
+        .await
+        .map(|admins| admins.into_iter().map(|a| a.login).collect())
+        .unwrap_or_default();
+
+    let names: BTreeMap<String, String> = stream::iter(logins.iter().take(MAX_NAME_LOOKUPS))
+        .map(|login| async move {
+            let route = format!("users/{login}");
+            let profile: octocrab::Result<UserProfile> =
+                with_timeout!(octocrab.get(&route, None::<&()>));
+
+            profile.ok()?.name.map(|name| (login.clone(), name))
+        })
+        .buffer_unordered(CONCURRENT_LOOKUPS)
+        .filter_map(|name| async move { name })
+        .collect()
+        .await;
+
+    logins
+        .iter()
+        .filter_map(|login| {
+            let detail = describe(names.get(login), admins.contains(login))?;
+            Some((login.clone(), detail))
+        })
+        .collect()
+}
+
+fn describe(name: Option<&String>, admin: bool) -> Option<String> {
+    let name = name.map(|n| n.trim()).filter(|n| !n.is_empty());
+
+    match (name, admin) {
+        (Some(name), true) => Some(format!("{name}, admin")),
+        (Some(name), false) => Some(name.to_owned()),
+        (None, true) => Some("admin".to_owned()),
+        (None, false) => None,
+    }
+}
+
+async fn get_org_members(octocrab: &Octocrab, base: &str) -> Option<Vec<String>> {
+    let route = format!("orgs/{base}/members");
+    let members_resp: octocrab::Result<Vec<Collaborator>> =
+        paginate::collect(octocrab, route).await;
+
+    match members_resp {
+        Ok(members) => Some(members.into_iter().map(|m| m.login).collect()),
+        Err(_) => {
+            println!("\n{}", "Error fetching org members, ignoring...".red());
+            None
+        }
+    }
+}
+
+async fn get_collaborators(octocrab: &Octocrab, base: &str, repo: &str) -> Option<Vec<String>> {
+    let route = format!("repos/{base}/{repo}/collaborators");
+    let collaborators_resp: octocrab::Result<Vec<Collaborator>> =
+        paginate::collect(octocrab, route).await;
+
+    match collaborators_resp {
+        Ok(collaborators) => Some(collaborators.into_iter().map(|c| c.login).collect()),
+        Err(_) => {
+            println!("\n{}", "Error fetching collaborators, ignoring...".red());
+            None
+        }
+    }
+}
+
+fn get_reviewers(
+    collaborators: Vec<String>,
+    suggested: &[String],
+    preselected: &[String],
+) -> Vec<Reviewer> {
+    let mut usernames = suggested.to_vec();
+
+    for username in collaborators.iter().chain(preselected) {
+        if !usernames.contains(username) {
+            usernames.push(username.clone());
+        }
+    }
+
+    usernames
+        .into_iter()
+        .enumerate()
+        .map(|(index, username)| Reviewer {
+            selected: preselected.contains(&username),
+            username,
+            index,
+            detail: None,
+        })
+        .collect()
+}
+
+fn is_group_selected(reviewers: &[Reviewer], teams: &[String], group: &ReviewerGroup) -> bool {
+    match group {
+        ReviewerGroup::Members(members) => members
+            .iter()
+            .all(|m| reviewers.iter().any(|r| r.selected && &r.username == m)),
+        ReviewerGroup::Team { team } => teams.contains(team),
+    }
+}
+
+fn toggle_group(reviewers: &mut Vec<Reviewer>, teams: &mut Vec<String>, group: &ReviewerGroup) {
+    let selected = !is_group_selected(reviewers, teams, group);
+
+    match group {
+        ReviewerGroup::Members(members) => {
+            for member in members {
+                match reviewers.iter_mut().find(|r| &r.username == member) {
+                    Some(reviewer) => reviewer.selected = selected,
+                    None if selected => reviewers.push(Reviewer {
+                        username: member.clone(),
+                        index: reviewers.len(),
+                        selected,
+                        detail: None,
+                    }),
+                    None => {}
+                }
+            }
+        }
+        ReviewerGroup::Team { team } if selected => teams.push(team.clone()),
+        ReviewerGroup::Team { team } => teams.retain(|t| t != team),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups() -> ReviewerGroups {
+        toml::from_str(
+            r#"
+            backend = ["alice", "bob"]
+            web = { team = "web-team" }
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn expand_test() {
+        let selection = expand(
+            &names(&["carol", "@backend", "@web", "alice", "acme/ops"]),
+            &groups(),
+        );
+
+        assert_eq!(selection.usernames, ["carol", "alice", "bob"]);
+        assert_eq!(selection.teams, ["web-team", "ops"]);
+    }
+
+    #[test]
+    fn check_names_test() {
+        let (known, errors) = check_names(
+            &names(&["alice", "@backend", "@missing", "acme/ops", "other/ops"]),
+            "acme",
+            &groups(),
+        );
+
+        assert_eq!(known, ["alice", "@backend", "acme/ops"]);
+        assert_eq!(
+            errors,
+            [
+                "unknown reviewer group @missing",
+                "team other/ops isn't in the acme org"
+            ]
+        );
+    }
+
+    #[test]
+    fn get_reviewers_test() {
+        let reviewers = get_reviewers(
+            names(&["alice", "bob", "carol"]),
+            &names(&["carol"]),
+            &names(&["alice", "erin"]),
+        );
+
+        let usernames: Vec<&str> = reviewers.iter().map(|r| r.username.as_str()).collect();
+
+        assert_eq!(usernames, ["carol", "alice", "bob", "erin"]);
+        assert!(reviewers[1].selected && reviewers[3].selected);
+        assert!(!reviewers[0].selected);
+    }
+
+    #[test]
+    fn without_ignored_test() {
+        assert_eq!(
+            without_ignored(
+                names(&["alice", "CEO", "dependabot[bot]", "bob"]),
+                &names(&["ceo", "ex-employee"])
+            ),
+            ["alice", "bob"]
+        );
+    }
+
+    #[test]
+    fn describe_test() {
+        let name = "Jane Smith".to_owned();
+
+        assert_eq!(
+            Some("Jane Smith, admin".to_owned()),
+            describe(Some(&name), true)
+        );
+        assert_eq!(Some("Jane Smith".to_owned()), describe(Some(&name), false));
+        assert_eq!(
+            Some("admin".to_owned()),
+            describe(Some(&" ".to_owned()), true)
+        );
+        assert_eq!(None, describe(None, false));
+    }
+
+    #[test]
+    fn toggle_group_test() {
+        let groups = groups();
+        let mut reviewers = get_reviewers(names(&["alice", "dave"]), &[], &names(&["alice"]));
+        let mut teams = vec![];
+
+        toggle_group(&mut reviewers, &mut teams, &groups["backend"]);
+
+        let selected: Vec<&str> = reviewers
+            .iter()
+            .filter(|r| r.selected)
+            .map(|r| r.username.as_str())
+            .collect();
+
+        assert_eq!(selected, ["alice", "bob"]);
+
+        toggle_group(&mut reviewers, &mut teams, &groups["backend"]);
+
+        assert!(reviewers.iter().all(|r| !r.selected));
+
+        toggle_group(&mut reviewers, &mut teams, &groups["web"]);
+
+        assert_eq!(teams, ["web-team"]);
+    }
+}
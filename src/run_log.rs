@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use std::{env, fs, path::PathBuf};
+
+const LAST_RUN_FILE: &str = "last_run.toml";
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunLog {
+    pub base: String,
+    pub repo: String,
+    pub number: u64,
+    pub link: String,
+    pub reviewers: Vec<String>,
+    pub teams: Vec<String>,
+    pub labels: Vec<String>,
+}
+
+impl RunLog {
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(state_path(LAST_RUN_FILE)?).ok()?;
+
+        toml::from_str(&content).ok()
+    }
+
+    pub fn save(&self) {
+        let Some(path) = state_path(LAST_RUN_FILE) else {
+            return;
+        };
+
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+
+        if let Ok(content) = toml::to_string(self) {
+            let _ = fs::write(path, content);
+        }
+    }
+
+    pub fn clear() {
+        if let Some(path) = state_path(LAST_RUN_FILE) {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+pub fn state_path(file: &str) -> Option<PathBuf> {
+    let state_dir = env::var("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".local/state")))
+        .ok()?;
+
+    Some(state_dir.join("prmaker").join(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let run_log = RunLog {
+            base: "acme".to_owned(),
+            repo: "api".to_owned(),
+            number: 42,
+            link: "https://github.com/acme/api/pull/42".to_owned(),
+            reviewers: vec!["john".to_owned()],
+            teams: vec![],
+            labels: vec!["backend".to_owned()],
+        };
+
+        let content = toml::to_string(&run_log).unwrap();
+
+        assert_eq!(run_log, toml::from_str(&content).unwrap());
+    }
+}
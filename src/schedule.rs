@@ -0,0 +1,103 @@
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+    Weekday,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::run_log;
+
+const SCHEDULE_FILE: &str = "scheduled.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledPR {
+    pub due: DateTime<Utc>,
+    pub base: String,
+    pub repo: String,
+    pub branch: String,
+    pub base_branch: String,
+    pub title: String,
+    pub body: String,
+    pub reviewers: Vec<String>,
+    pub teams: Vec<String>,
+}
+
+pub fn load() -> Vec<ScheduledPR> {
+    run_log::state_path(SCHEDULE_FILE)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(scheduled: &[ScheduledPR]) {
+    let Some(path) = run_log::state_path(SCHEDULE_FILE) else {
+        return;
+    };
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    if let Ok(content) = serde_json::to_string_pretty(scheduled) {
+        let _ = fs::write(path, content);
+    }
+}
+
+pub fn parse_schedule<Tz: TimeZone>(input: &str, now: DateTime<Tz>) -> Option<DateTime<Tz>> {
+    let input = input.trim();
+
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        return now.timezone().from_local_datetime(&date_time).earliest();
+    }
+
+    let (day, time) = input.split_once(' ')?;
+    let weekday: Weekday = day.parse().ok()?;
+    let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+
+    let today = now.date_naive();
+    let days_ahead = (7 + weekday.num_days_from_monday() as i64
+        - today.weekday().num_days_from_monday() as i64)
+        % 7;
+
+    (0..=1).find_map(|week| {
+        let date: NaiveDate = today + Duration::days(days_ahead + week * 7);
+        let due = now
+            .timezone()
+            .from_local_datetime(&date.and_time(time))
+            .earliest()?;
+
+        (due > now).then_some(due)
+    })
+}
+
+pub fn local_due(input: &str) -> Option<DateTime<Utc>> {
+    parse_schedule(input, Local::now()).map(|due| due.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_schedule_test() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 14, 10, 0, 0).unwrap();
+
+        assert_eq!(
+            parse_schedule("Mon 09:00", now),
+            Some(Utc.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_schedule("Wed 11:30", now),
+            Some(Utc.with_ymd_and_hms(2026, 10, 14, 11, 30, 0).unwrap())
+        );
+        assert_eq!(
+            parse_schedule("Wed 09:00", now),
+            Some(Utc.with_ymd_and_hms(2026, 10, 21, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_schedule("2026-10-20 08:15", now),
+            Some(Utc.with_ymd_and_hms(2026, 10, 20, 8, 15, 0).unwrap())
+        );
+        assert_eq!(parse_schedule("someday", now), None);
+    }
+}
@@ -0,0 +1,255 @@
+use serde::{
+    de::{self, value, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    forward_to_deserialize_any, Deserialize,
+};
+use std::fmt;
+use toml::Spanned;
+
+use crate::{
+    config::Config, profiles::Profile, projects::Project, spelling::SpellCheck,
+    summary_comment::SummaryComment, validation::ValidationRules,
+};
+
+const DATETIME_KEY: &str = "$__toml_private_datetime";
+const MAX_DISTANCE: usize = 3;
+const ALIASES: [(&str, &str); 3] = [
+    ("base", "base_branch"),
+    ("default_base", "base_branch"),
+    ("default_branch", "base_branch"),
+];
+
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub message: String,
+}
+
+enum Node {
+    Table(Vec<(Spanned<String>, Node)>),
+    Array(Vec<Node>),
+    Value,
+}
+
+pub fn check(content: &str) -> Vec<Diagnostic> {
+    let Ok(Node::Table(root)) = toml::from_str::<Node>(content) else {
+        return vec![];
+    };
+
+    let mut diagnostics = vec![];
+
+    check_table(content, "", &root, fields::<Config>(), &mut diagnostics);
+    diagnostics.sort_by_key(|d| d.line);
+    diagnostics
+}
+
+fn check_table(
+    content: &str,
+    prefix: &str,
+    table: &[(Spanned<String>, Node)],
+    known: &[&str],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (key, node) in table {
+        let name = key.get_ref().as_str();
+        let path = format!("{prefix}{name}");
+
+        if !known.contains(&name) {
+            diagnostics.push(Diagnostic {
+                line: content[..key.span().start].matches('\n').count() + 1,
+                message: unknown(&path, name, known),
+            });
+            continue;
+        }
+
+        if !prefix.is_empty() {
+            continue;
+        }
+
+        let mut nested = |path: &str, node: &Node, known: &[&str]| {
+            if let Node::Table(table) = node {
+                check_table(content, &format!("{path}."), table, known, diagnostics);
+            }
+        };
+
+        match (name, node) {
+            ("projects", Node::Array(projects)) => {
+                for project in projects {
+                    nested(&path, project, fields::<Project>());
+                }
+            }
+            ("profiles", Node::Table(profiles)) => {
+                for (profile, node) in profiles {
+                    nested(
+                        &format!("{path}.{}", profile.get_ref()),
+                        node,
+                        fields::<Profile>(),
+                    );
+                }
+            }
+            ("summary_comment", _) => nested(&path, node, fields::<SummaryComment>()),
+            ("validation", _) => nested(&path, node, fields::<ValidationRules>()),
+            ("spell_check", _) => nested(&path, node, fields::<SpellCheck>()),
+            #[cfg(feature = "github-app")]
+            ("github_app", _) => nested(&path, node, fields::<crate::github_app::GithubApp>()),
+            _ => {}
+        }
+    }
+}
+
+fn unknown(path: &str, name: &str, known: &[&str]) -> String {
+    let aliases = ALIASES.iter().filter(|(_, key)| known.contains(key));
+    let suggestion = known
+        .iter()
+        .map(|key| (*key, *key))
+        .chain(aliases.copied())
+        .map(|(spelling, key)| (strsim::levenshtein(name, spelling), key))
+        .filter(|(distance, _)| *distance <= MAX_DISTANCE.min(name.len() / 2))
+        .min();
+
+    match suggestion {
+        Some((_, key)) => format!("unknown key `{path}`, did you mean `{key}`?"),
+        None => format!("unknown key `{path}`, it's ignored"),
+    }
+}
+
+fn fields<'de, T: Deserialize<'de>>() -> &'static [&'static str] {
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+
+    fields
+}
+
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("fields collected"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a TOML value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut items = vec![];
+
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+
+        Ok(Node::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut entries = vec![];
+
+        while let Some(key) = map.next_key::<Spanned<String>>()? {
+            if key.get_ref() == DATETIME_KEY {
+                map.next_value::<IgnoredAny>()?;
+                return Ok(Node::Value);
+            }
+
+            entries.push((key, map.next_value()?));
+        }
+
+        Ok(Node::Table(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_test() {
+        let content = r#"
+defualt_base = "main"
+gitmoji = true
+
+[[projects]]
+path = "api"
+templates = ".github/api.md"
+
+[profiles.work]
+hosts = ["github.acme.com"]
+extra = 1
+
+[plugin]
+enabled = true
+"#;
+        let messages: Vec<(usize, String)> = check(content)
+            .into_iter()
+            .map(|d| (d.line, d.message))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (
+                    2,
+                    "unknown key `defualt_base`, did you mean `base_branch`?".to_owned()
+                ),
+                (
+                    7,
+                    "unknown key `projects.templates`, did you mean `template`?".to_owned()
+                ),
+                (
+                    11,
+                    "unknown key `profiles.work.extra`, it's ignored".to_owned()
+                ),
+                (13, "unknown key `plugin`, it's ignored".to_owned()),
+            ],
+            messages
+        );
+        assert!(check("base_branch = \"main\"\n[validation]\nrequire_type = true\n").is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use colored::Colorize;
+use regex::Regex;
+use serde::Deserialize;
+use std::{collections::HashSet, fmt::Display, fs, path::PathBuf, sync::LazyLock};
+
+const MIN_WORD_LENGTH: usize = 3;
+const MAX_DISTANCE: usize = 2;
+const MAX_SUGGESTIONS: usize = 3;
+const SUFFIXES: [&str; 6] = ["'s", "s", "es", "ed", "ing", "ly"];
+static CODE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)```.*?```|`[^`]*`|https?://\S+").unwrap());
+static WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Za-z][A-Za-z']*").unwrap());
+
+#[derive(Debug, Deserialize)]
+pub struct SpellCheck {
+    pub dictionary: PathBuf,
+    #[serde(default)]
+    pub words: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Typo {
+    pub word: String,
+    pub suggestions: Vec<String>,
+}
+
+impl Display for Typo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.suggestions.is_empty() {
+            write!(f, "{}", self.word.red())
+        } else {
+            write!(
+                f,
+                "{} -> {}",
+                self.word.red(),
+                self.suggestions.join(", ").green()
+            )
+        }
+    }
+}
+
+struct Dictionary {
+    words: HashSet<String>,
+}
+
+impl Dictionary {
+    fn parse(dic: &str, extra: &[String]) -> Self {
+        let words = dic
+            .lines()
+            .skip_while(|line| line.trim().chars().all(|c| c.is_ascii_digit()))
+            .filter_map(|line| line.split('/').next())
+            .chain(extra.iter().map(|w| w.as_str()))
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        Dictionary { words }
+    }
+
+    fn knows(&self, word: &str) -> bool {
+        self.words.contains(word)
+            || SUFFIXES.iter().any(|suffix| {
+                word.strip_suffix(suffix)
+                    .is_some_and(|stem| self.words.contains(stem))
+            })
+    }
+
+    fn suggest(&self, word: &str) -> Vec<String> {
+        let mut candidates: Vec<(usize, &String)> = self
+            .words
+            .iter()
+            .filter(|w| w.len().abs_diff(word.len()) <= MAX_DISTANCE)
+            .map(|w| (distance(word, w), w))
+            .filter(|(d, _)| *d <= MAX_DISTANCE)
+            .collect();
+
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, w)| w.clone())
+            .collect()
+    }
+}
+
+pub fn check(texts: &[&str], config: &SpellCheck) -> Vec<Typo> {
+    let dic = match fs::read_to_string(&config.dictionary) {
+        Ok(dic) => dic,
+        Err(_) => {
+            println!(
+                "\n{}",
+                format!(
+                    "Couldn't read dictionary {}, skipping spell-check...",
+                    config.dictionary.display()
+                )
+                .red()
+            );
+            return vec![];
+        }
+    };
+
+    find_typos(texts, &Dictionary::parse(&dic, &config.words))
+}
+
+fn find_typos(texts: &[&str], dictionary: &Dictionary) -> Vec<Typo> {
+    let mut typos: Vec<Typo> = vec![];
+
+    for text in texts {
+        let prose = CODE_REGEX.replace_all(text, " ");
+
+        for word in WORD_REGEX.find_iter(&prose).map(|m| m.as_str()) {
+            let word = word.trim_matches('\'');
+            let is_identifier = word.chars().skip(1).any(|c| c.is_uppercase());
+            let lowercase = word.to_lowercase();
+
+            if word.len() < MIN_WORD_LENGTH
+                || is_identifier
+                || dictionary.knows(&lowercase)
+                || typos.iter().any(|t| t.word == word)
+            {
+                continue;
+            }
+
+            typos.push(Typo {
+                word: word.to_owned(),
+                suggestions: dictionary.suggest(&lowercase),
+            });
+        }
+    }
+
+    typos
+}
+
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != *b_char);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_typos_test() {
+        let dictionary = Dictionary::parse(
+            "8\nadd/S\nfeat\nthe\nlogin/M\npage\nfix\nbutton\nwith\n",
+            &["prmaker".to_owned()],
+        );
+
+        let typos = find_typos(
+            &[
+                "feat: add teh login pages",
+                "Fixes the buton with prmaker in `signIn`, https://exmple.com",
+            ],
+            &dictionary,
+        );
+
+        assert_eq!(
+            typos,
+            [
+                Typo {
+                    word: "teh".to_owned(),
+                    suggestions: vec!["the".to_owned()],
+                },
+                Typo {
+                    word: "buton".to_owned(),
+                    suggestions: vec!["button".to_owned()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn distance_test() {
+        assert_eq!(distance("teh", "the"), 2);
+        assert_eq!(distance("buton", "button"), 1);
+        assert_eq!(distance("", "add"), 3);
+    }
+}
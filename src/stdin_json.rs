@@ -0,0 +1,93 @@
+use serde::Serialize;
+use std::{
+    io::{self, Read},
+    process::exit,
+};
+
+use crate::api::{self, PrRequest, PrResult};
+
+#[derive(Debug, Default, Serialize)]
+pub struct Response {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reviewers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+pub async fn run() {
+    let mut input = String::new();
+    let _ = io::stdin().read_to_string(&mut input);
+
+    let result = match parse_request(&input) {
+        Ok(request) => api::create_pull_request(request).await,
+        Err(error) => Err(error),
+    };
+
+    let response = match result {
+        Ok(PrResult {
+            number,
+            url,
+            reviewers,
+            warnings,
+        }) => Response {
+            ok: true,
+            number: Some(number),
+            url: Some(url),
+            reviewers,
+            warnings,
+            error: None,
+        },
+        Err(error) => Response {
+            error: Some(error),
+            ..Default::default()
+        },
+    };
+
+    println!("{}", serde_json::to_string(&response).unwrap());
+
+    if !response.ok {
+        exit(1);
+    }
+}
+
+fn parse_request(input: &str) -> Result<PrRequest, String> {
+    let request: PrRequest =
+        serde_json::from_str(input).map_err(|err| format!("invalid request: {err}"))?;
+
+    if request.title.trim().is_empty() {
+        return Err("invalid request: title is required".to_owned());
+    }
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_request_test() {
+        assert_eq!(
+            Ok(PrRequest {
+                title: "feat: add login".to_owned(),
+                reviewers: vec!["alice".to_owned()],
+                ..Default::default()
+            }),
+            parse_request(r#"{"title": "feat: add login", "reviewers": ["alice"]}"#)
+        );
+        assert_eq!(
+            Err("invalid request: title is required".to_owned()),
+            parse_request(r#"{"body": "Adds it"}"#)
+        );
+        assert!(parse_request(r#"{"title": "x", "reviewer": "alice"}"#)
+            .unwrap_err()
+            .contains("unknown field `reviewer`"));
+    }
+}
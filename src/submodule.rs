@@ -0,0 +1,103 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use std::path::Path;
+
+use crate::{
+    base_branch, confirm_or, git,
+    pr::{self, PR},
+};
+
+pub async fn offer_superproject_bump(octocrab: &Octocrab, submodule_pr: &PR) {
+    let Some(superproject) = git::superproject() else {
+        return;
+    };
+
+    let toplevel = git::toplevel();
+    let path = Path::new(&toplevel)
+        .strip_prefix(&superproject)
+        .map(|p| p.display().to_string())
+        .unwrap_or(toplevel.clone());
+
+    if !confirm_or(
+        &format!("This is the {path} submodule, bump it in the superproject and open a linked PR?"),
+        false,
+    ) {
+        return;
+    }
+
+    let sha = git::head_sha();
+    let branch = bump_branch(&path, &submodule_pr.branch);
+    let message = bump_title(&path, &sha);
+    let git_in_superproject = |args: &[&str]| {
+        let mut full_args = vec!["-C", superproject.as_str()];
+        full_args.extend_from_slice(args);
+        git::try_git(&full_args)
+    };
+
+    let bumped = git_in_superproject(&["switch", "-c", &branch])
+        .and_then(|_| git_in_superproject(&["add", &path]))
+        .and_then(|_| git_in_superproject(&["commit", "-m", &message, "--", &path]))
+        .and_then(|_| git_in_superproject(&["push", "-u", "origin", &branch]));
+
+    if let Err(err) = bumped {
+        println!(
+            "\n{}",
+            "Couldn't bump the submodule in the superproject".red()
+        );
+        println!("{err}");
+        return;
+    }
+
+    let remote_url = match git_in_superproject(&["config", "--get", "remote.origin.url"]) {
+        Ok(remote_url) => remote_url,
+        Err(err) => {
+            println!("\n{}", "Couldn't get the superproject remote".red());
+            println!("{err}");
+            return;
+        }
+    };
+
+    let base = pr::get_base(&remote_url);
+    let repo = pr::get_repo(&remote_url);
+    let base_branch = base_branch::resolve(octocrab, &base, &repo, None).await;
+    let body = bump_body(&path, submodule_pr.link.as_deref().unwrap_or_default());
+
+    let mut superproject_pr = PR::new(base, repo, branch, base_branch, message, body);
+
+    println!("\nCreating superproject PR...");
+
+    let _ = superproject_pr.create(octocrab).await;
+}
+
+fn bump_branch(path: &str, branch: &str) -> String {
+    format!("bump/{}/{branch}", path.replace('/', "-"))
+}
+
+fn bump_title(path: &str, sha: &str) -> String {
+    format!("chore: bump {path} to {}", &sha[..sha.len().min(7)])
+}
+
+fn bump_body(path: &str, submodule_pr_link: &str) -> String {
+    format!("Bumps the `{path}` submodule to include {submodule_pr_link}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bump_naming_test() {
+        assert_eq!(
+            "bump/libs-core/fix/CT-1/thing",
+            bump_branch("libs/core", "fix/CT-1/thing")
+        );
+        assert_eq!(
+            "chore: bump libs/core to 1a2b3c4",
+            bump_title("libs/core", "1a2b3c4d5e6f")
+        );
+        assert_eq!(
+            "Bumps the `libs/core` submodule to include https://github.com/o/core/pull/3",
+            bump_body("libs/core", "https://github.com/o/core/pull/3")
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::{git, network::with_timeout, pr::PR};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SummaryComment {
+    pub test_plan: Vec<String>,
+}
+
+pub async fn post(octocrab: &Octocrab, pr: &PR, summary: &SummaryComment) {
+    let diffstat = git::try_git(&[
+        "diff",
+        "--stat",
+        &format!("origin/{}...{}", pr.base_branch, git::head()),
+    ])
+    .unwrap_or_default();
+    let commits = git::commit_subjects(&format!("origin/{}..{}", pr.base_branch, git::head()))
+        .unwrap_or_default();
+
+    let comment_resp = with_timeout!(octocrab.issues(&pr.base, &pr.repo).create_comment(
+        pr.number.unwrap(),
+        build(&diffstat, &commits, &summary.test_plan),
+    ));
+
+    match comment_resp {
+        Ok(_) => println!("\n{}", "Summary comment posted successfully".green()),
+        Err(_) => println!("\n{}", "Error when posting summary comment".red()),
+    }
+}
+
+fn build(diffstat: &str, commits: &[String], test_plan: &[String]) -> String {
+    let mut comment = String::from("### What changed\n");
+
+    if !diffstat.trim().is_empty() {
+        comment.push_str(&format!("\n```\n{}\n```\n", diffstat.trim_end()));
+    }
+
+    if !commits.is_empty() {
+        comment.push_str("\n#### Commits\n\n");
+
+        for commit in commits.iter().rev() {
+            comment.push_str(&format!("- {commit}\n"));
+        }
+    }
+
+    if !test_plan.is_empty() {
+        comment.push_str("\n#### Test plan\n\n");
+
+        for step in test_plan {
+            comment.push_str(&format!("- [ ] {step}\n"));
+        }
+    }
+
+    comment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_test() {
+        let commits = ["feat: second".to_owned(), "feat: first".to_owned()];
+        let test_plan = ["Run the migrations".to_owned()];
+
+        assert_eq!(
+            build(
+                " src/main.rs | 2 +-\n 1 file changed\n",
+                &commits,
+                &test_plan
+            ),
+            "\
+### What changed
+
+```
+ src/main.rs | 2 +-
+ 1 file changed
+```
+
+#### Commits
+
+- feat: first
+- feat: second
+
+#### Test plan
+
+- [ ] Run the migrations
+"
+        );
+        assert_eq!(build("", &[], &[]), "### What changed\n");
+    }
+}
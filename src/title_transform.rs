@@ -0,0 +1,93 @@
+use colored::Colorize;
+use serde::Deserialize;
+
+use crate::conventional;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitleTransform {
+    SentenceCase,
+    StripPeriod,
+    Capitalize,
+}
+
+pub fn apply(title: &str, transforms: &[TitleTransform]) -> String {
+    let (prefix, subject) = conventional::split_prefix(title);
+    let mut subject = subject.to_owned();
+
+    for transform in transforms {
+        subject = match transform {
+            TitleTransform::SentenceCase => sentence_case(&subject),
+            TitleTransform::StripPeriod => subject.trim_end_matches('.').trim_end().to_owned(),
+            TitleTransform::Capitalize => capitalize(&subject),
+        };
+    }
+
+    format!("{prefix}{subject}")
+}
+
+pub fn preview(title: &str, transforms: &[TitleTransform]) -> String {
+    let transformed = apply(title, transforms);
+
+    if transformed != title {
+        println!(
+            "{} {} -> {}",
+            "Title normalized:".yellow(),
+            title,
+            transformed.cyan()
+        );
+    }
+
+    transformed
+}
+
+fn sentence_case(subject: &str) -> String {
+    let words: Vec<String> = subject
+        .split(' ')
+        .enumerate()
+        .map(|(index, word)| {
+            let is_title_case = word.chars().next().is_some_and(|c| c.is_uppercase())
+                && word.chars().skip(1).all(|c| !c.is_uppercase());
+
+            if index > 0 && is_title_case {
+                word.to_lowercase()
+            } else {
+                word.to_owned()
+            }
+        })
+        .collect();
+
+    capitalize(&words.join(" "))
+}
+
+fn capitalize(subject: &str) -> String {
+    let mut chars = subject.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_test() {
+        use TitleTransform::*;
+
+        assert_eq!(
+            "feat(api): Add the Login page for SSO",
+            apply(
+                "feat(api): add the Login page for SSO.",
+                &[StripPeriod, Capitalize]
+            )
+        );
+        assert_eq!(
+            "Add the login page for SSO",
+            apply("add The Login Page for SSO", &[SentenceCase])
+        );
+        assert_eq!("fix: crash..", apply("fix: crash..", &[]));
+    }
+}
@@ -0,0 +1,92 @@
+use colored::Colorize;
+use octocrab::Octocrab;
+use std::process::exit;
+
+use crate::{confirm_or, network::with_timeout};
+
+pub const REQUIRED_SCOPES: [&str; 2] = ["repo", "read:org"];
+
+const CREATE_SCOPES: [&str; 1] = ["public_repo"];
+
+pub async fn get_scopes(octocrab: &Octocrab) -> Result<Option<String>, String> {
+    let url = octocrab
+        .absolute_url("rate_limit")
+        .map_err(|_| "invalid API url".to_owned())?;
+    let resp = with_timeout!(octocrab._get(url.as_str(), None::<&()>))
+        .map_err(|_| "GitHub is unreachable".to_owned())?;
+
+    if !resp.status().is_success() {
+        return Err(format!("token was rejected with {}", resp.status()));
+    }
+
+    Ok(resp
+        .headers()
+        .get("x-oauth-scopes")
+        .map(|s| s.to_str().unwrap_or_default().to_owned()))
+}
+
+pub async fn ensure_can_create(octocrab: &Octocrab) {
+    let scopes = match get_scopes(octocrab).await {
+        Ok(Some(scopes)) => scopes,
+        Ok(None) => return,
+        Err(err) => {
+            println!("{}", format!("GitHub token check failed: {err}").red());
+            exit(1);
+        }
+    };
+
+    let missing = missing_scopes(&scopes, &CREATE_SCOPES);
+
+    if missing.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{} {}",
+        "The GitHub token is missing the scopes needed to create PRs:".red(),
+        missing.join(", ")
+    );
+
+    if !confirm_or("Continue anyway?", false) {
+        println!("\nClosing...");
+        exit(0);
+    }
+}
+
+pub fn missing_scopes(scopes: &str, required: &[&'static str]) -> Vec<&'static str> {
+    let granted: Vec<&str> = scopes.split(',').map(|s| s.trim()).collect();
+
+    required
+        .iter()
+        .copied()
+        .filter(|required| !granted.iter().any(|g| grants(g, required)))
+        .collect()
+}
+
+fn grants(granted: &str, required: &str) -> bool {
+    match required {
+        "read:org" => ["read:org", "write:org", "admin:org"].contains(&granted),
+        "public_repo" => ["repo", "public_repo"].contains(&granted),
+        _ => granted == required,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_scopes_test() {
+        assert!(missing_scopes("repo, read:org", &REQUIRED_SCOPES).is_empty());
+        assert!(missing_scopes("admin:org, repo, gist", &REQUIRED_SCOPES).is_empty());
+        assert_eq!(missing_scopes("repo", &REQUIRED_SCOPES), ["read:org"]);
+        assert_eq!(missing_scopes("", &REQUIRED_SCOPES), ["repo", "read:org"]);
+        assert_eq!(
+            missing_scopes("public_repo", &REQUIRED_SCOPES),
+            ["repo", "read:org"]
+        );
+        assert!(missing_scopes("public_repo", &CREATE_SCOPES).is_empty());
+        assert!(missing_scopes("repo", &CREATE_SCOPES).is_empty());
+        assert_eq!(missing_scopes("gist", &CREATE_SCOPES), ["public_repo"]);
+    }
+}
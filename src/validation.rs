@@ -0,0 +1,116 @@
+use serde::Deserialize;
+
+use crate::conventional;
+
+const MAX_TITLE_LENGTH: usize = 256;
+const MAX_BODY_LENGTH: usize = 65536;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ValidationRules {
+    pub forbidden_words: Vec<String>,
+    pub allowed_types: Vec<String>,
+    pub require_type: bool,
+}
+
+pub fn validate(title: &str, body: &str, rules: &ValidationRules) -> Vec<String> {
+    let mut errors = vec![];
+
+    if title.trim().is_empty() {
+        errors.push("Title can't be empty".to_owned());
+    }
+
+    if title.chars().count() > MAX_TITLE_LENGTH {
+        errors.push(format!(
+            "Title has {} characters, GitHub allows at most {MAX_TITLE_LENGTH}",
+            title.chars().count()
+        ));
+    }
+
+    if body.chars().count() > MAX_BODY_LENGTH {
+        errors.push(format!(
+            "Body has {} characters, GitHub allows at most {MAX_BODY_LENGTH}",
+            body.chars().count()
+        ));
+    }
+
+    let lowercase_title = title.to_lowercase();
+
+    for word in &rules.forbidden_words {
+        let is_forbidden = lowercase_title
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|w| w == word.to_lowercase());
+
+        if is_forbidden {
+            errors.push(format!("Title must not contain `{word}`"));
+        }
+    }
+
+    match conventional::commit_type(title) {
+        Some(commit_type)
+            if !rules.allowed_types.is_empty() && !rules.allowed_types.contains(&commit_type) =>
+        {
+            errors.push(format!(
+                "Title type `{commit_type}` is not one of {}",
+                rules.allowed_types.join(", ")
+            ))
+        }
+        None if rules.require_type => {
+            errors.push("Title must start with a conventional commit type".to_owned())
+        }
+        _ => {}
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_test() {
+        let rules = ValidationRules {
+            forbidden_words: vec!["WIP".to_owned()],
+            allowed_types: vec!["feat".to_owned(), "fix".to_owned()],
+            require_type: true,
+        };
+
+        assert!(validate("feat: add validation", "Body", &rules).is_empty());
+        assert_eq!(
+            validate("WIP add validation", "Body", &rules),
+            [
+                "Title must not contain `WIP`",
+                "Title must start with a conventional commit type"
+            ]
+        );
+        assert_eq!(
+            validate("chore: wipe cache", "Body", &rules),
+            ["Title type `chore` is not one of feat, fix"]
+        );
+        assert_eq!(
+            validate(
+                &"a".repeat(257),
+                &"b".repeat(65537),
+                &ValidationRules::default()
+            ),
+            [
+                "Title has 257 characters, GitHub allows at most 256",
+                "Body has 65537 characters, GitHub allows at most 65536"
+            ]
+        );
+    }
+
+    #[test]
+    fn error_messages_snapshot_test() {
+        let rules = ValidationRules {
+            forbidden_words: vec!["WIP".to_owned()],
+            allowed_types: vec!["feat".to_owned(), "fix".to_owned()],
+            require_type: true,
+        };
+        let mut errors = validate("chore: WIP cleanup", "Body", &rules);
+        errors.extend(validate(&"a".repeat(300), "Body", &rules));
+
+        insta::assert_snapshot!("error_messages", errors.join("\n"));
+    }
+}
@@ -0,0 +1,142 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use colored::Colorize;
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use std::{env, num::NonZeroU32, process::Command, sync::OnceLock};
+
+use crate::{flush_line, interrupt::HiddenInput, read_input};
+
+const PREFIX: &str = "enc:v1:";
+const PASSPHRASE_VAR: &str = "PRMAKER_PASSPHRASE";
+const KEYCHAIN_SERVICE: &str = "prmaker";
+const SALT_LEN: usize = 16;
+const ITERATIONS: u32 = 100_000;
+
+static PASSPHRASE: OnceLock<String> = OnceLock::new();
+
+pub fn is_encrypted(value: &str) -> bool {
+    value.starts_with(PREFIX)
+}
+
+pub fn unlock(value: &str) -> Result<String, String> {
+    decrypt(value, passphrase())
+}
+
+pub fn passphrase() -> &'static str {
+    PASSPHRASE.get_or_init(|| {
+        env::var(PASSPHRASE_VAR)
+            .ok()
+            .or_else(from_keychain)
+            .unwrap_or_else(|| read_hidden("Passphrase to unlock the config secrets: "))
+    })
+}
+
+pub fn read_hidden(question: &str) -> String {
+    print!("{}", question.yellow());
+    flush_line();
+
+    let input = {
+        let _hidden = HiddenInput::start();
+
+        read_input()
+    };
+
+    println!();
+
+    input.trim_end_matches(['\r', '\n']).to_owned()
+}
+
+pub fn encrypt(plaintext: &str, passphrase: &str) -> String {
+    let rng = SystemRandom::new();
+    let mut salt = [0; SALT_LEN];
+    let mut nonce = [0; NONCE_LEN];
+
+    rng.fill(&mut salt).unwrap();
+    rng.fill(&mut nonce).unwrap();
+
+    let mut sealed = plaintext.as_bytes().to_vec();
+
+    derive_key(passphrase, &salt)
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut sealed,
+        )
+        .unwrap();
+
+    let payload = [&salt[..], &nonce[..], &sealed].concat();
+
+    format!("{PREFIX}{}", STANDARD.encode(payload))
+}
+
+fn decrypt(value: &str, passphrase: &str) -> Result<String, String> {
+    let encoded = value.strip_prefix(PREFIX).ok_or("value isn't encrypted")?;
+    let payload = STANDARD
+        .decode(encoded)
+        .map_err(|_| "encrypted value is corrupted".to_owned())?;
+
+    if payload.len() < SALT_LEN + NONCE_LEN {
+        return Err("encrypted value is corrupted".to_owned());
+    }
+
+    let (salt, rest) = payload.split_at(SALT_LEN);
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).unwrap();
+    let mut sealed = sealed.to_vec();
+
+    let plaintext = derive_key(passphrase, salt)
+        .open_in_place(nonce, Aad::empty(), &mut sealed)
+        .map_err(|_| "wrong passphrase or corrupted value".to_owned())?;
+
+    String::from_utf8(plaintext.to_vec()).map_err(|_| "encrypted value isn't text".to_owned())
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> LessSafeKey {
+    let mut key = [0; 32];
+
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(ITERATIONS).unwrap(),
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+
+    LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, &key).unwrap())
+}
+
+fn from_keychain() -> Option<String> {
+    let output = if cfg!(target_os = "macos") {
+        Command::new("security")
+            .args(["find-generic-password", "-s", KEYCHAIN_SERVICE, "-w"])
+            .output()
+    } else {
+        Command::new("secret-tool")
+            .args(["lookup", "service", KEYCHAIN_SERVICE])
+            .output()
+    }
+    .ok()
+    .filter(|o| o.status.success())?;
+
+    let passphrase = String::from_utf8(output.stdout).ok()?.trim().to_owned();
+
+    (!passphrase.is_empty()).then_some(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let encrypted = encrypt("ghp_secret", "hunter2");
+
+        assert!(is_encrypted(&encrypted));
+        assert_eq!(Ok("ghp_secret".to_owned()), decrypt(&encrypted, "hunter2"));
+        assert!(decrypt(&encrypted, "wrong").is_err());
+        assert!(decrypt("enc:v1:AAAA", "hunter2").is_err());
+    }
+}
@@ -0,0 +1,410 @@
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const RUN_TIMEOUT: Duration = Duration::from_secs(30);
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+struct Route {
+    method: String,
+    path: String,
+    status: u16,
+    body: String,
+    headers: Vec<(String, String)>,
+}
+
+pub struct FakeGithub {
+    pub url: String,
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl FakeGithub {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let routes = Arc::new(Mutex::new(vec![]));
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let server_routes = Arc::clone(&routes);
+        let server_requests = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &server_routes, &server_requests);
+            }
+        });
+
+        let github = FakeGithub {
+            url,
+            routes,
+            requests,
+        };
+
+        github.route("GET", "/rate_limit", 200, RATE_LIMIT);
+        github.route("GET", "/repos/acme/api", 200, REPOSITORY);
+        github.route("GET", "/repos/acme/api/branches", 200, BRANCHES);
+        github.route("POST", "/repos/acme/api/pulls", 201, PULL_REQUEST);
+        github.route("GET", "/repos/acme/api/collaborators", 200, COLLABORATORS);
+        github.route("POST", "/repos/acme/api/issues/7/assignees", 201, &issue());
+        github.route(
+            "GET",
+            "/repos/acme/api/collaborators/alice/permission",
+            200,
+            WRITE_PERMISSION,
+        );
+        github.route(
+            "POST",
+            "/repos/acme/api/pulls/7/requested_reviewers",
+            201,
+            PULL_REQUEST,
+        );
+        github.route("POST", "/repos/acme/api/issues/7/labels", 200, "[]");
+        github
+    }
+
+    pub fn route(&self, method: &str, path: &str, status: u16, body: &str) {
+        self.route_with_headers(method, path, status, body, &[]);
+    }
+
+    pub fn route_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        status: u16,
+        body: &str,
+        headers: &[(&str, &str)],
+    ) {
+        self.routes.lock().unwrap().push(Route {
+            method: method.to_owned(),
+            path: path.to_owned(),
+            status,
+            body: body.to_owned(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        });
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn find(&self, method: &str, path: &str) -> Option<Request> {
+        self.requests()
+            .into_iter()
+            .find(|r| r.method == method && r.path == path)
+    }
+}
+
+fn handle(mut stream: TcpStream, routes: &Mutex<Vec<Route>>, requests: &Mutex<Vec<Request>>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split('?').next().unwrap_or_default().to_owned();
+    let mut content_length = 0;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    let _ = reader.read_exact(&mut body);
+
+    let (status, response, headers) = routes
+        .lock()
+        .unwrap()
+        .iter()
+        .rev()
+        .find(|r| r.method == method && (r.path == path || r.path == target))
+        .map(|r| (r.status, r.body.clone(), r.headers.clone()))
+        .unwrap_or((404, r#"{"message": "Not Found"}"#.to_owned(), vec![]));
+    let headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}\r\n"))
+        .collect();
+
+    requests.lock().unwrap().push(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    });
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status} Fake\r\ncontent-type: application/json\r\nx-oauth-scopes: repo, read:org\r\n{headers}content-length: {}\r\nconnection: close\r\n\r\n{response}",
+        response.len()
+    );
+}
+
+pub struct Workspace {
+    pub root: PathBuf,
+    pub repo: PathBuf,
+    config: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(github: &FakeGithub) -> Self {
+        let root = env::temp_dir().join(format!(
+            "prmaker-e2e-{}-{}",
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+        ));
+        let repo = root.join("repo");
+        let config = root.join("config.toml");
+
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&repo).unwrap();
+
+        fs::write(
+            &config,
+            format!(
+                r#"
+reviewer_source = "collaborators"
+
+[[projects]]
+path = "web"
+labels = ["web"]
+
+[profiles.test]
+api_url = "{}"
+"#,
+                github.url
+            ),
+        )
+        .unwrap();
+
+        git(&repo, &["init", "-q", "-b", "main"]);
+        fs::write(repo.join("README.md"), "# api\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-qm", "Initial commit"]);
+        git(&repo, &["update-ref", "refs/remotes/origin/main", "HEAD"]);
+        git(&repo, &["checkout", "-qb", "feature"]);
+        fs::create_dir_all(repo.join("web")).unwrap();
+        fs::write(repo.join("web/app.js"), "console.log('app')\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-qm", "feat: add app"]);
+        git(
+            &repo,
+            &["update-ref", "refs/remotes/origin/feature", "HEAD"],
+        );
+        git(
+            &repo,
+            &["remote", "add", "origin", "git@github.com:acme/api.git"],
+        );
+
+        Workspace { root, repo, config }
+    }
+
+    pub fn configure(&self, top_level: &str) {
+        let config = fs::read_to_string(&self.config).unwrap();
+        fs::write(&self.config, format!("{top_level}\n{config}")).unwrap();
+    }
+
+    pub fn configure_profile(&self, line: &str) {
+        let config = fs::read_to_string(&self.config).unwrap();
+        let config = config.replace("[profiles.test]\n", &format!("[profiles.test]\n{line}\n"));
+        fs::write(&self.config, config).unwrap();
+    }
+
+    pub fn configure_project(&self, line: &str) {
+        let config = fs::read_to_string(&self.config).unwrap();
+        let config = config.replace("path = \"web\"\n", &format!("path = \"web\"\n{line}\n"));
+        fs::write(&self.config, config).unwrap();
+    }
+
+    pub fn run(&self, args: &[&str], stdin: &str) -> Output {
+        self.run_with_env(args, stdin, &[])
+    }
+
+    pub fn run_with_env(&self, args: &[&str], stdin: &str, vars: &[(&str, &str)]) -> Output {
+        let mut child = self
+            .command(args)
+            .envs(vars.iter().copied())
+            .spawn()
+            .unwrap();
+
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+
+        let started = Instant::now();
+
+        while child.try_wait().unwrap().is_none() {
+            if started.elapsed() > RUN_TIMEOUT {
+                let _ = child.kill();
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        child.wait_with_output().unwrap()
+    }
+
+    pub fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_prmaker"));
+
+        command
+            .args(["--profile", "test"])
+            .args(args)
+            .current_dir(&self.repo)
+            .env("PRMAKER_CONFIG", &self.config)
+            .env("GITHUB_TOKEN", "test-token")
+            .env("GITHUB_USER", "me")
+            .env("HOME", &self.root)
+            .env("XDG_STATE_HOME", self.root.join("state"))
+            .env("XDG_CONFIG_HOME", self.root.join("config"))
+            .env("GIT_SSH_COMMAND", "false")
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .env("NO_COLOR", "1")
+            .env_remove("RUST_BACKTRACE")
+            .env_remove("EDITOR")
+            .env_remove("VISUAL")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        command
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Me")
+        .env("GIT_AUTHOR_EMAIL", "me@example.com")
+        .env("GIT_COMMITTER_NAME", "Me")
+        .env("GIT_COMMITTER_EMAIL", "me@example.com")
+        .status()
+        .unwrap();
+
+    assert!(status.success(), "git {args:?} failed");
+}
+
+const RATE_LIMIT: &str = r#"{
+  "resources": {"core": {"limit": 5000, "used": 1, "remaining": 4999, "reset": 1700000000}},
+  "rate": {"limit": 5000, "used": 1, "remaining": 4999, "reset": 1700000000}
+}"#;
+
+const WRITE_PERMISSION: &str = r#"{"permission": "write"}"#;
+
+const COLLABORATORS: &str = r#"[{"login": "alice"}, {"login": "bob"}]"#;
+
+const REPOSITORY: &str = r#"{
+  "id": 1,
+  "name": "api",
+  "url": "https://api.github.com/repos/acme/api",
+  "default_branch": "main"
+}"#;
+
+const BRANCHES: &str = r#"[{
+  "name": "main",
+  "commit": {"sha": "def", "url": "https://api.github.com/repos/acme/api/commits/def"},
+  "protected": false
+}]"#;
+
+const PULL_REQUEST: &str = r#"{
+  "url": "https://api.github.com/repos/acme/api/pulls/7",
+  "html_url": "https://github.com/acme/api/pull/7",
+  "id": 1,
+  "node_id": "PR_1",
+  "number": 7,
+  "locked": false,
+  "maintainer_can_modify": true,
+  "head": {"ref": "feature", "sha": "abc"},
+  "base": {"ref": "main", "sha": "def"}
+}"#;
+
+fn issue() -> String {
+    let user = r#"{
+      "login": "me",
+      "id": 1,
+      "node_id": "U_1",
+      "avatar_url": "https://github.com/me.png",
+      "gravatar_id": "",
+      "url": "https://api.github.com/users/me",
+      "html_url": "https://github.com/me",
+      "followers_url": "https://api.github.com/users/me/followers",
+      "following_url": "https://api.github.com/users/me/following",
+      "gists_url": "https://api.github.com/users/me/gists",
+      "starred_url": "https://api.github.com/users/me/starred",
+      "subscriptions_url": "https://api.github.com/users/me/subscriptions",
+      "organizations_url": "https://api.github.com/users/me/orgs",
+      "repos_url": "https://api.github.com/users/me/repos",
+      "events_url": "https://api.github.com/users/me/events",
+      "received_events_url": "https://api.github.com/users/me/received_events",
+      "type": "User",
+      "site_admin": false
+    }"#;
+
+    format!(
+        r#"{{
+  "id": 1,
+  "node_id": "I_1",
+  "url": "https://api.github.com/repos/acme/api/issues/7",
+  "repository_url": "https://api.github.com/repos/acme/api",
+  "labels_url": "https://api.github.com/repos/acme/api/issues/7/labels",
+  "comments_url": "https://api.github.com/repos/acme/api/issues/7/comments",
+  "events_url": "https://api.github.com/repos/acme/api/issues/7/events",
+  "html_url": "https://github.com/acme/api/pull/7",
+  "number": 7,
+  "state": "open",
+  "title": "feat(web): add app",
+  "user": {user},
+  "labels": [],
+  "assignees": [{user}],
+  "author_association": "OWNER",
+  "locked": false,
+  "comments": 0,
+  "created_at": "2026-10-14T09:00:00Z",
+  "updated_at": "2026-10-14T09:00:00Z"
+}}"#
+    )
+}
@@ -0,0 +1,609 @@
+mod common;
+
+use common::{FakeGithub, Workspace};
+
+const PULLS: &str = "/repos/acme/api/pulls";
+const ASSIGNEES: &str = "/repos/acme/api/issues/7/assignees";
+const REVIEWERS: &str = "/repos/acme/api/pulls/7/requested_reviewers";
+const LABELS: &str = "/repos/acme/api/issues/7/labels";
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn create_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["-y", "-r", "alice"], "");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(stdout(&output).contains("PR: https://github.com/acme/api/pull/7"));
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains(r#""title":"feat(web): add app""#));
+    assert!(create.body.contains(r#""head":"feature","base":"main""#));
+
+    let assign = github.find("POST", ASSIGNEES).unwrap();
+    assert_eq!(assign.body, r#"{"assignees":["me"]}"#);
+
+    let reviewers = github.find("POST", REVIEWERS).unwrap();
+    assert_eq!(
+        reviewers.body,
+        r#"{"reviewers":["alice"],"team_reviewers":[]}"#
+    );
+
+    let labels = github.find("POST", LABELS).unwrap();
+    assert_eq!(labels.body, r#"{"labels":["web"]}"#);
+}
+
+#[cfg(feature = "replay")]
+#[test]
+fn audit_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["--audit", "-y", "-r", "alice"], "");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let log = std::fs::read_to_string(workspace.root.join("state/prmaker/audit.log")).unwrap();
+    assert!(
+        log.contains("http POST /repos/acme/api/pulls -> 201"),
+        "{log}"
+    );
+    assert!(log.contains("git rev-parse"), "{log}");
+}
+
+#[test]
+fn profile_base_branch_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "GET",
+        "/repos/acme/api/branches",
+        200,
+        r#"[{"name": "develop", "commit": {"sha": "def", "url": "https://api.github.com/repos/acme/api/commits/def"}, "protected": false}]"#,
+    );
+    let workspace = Workspace::new(&github);
+    workspace.configure_profile(r#"base_branch = "develop""#);
+
+    let output = workspace.run(&["-y", "--no-reviewers"], "");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains(r#""head":"feature","base":"develop""#));
+}
+
+#[test]
+fn create_with_scripted_answers_test() {
+    let github = FakeGithub::start();
+    github.route("GET", "/users/bob", 200, r#"{"name": "Bob Builder"}"#);
+    github.route("GET", "/orgs/acme/members", 200, r#"[{"login": "bob"}]"#);
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(
+        &[],
+        "feat: scripted title\nABC-12\nScripted body\ny\n1\n\nn\nn\n",
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains(r#""title":"feat: scripted title""#));
+    assert!(create.body.contains("Scripted body"));
+    assert!(create.body.contains("ABC-12"));
+    assert!(stdout(&output).contains("1 - bob (Bob Builder, admin)"));
+
+    let reviewers = github.find("POST", REVIEWERS).unwrap();
+    assert_eq!(
+        reviewers.body,
+        r#"{"reviewers":["bob"],"team_reviewers":[]}"#
+    );
+}
+
+#[test]
+fn late_vars_test() {
+    let github = FakeGithub::start();
+    github.route("PATCH", "/repos/acme/api/pulls/7", 200, "{}");
+    let workspace = Workspace::new(&github);
+    workspace.configure(r#"late_vars = { preview = "https://preview-{{pr_number}}.example.com" }"#);
+
+    let output = workspace.run(
+        &["--no-reviewers", "--no-labels"],
+        "\nABC-12\nSee {{preview}} at {{head_sha}}\ny\n",
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(!stdout(&output).contains("placeholders"));
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains("See {{preview}} at {{head_sha}}"));
+
+    let patch = github.find("PATCH", "/repos/acme/api/pulls/7").unwrap();
+    assert!(patch
+        .body
+        .contains("See https://preview-7.example.com at abc"));
+}
+
+#[test]
+fn unsupported_feature_test() {
+    let github = FakeGithub::start();
+    github.route("GET", "/meta", 200, r#"{"installed_version": "3.9.4"}"#);
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["queue", "7"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert!(stdout(&output).contains(
+        "GitHub Enterprise Server 3.9.4 doesn't support merge queues, it needs 3.12 or newer"
+    ));
+    assert!(github.find("POST", "/graphql").is_none());
+}
+
+#[test]
+fn paginated_collaborators_test() {
+    let github = FakeGithub::start();
+    let next_page = format!(
+        "<{}repos/acme/api/collaborators?page=2>; rel=\"next\"",
+        github.url
+    );
+    github.route_with_headers(
+        "GET",
+        "/repos/acme/api/collaborators",
+        200,
+        r#"[{"login": "alice"}, {"login": "bob"}]"#,
+        &[("link", &next_page)],
+    );
+    github.route(
+        "GET",
+        "/repos/acme/api/collaborators?page=2",
+        200,
+        r#"[{"login": "carol"}]"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&[], "\nABC-12\n\ny\n2\n\nn\nn\n");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let reviewers = github.find("POST", REVIEWERS).unwrap();
+    assert_eq!(
+        reviewers.body,
+        r#"{"reviewers":["carol"],"team_reviewers":[]}"#
+    );
+}
+
+#[cfg(feature = "replay")]
+#[test]
+fn audited_paginated_collaborators_test() {
+    let github = FakeGithub::start();
+    let next_page = format!(
+        "<{}repos/acme/api/collaborators?page=2>; rel=\"next\"",
+        github.url
+    );
+    github.route_with_headers(
+        "GET",
+        "/repos/acme/api/collaborators",
+        200,
+        r#"[{"login": "alice"}, {"login": "bob"}]"#,
+        &[("link", &next_page)],
+    );
+    github.route(
+        "GET",
+        "/repos/acme/api/collaborators?page=2",
+        200,
+        r#"[{"login": "carol"}]"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["--audit"], "\nABC-12\n\ny\n2\n\nn\nn\n");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(stdout(&output).contains("carol"));
+
+    let reviewers = github.find("POST", REVIEWERS).unwrap();
+    assert_eq!(
+        reviewers.body,
+        r#"{"reviewers":["carol"],"team_reviewers":[]}"#
+    );
+}
+
+#[test]
+fn skip_flags_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(
+        &[
+            "-y",
+            "-r",
+            "alice",
+            "--no-assign",
+            "--no-reviewers",
+            "--no-labels",
+        ],
+        "",
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(github.find("POST", PULLS).is_some());
+    assert!(github.find("POST", ASSIGNEES).is_none());
+    assert!(github.find("POST", REVIEWERS).is_none());
+    assert!(github.find("POST", LABELS).is_none());
+}
+
+#[test]
+fn create_error_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "POST",
+        PULLS,
+        422,
+        r#"{"message": "A pull request already exists for acme:feature."}"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["-y"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert!(stdout(&output).contains("A pull request already exists"));
+    assert!(github.find("POST", ASSIGNEES).is_none());
+}
+
+#[test]
+fn duplicate_pr_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "GET",
+        PULLS,
+        200,
+        r#"[{
+          "url": "https://api.github.com/repos/acme/api/pulls/5",
+          "id": 5,
+          "number": 5,
+          "title": "feat: add the app",
+          "head": {"ref": "other", "sha": "abc"},
+          "base": {"ref": "main", "sha": "def"}
+        }]"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["--no-reviewers"], "\n\n\ny\nn\n");
+    let stdout = stdout(&output);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("A similar PR already exists:"));
+    assert!(stdout.contains("#5 feat: add the app"));
+    assert!(github.find("POST", PULLS).is_none());
+}
+
+#[test]
+fn related_prs_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "GET",
+        "/repos/acme/web/pulls/12",
+        200,
+        r#"{
+          "url": "https://api.github.com/repos/acme/web/pulls/12",
+          "id": 12,
+          "number": 12,
+          "title": "feat: show the app",
+          "head": {"ref": "app", "sha": "abc"},
+          "base": {"ref": "main", "sha": "def"}
+        }"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["-y", "--related", "acme/web#12"], "");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create
+        .body
+        .contains(r"### Related PRs\n\n- acme/web#12: feat: show the app"));
+
+    let output = workspace.run(&["-y", "--related", "acme/web#13"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert!(stdout(&output).contains("Couldn't find PR acme/web#13"));
+}
+
+#[test]
+fn invalid_reviewers_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "GET",
+        "/repos/acme/api/collaborators/bob/permission",
+        200,
+        r#"{"permission": "read"}"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(
+        &[
+            "-y", "-r", "alice", "-r", "bob", "-r", "ghost", "-r", "acme/ops",
+        ],
+        "",
+    );
+    let stdout = stdout(&output);
+
+    assert_eq!(Some(1), output.status.code(), "{stdout}");
+    assert!(stdout.contains("bob doesn't have push access to acme/api"));
+    assert!(stdout.contains("unknown user ghost"));
+    assert!(stdout.contains("unknown team ops in the acme org"));
+    assert!(!stdout.contains("alice"));
+    assert!(github.find("POST", PULLS).is_none());
+}
+
+#[test]
+fn follow_up_errors_test() {
+    let github = FakeGithub::start();
+    github.route("POST", ASSIGNEES, 403, r#"{"message": "Forbidden"}"#);
+    github.route(
+        "POST",
+        REVIEWERS,
+        422,
+        r#"{"message": "Reviews may only be requested from collaborators."}"#,
+    );
+    github.route("POST", LABELS, 404, r#"{"message": "Not Found"}"#);
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["-y", "-r", "alice"], "");
+    let stdout = stdout(&output);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("Error when assigning"));
+    assert!(stdout.contains("Failed to request reviewers"));
+    assert!(stdout.contains("Error when adding labels"));
+    assert!(stdout.contains("Adding labels failed, (r)etry, (s)kip or (e)dit it?"));
+    assert!(stdout.contains("PR: https://github.com/acme/api/pull/7"));
+
+    let assigns = github
+        .requests()
+        .into_iter()
+        .filter(|r| r.method == "POST" && r.path == ASSIGNEES)
+        .count();
+
+    assert_eq!(1, assigns);
+}
+
+#[test]
+fn per_reviewer_failures_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "GET",
+        "/repos/acme/api/collaborators/bob/permission",
+        200,
+        r#"{"permission": "admin"}"#,
+    );
+    github.route(
+        "POST",
+        REVIEWERS,
+        422,
+        r#"{"message": "Reviews may only be requested from collaborators."}"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["-y", "-r", "alice", "-r", "bob"], "");
+    let stdout = stdout(&output);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("requesting them one by one"));
+    assert!(stdout.contains(
+        "Failed to request a review from alice: Reviews may only be requested from collaborators."
+    ));
+    assert!(stdout.contains("Failed to request a review from bob"));
+
+    let bodies: Vec<String> = github
+        .requests()
+        .into_iter()
+        .filter(|r| r.method == "POST" && r.path == REVIEWERS)
+        .map(|r| r.body)
+        .collect();
+
+    assert_eq!(
+        bodies,
+        [
+            r#"{"reviewers":["alice","bob"],"team_reviewers":[]}"#,
+            r#"{"reviewers":["alice"],"team_reviewers":[]}"#,
+            r#"{"reviewers":["bob"],"team_reviewers":[]}"#,
+        ]
+    );
+}
+
+#[test]
+fn unauthorized_token_test() {
+    let github = FakeGithub::start();
+    github.route(
+        "GET",
+        "/rate_limit",
+        401,
+        r#"{"message": "Bad credentials"}"#,
+    );
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&["-y"], "");
+
+    assert_eq!(Some(1), output.status.code());
+    assert!(github.find("POST", PULLS).is_none());
+}
+
+#[test]
+fn declined_review_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(&[], "\n\n\nn\n");
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(stdout(&output).contains("Closing..."));
+    assert!(github.find("POST", PULLS).is_none());
+}
+
+#[test]
+fn stdin_json_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+
+    let output = workspace.run(
+        &["--stdin-json"],
+        r#"{"title": "feat: add login", "body": "Adds it", "reviewers": ["alice"]}"#,
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert_eq!(
+        r#"{"ok":true,"number":7,"url":"https://github.com/acme/api/pull/7","reviewers":["alice"]}"#,
+        stdout(&output).trim()
+    );
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains(r#""title":"feat: add login""#));
+    assert!(create.body.contains("Adds it"));
+    assert!(github
+        .find("POST", REVIEWERS)
+        .unwrap()
+        .body
+        .contains("alice"));
+}
+
+#[test]
+fn stdin_json_other_head_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+    let switch = std::process::Command::new("git")
+        .args(["switch", "-q", "main"])
+        .current_dir(&workspace.repo)
+        .status()
+        .unwrap();
+    assert!(switch.success());
+
+    let output = workspace.run(
+        &["--stdin-json"],
+        r#"{"title": "feat(web): add app", "head": "feature"}"#,
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains(r#""head":"feature""#));
+
+    let labels = github.find("POST", LABELS).unwrap();
+    assert_eq!(labels.body, r#"{"labels":["web"]}"#);
+}
+
+#[test]
+fn stdin_json_warnings_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+    workspace.configure_project(r#"template = "missing.md""#);
+
+    let output = workspace.run(
+        &["--stdin-json"],
+        r#"{"title": "feat(web): add app", "reviewers": ["@nobody"]}"#,
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+
+    let stdout = stdout(&output);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(1, lines.len(), "{stdout}");
+
+    let response: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(
+        response["warnings"],
+        serde_json::json!([
+            "Couldn't read template missing.md, using the default one",
+            "unknown reviewer group @nobody, ignored it"
+        ])
+    );
+}
+
+#[test]
+fn stdin_json_forge_test() {
+    let github = FakeGithub::start();
+    let workspace = Workspace::new(&github);
+    workspace.configure(r#"forges = { "github.com" = "gitea" }"#);
+
+    let output = workspace.run_with_env(
+        &["--stdin-json"],
+        r#"{"title": "feat: add login", "base": "main", "reviewers": ["alice"]}"#,
+        &[
+            ("GITEA_API_URL", &github.url),
+            ("GITEA_TOKEN", "gitea-token"),
+        ],
+    );
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert_eq!(
+        r#"{"ok":true,"number":7,"url":"https://github.com/acme/api/pull/7","reviewers":["alice"]}"#,
+        stdout(&output).trim()
+    );
+
+    let create = github.find("POST", PULLS).unwrap();
+    assert!(create.body.contains(r#""head":"feature","base":"main""#));
+    assert!(github
+        .find("POST", REVIEWERS)
+        .unwrap()
+        .body
+        .contains("alice"));
+}
+
+#[test]
+fn bot_manifest_test() {
+    let github = FakeGithub::start();
+    github.route("GET", PULLS, 200, "[]");
+    github.route(
+        "GET",
+        "/repos/acme/web/pulls",
+        200,
+        r#"[{
+          "url": "https://api.github.com/repos/acme/web/pulls/3",
+          "id": 3,
+          "number": 3,
+          "head": {"ref": "deps/bump-serde", "sha": "abc"},
+          "base": {"ref": "main", "sha": "def"}
+        }]"#,
+    );
+    let workspace = Workspace::new(&github);
+    let manifest = workspace.root.join("prs.toml");
+    std::fs::write(
+        &manifest,
+        r#"
+[[prs]]
+repo = "acme/api"
+head = "deps/bump-serde"
+base = "main"
+title = "chore(deps): bump serde"
+labels = ["dependencies"]
+
+[[prs]]
+repo = "acme/web"
+head = "deps/bump-serde"
+base = "main"
+title = "chore(deps): bump serde"
+"#,
+    )
+    .unwrap();
+
+    let output = workspace.run(&["bot", "--manifest", manifest.to_str().unwrap()], "");
+    let stdout = stdout(&output);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("https://github.com/acme/api/pull/7"));
+    assert!(stdout.contains("already open: #3"));
+    assert!(github
+        .find("POST", PULLS)
+        .unwrap()
+        .body
+        .contains(r#""head":"deps/bump-serde""#));
+    assert!(github
+        .find("POST", LABELS)
+        .unwrap()
+        .body
+        .contains("dependencies"));
+    assert!(github.find("POST", "/repos/acme/web/pulls").is_none());
+}